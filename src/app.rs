@@ -4,8 +4,34 @@
 
 use crate::config::FilterSettings;
 use crate::history::History;
-use crate::youtube::Video;
+use crate::mpv_ipc::Chapter;
+use crate::query::ParsedQuery;
+use crate::saved::SavedList;
+use crate::youtube::{NumberStyle, Video};
+use anyhow::{Context, Result};
 use std::cmp;
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+/// Time within which `X` must be pressed again to clear the watch history.
+const CLEAR_HISTORY_WINDOW: Duration = Duration::from_secs(2);
+/// Frames of the spinner shown while a background task runs.
+const SPINNER_FRAMES: [char; 4] = ['|', '/', '-', '\\'];
+/// Longest duration (seconds) of a video treated as a Short by `hide_shorts`.
+pub const SHORTS_MAX_DURATION: u64 = 60;
+/// Time within which a second `g` completes `gg` (jump to the first entry).
+const PENDING_KEY_WINDOW: Duration = Duration::from_secs(1);
+/// Maximum gap between moves that still counts as holding the key (scroll acceleration).
+const SCROLL_REPEAT_WINDOW: Duration = Duration::from_millis(100);
+/// Consecutive rapid moves before the step size doubles.
+const SCROLL_MOVES_PER_LEVEL: u32 = 10;
+/// Largest step while accelerating (as a power of two: 8 videos).
+const SCROLL_MAX_LEVEL: u32 = 3;
+
+/// Maximum number of status messages kept in the message log.
+const STATUS_LOG_LIMIT: usize = 100;
 
 /// Application state and UI mode.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -16,6 +42,22 @@ pub enum UiMode {
     Search,
     /// Filters mode
     Filters,
+    /// Message log popup
+    Log,
+    /// Watch statistics popup
+    Stats,
+    /// Chapter list of the video playing in MPV
+    Chapters,
+    /// Raw and parsed values of the selected video (`--debug`)
+    Debug,
+    /// Full description and metadata of the selected video
+    Detail,
+    /// Waiting for y/n before playing the selected video (`confirm_play`)
+    ConfirmPlay,
+    /// Waiting for y/n before opening many marked videos at once
+    ConfirmOpenMarked,
+    /// Typing a 1-based list position to jump to (`:`)
+    Goto,
 }
 
 /// Sort mode for video list.
@@ -29,6 +71,106 @@ pub enum SortMode {
     UploadDate,
     /// Sort by creator/channel name (alphabetical)
     Creator,
+    /// Keep the order returned by the source (API relevance/ranking)
+    Relevance,
+    /// Sort by watch activity (continue watching first, then most recently watched)
+    WatchRecency,
+}
+
+impl std::str::FromStr for SortMode {
+    type Err = anyhow::Error;
+
+    /// Parse a sort mode from its config name.
+    ///
+    /// # Arguments
+    /// * `s` - One of "date", "views", "upload_date", "creator", "relevance", "watch_recency"
+    ///
+    /// # Returns
+    /// * `Result<SortMode>` - Parsed sort mode or error for unknown names
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "date" => Ok(SortMode::Date),
+            "views" => Ok(SortMode::Views),
+            "upload_date" => Ok(SortMode::UploadDate),
+            "creator" => Ok(SortMode::Creator),
+            "relevance" => Ok(SortMode::Relevance),
+            "watch_recency" => Ok(SortMode::WatchRecency),
+            other => Err(anyhow::anyhow!("Unknown sort mode: {}", other)),
+        }
+    }
+}
+
+impl SortMode {
+    /// Get the config name of the sort mode (inverse of `from_str`).
+    ///
+    /// # Returns
+    /// * `&'static str` - Name as used by the `default_sort_*` config fields
+    pub fn as_config_str(&self) -> &'static str {
+        match self {
+            SortMode::Date => "date",
+            SortMode::Views => "views",
+            SortMode::UploadDate => "upload_date",
+            SortMode::Creator => "creator",
+            SortMode::Relevance => "relevance",
+            SortMode::WatchRecency => "watch_recency",
+        }
+    }
+}
+
+/// How the video list scrolls as the selection moves.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScrollMode {
+    /// Keep the selection in the middle of the list
+    Centered,
+    /// Only scroll when the selection would leave the visible area
+    Anchored,
+}
+
+impl std::str::FromStr for ScrollMode {
+    type Err = anyhow::Error;
+
+    /// Parse a scroll mode from its config name.
+    ///
+    /// # Arguments
+    /// * `s` - "centered" or "anchored"
+    ///
+    /// # Returns
+    /// * `Result<ScrollMode>` - Parsed scroll mode or error for unknown names
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "centered" => Ok(ScrollMode::Centered),
+            "anchored" => Ok(ScrollMode::Anchored),
+            other => Err(anyhow::anyhow!("Unknown scroll mode: {}", other)),
+        }
+    }
+}
+
+/// How several marked videos are opened at once.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OpenMode {
+    /// One MPV instance playing the videos as a playlist
+    Playlist,
+    /// One MPV window per video
+    Windows,
+}
+
+impl std::str::FromStr for OpenMode {
+    type Err = anyhow::Error;
+
+    /// Parse an open mode from its config name.
+    ///
+    /// # Arguments
+    /// * `s` - "playlist" or "windows"
+    ///
+    /// # Returns
+    /// * `Result<OpenMode>` - Parsed open mode or error for unknown names
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "playlist" => Ok(OpenMode::Playlist),
+            "windows" => Ok(OpenMode::Windows),
+            other => Err(anyhow::anyhow!("Unknown open mode: {}", other)),
+        }
+    }
 }
 
 /// Tab mode for different video views.
@@ -40,8 +182,161 @@ pub enum Tab {
     Search,
     /// History tab - show watched videos
     History,
+    /// Saved tab - videos saved for later
+    Saved,
+}
+
+impl Tab {
+    /// All tabs in tab bar order.
+    pub const ALL: [Tab; 4] = [Tab::CurrentView, Tab::Search, Tab::History, Tab::Saved];
+
+    /// Get the name shown in the tab bar.
+    ///
+    /// # Returns
+    /// * `&'static str` - Tab name
+    pub fn name(self) -> &'static str {
+        match self {
+            Tab::CurrentView => "Current View",
+            Tab::Search => "Search",
+            Tab::History => "History",
+            Tab::Saved => "Saved",
+        }
+    }
+
+    /// Get the key identifying the tab in saved state.
+    ///
+    /// # Returns
+    /// * `&'static str` - Stable lowercase key
+    pub fn key(self) -> &'static str {
+        match self {
+            Tab::CurrentView => "current",
+            Tab::Search => "search",
+            Tab::History => "history",
+            Tab::Saved => "saved",
+        }
+    }
+
+    /// Get the position of the tab, used to index per-tab state.
+    ///
+    /// # Returns
+    /// * `usize` - Zero-based tab index
+    pub fn index(self) -> usize {
+        match self {
+            Tab::CurrentView => 0,
+            Tab::Search => 1,
+            Tab::History => 2,
+            Tab::Saved => 3,
+        }
+    }
+}
+
+/// Where search mode input goes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SearchTarget {
+    /// Filter the current list locally (`search_queries`)
+    Filter,
+    /// Search the YouTube platform (`search_query_global`, Search tab)
+    YouTube,
+}
+
+impl SearchTarget {
+    /// Get the label shown in the search bar.
+    ///
+    /// # Returns
+    /// * `&'static str` - Mode indicator text
+    pub fn label(self) -> &'static str {
+        match self {
+            SearchTarget::Filter => "Filter list",
+            SearchTarget::YouTube => "Search YouTube",
+        }
+    }
+}
+
+/// Filter setting that can be edited in Filters mode.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FilterField {
+    /// Channel name filter
+    Channel,
+    /// Minimum duration filter
+    MinDuration,
+    /// Maximum duration filter
+    MaxDuration,
+    /// Minimum view count filter
+    MinViews,
+    /// Maximum view count filter
+    MaxViews,
+    /// Published-after date filter
+    AfterDate,
+}
+
+impl FilterField {
+    /// All fields in the order the filter editor lists them.
+    pub const ALL: [FilterField; 6] = [
+        FilterField::Channel,
+        FilterField::MinDuration,
+        FilterField::MaxDuration,
+        FilterField::MinViews,
+        FilterField::MaxViews,
+        FilterField::AfterDate,
+    ];
+
+    /// Get the label shown in the filter editor.
+    ///
+    /// # Returns
+    /// * `&'static str` - Field label
+    pub fn label(self) -> &'static str {
+        match self {
+            FilterField::Channel => "Channel",
+            FilterField::MinDuration => "Min duration",
+            FilterField::MaxDuration => "Max duration",
+            FilterField::MinViews => "Min views",
+            FilterField::MaxViews => "Max views",
+            FilterField::AfterDate => "After date",
+        }
+    }
+
+    /// Get an example of valid input for the field.
+    ///
+    /// # Returns
+    /// * `&'static str` - Input hint
+    pub fn hint(self) -> &'static str {
+        match self {
+            FilterField::Channel => "part of the channel name",
+            FilterField::MinDuration | FilterField::MaxDuration => "e.g. 90s, 5m, 1h30m",
+            FilterField::MinViews | FilterField::MaxViews => "e.g. 5000, 10k, 1.5M",
+            FilterField::AfterDate => "YYYY-MM-DD",
+        }
+    }
+}
+
+/// In-progress text entry for a filter field.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FilterInput {
+    /// Which filter is being edited
+    pub field: FilterField,
+    /// Text typed so far (e.g. "1h30m")
+    pub buffer: String,
+    /// Parse error from the last commit attempt
+    pub error: Option<String>,
 }
 
+/// Screen position of a rendered list item, used for mouse hit-testing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ItemRow {
+    /// Index of the video in the current tab's list
+    pub index: usize,
+    /// First terminal row occupied by the item
+    pub start_row: u16,
+    /// Number of rows the item occupies
+    pub height: u16,
+}
+
+/// Background platform search: one page of videos and the next page token.
+pub type SearchTask = tokio::task::JoinHandle<Result<(Vec<Video>, Option<String>)>>;
+
+/// Background re-fetch of the CurrentView recommendations.
+pub type RefreshTask = tokio::task::JoinHandle<Result<Vec<Video>>>;
+
 /// Main application state.
 ///
 /// Manages all application data including videos, selection, search, and filters.
@@ -53,30 +348,147 @@ pub struct App {
     pub filtered_videos: Vec<Video>,
     /// Currently selected video index (in filtered_videos)
     pub selected_index: usize,
-    /// Search query string
-    pub search_query: String,
+    /// Index of the video shown in the preview pane, independent of the selection
+    pub preview_index: Option<usize>,
+    /// Local filter query per tab (indexed by `Tab::index`)
+    pub search_queries: [String; 4],
     /// Current filter settings
     pub filters: FilterSettings,
     /// Current UI mode
     pub mode: UiMode,
     /// Whether to hide watched videos
     pub hide_watched: bool,
+    /// Whether to hide Shorts (see `is_short`)
+    pub hide_shorts: bool,
     /// History tracker
     pub history: History,
     /// Status message to display
     pub status_message: Option<String>,
-    /// Current sort mode
-    pub sort_mode: SortMode,
+    /// Recent status messages (oldest first) for the message log
+    pub status_log: Vec<String>,
+    /// State changed since the last frame; the UI is only redrawn when set
+    pub dirty: bool,
+    /// Current frame of the status bar spinner (see `status_with_spinner`)
+    pub spinner_frame: usize,
+    /// Chapters of the video playing in MPV (shown in the chapter popup)
+    pub chapters: Vec<Chapter>,
+    /// Selected row in the chapter popup
+    pub chapter_index: usize,
+    /// Sort mode per tab (indexed by `Tab::index`)
+    pub sort_modes: [SortMode; 4],
+    /// Selected index each tab had when it was last left (indexed by `Tab::index`)
+    pub selected_indices: [usize; 4],
     /// Active tab
     pub active_tab: Tab,
-    /// Videos from platform search
+    /// Videos from platform search in the order returned by the API
+    pub all_search_results: Vec<Video>,
+    /// Videos from platform search (sorted)
     pub search_results: Vec<Video>,
-    /// Videos from watch history
+    /// Videos from watch history in load order
+    pub all_history_videos: Vec<Video>,
+    /// Videos from watch history (sorted)
     pub history_videos: Vec<Video>,
     /// Search query for platform search (separate from filter search)
     pub search_query_global: String,
-    /// Pending search task handle (for non-blocking search)
-    pub search_task: Option<tokio::task::JoinHandle<anyhow::Result<Vec<Video>>>>,
+    /// Where typed search mode input goes (toggled with Tab in search mode)
+    pub search_target: SearchTarget,
+    /// Pending search task handle (for non-blocking search): videos and next page token
+    pub search_task: Option<SearchTask>,
+    /// Pending refresh of the CurrentView recommendations (`r`)
+    pub refresh_task: Option<RefreshTask>,
+    /// Whether the pending search loads the next page (appended) instead of a new search
+    pub search_loading_more: bool,
+    /// Page token for more results of the last search (Ctrl+L on the Search tab)
+    pub search_next_page: Option<String>,
+    /// Rows of the list items drawn in the last frame (sorted by start row)
+    pub item_rows: Vec<ItemRow>,
+    /// Channel IDs whose videos are hidden in every tab
+    pub blocked_channels: Vec<String>,
+    /// Minimum trimmed title length in the Current View (0 disables); symbol-only
+    /// titles are hidden regardless (see `is_spam_title`)
+    pub min_title_len: usize,
+    /// Index into `FilterField::ALL` of the field selected in Filters mode
+    pub filters_selected_field: usize,
+    /// Filter text entry (Filters mode)
+    pub filter_input: Option<FilterInput>,
+    /// Debug mode (`--debug`): raw API values are kept and can be inspected
+    pub debug: bool,
+    /// Play audio only (no video window), toggled with `b`
+    pub audio_only: bool,
+    /// Ask for confirmation before playing a video
+    pub confirm_play: bool,
+    /// Move faster while `j`/`k` are held down
+    pub scroll_acceleration: bool,
+    /// Time and direction (true = down) of the last keyboard move
+    pub last_move: Option<(Instant, bool)>,
+    /// Number of rapid moves in the same direction before the last one
+    pub move_streak: u32,
+    /// When `X` was first pressed on the History tab (cleared after the window)
+    pub clear_history_armed_at: Option<Instant>,
+    /// When `g` was pressed as the first key of `gg` (None when no key is pending)
+    pub pending_g: Option<Instant>,
+    /// Highest video height requested from yt-dlp (0 for no cap)
+    pub max_resolution: u32,
+    /// Videos saved for later (IDs, persisted separately from the history)
+    pub saved: SavedList,
+    /// Details of the saved videos, most recently saved first
+    pub all_saved_videos: Vec<Video>,
+    /// Saved tab list (blocked channels hidden, sorted)
+    pub saved_videos: Vec<Video>,
+    /// Scroll offset of the description in the detail popup
+    pub detail_scroll: u16,
+    /// Videos marked with Space (in marking order), opened together with `O`
+    pub marked: Vec<Video>,
+    /// How marked videos are opened
+    pub open_mode: OpenMode,
+    /// Ask before opening more than this many marked videos (0 never asks)
+    pub open_confirm_threshold: usize,
+    /// Videos queued with `a`, played in order with `p`
+    pub queue: Vec<Video>,
+    /// Lines shown per video, 1 (title only) to `ui::list::MAX_DETAIL_LEVEL` (`+`/`-`)
+    pub detail_level: u8,
+    /// How the list scrolls as the selection moves
+    pub scroll_mode: ScrollMode,
+    /// Extra MPV arguments for this session (set with `A`)
+    pub extra_mpv_args: Vec<String>,
+    /// Player command (`player_command` in the config)
+    pub player_command: String,
+    /// Arguments always passed to the player (`player_args` in the config)
+    pub player_args: Vec<String>,
+    /// In-progress text entry for the extra MPV arguments
+    pub mpv_args_input: Option<String>,
+    /// Digits typed so far in `UiMode::Goto`
+    pub goto_input: String,
+    /// What MPV is playing, polled over its IPC socket (None when not playing)
+    pub now_playing: Option<crate::mpv_ipc::PlaybackStatus>,
+    /// Pending transcript fetch task handle
+    pub transcript_task: Option<tokio::task::JoinHandle<anyhow::Result<String>>>,
+    /// Query of the most recently started platform search
+    pub last_search_query: String,
+    /// When the Search tab query was last edited (for live search)
+    pub last_keystroke: Option<Instant>,
+    /// Pause after the last keystroke before searching automatically (None disables live search)
+    pub live_search_delay: Option<Duration>,
+    /// Plain query to retry with after a search with operators found nothing
+    pub search_retry_query: Option<String>,
+    /// Disable per-channel coloring in the list
+    pub no_color: bool,
+    /// Show upload dates as "3 days ago" instead of the calendar date
+    pub relative_dates: bool,
+    /// Copy and show youtu.be links instead of full watch URLs
+    pub use_short_urls: bool,
+    /// Show raw video and channel IDs in the list
+    pub show_ids: bool,
+    /// Wrap long titles over several lines instead of truncating them
+    pub title_wrap: bool,
+    /// Show each video on a single line (`v`)
+    pub compact_list: bool,
+    /// Collapse the search and filter panels to one line each
+    pub focus_mode: bool,
+    /// How view counts are written
+    pub number_style: NumberStyle,
+    /// Thousands separator for `NumberStyle::Full`
+    pub thousands_separator: String,
 }
 
 impl App {
@@ -93,18 +505,81 @@ impl App {
             all_videos: Vec::new(),
             filtered_videos: Vec::new(),
             selected_index: 0,
-            search_query: String::new(),
+            preview_index: None,
+            search_queries: Default::default(),
             filters: FilterSettings::default(),
             mode: UiMode::List,
             hide_watched,
+            hide_shorts: false,
             history,
             status_message: None,
-            sort_mode: SortMode::Date,
+            status_log: Vec::new(),
+            chapters: Vec::new(),
+            chapter_index: 0,
+            selected_indices: [0; 4],
+            sort_modes: [
+                SortMode::Views,
+                SortMode::Relevance,
+                SortMode::WatchRecency,
+                SortMode::Relevance,
+            ],
             active_tab: Tab::CurrentView,
+            all_search_results: Vec::new(),
             search_results: Vec::new(),
+            all_history_videos: Vec::new(),
             history_videos: Vec::new(),
             search_query_global: String::new(),
+            search_target: SearchTarget::Filter,
             search_task: None,
+            refresh_task: None,
+            search_loading_more: false,
+            search_next_page: None,
+            item_rows: Vec::new(),
+            blocked_channels: Vec::new(),
+            min_title_len: 0,
+            filters_selected_field: 0,
+            filter_input: None,
+            debug: false,
+            audio_only: false,
+            confirm_play: false,
+            clear_history_armed_at: None,
+            pending_g: None,
+            scroll_acceleration: false,
+            last_move: None,
+            move_streak: 0,
+            max_resolution: 1080,
+            saved: SavedList::default(),
+            all_saved_videos: Vec::new(),
+            saved_videos: Vec::new(),
+            detail_scroll: 0,
+            dirty: true,
+            spinner_frame: 0,
+            marked: Vec::new(),
+            open_mode: OpenMode::Playlist,
+            open_confirm_threshold: 5,
+            queue: Vec::new(),
+            detail_level: crate::ui::list::MAX_DETAIL_LEVEL,
+            scroll_mode: ScrollMode::Centered,
+            extra_mpv_args: Vec::new(),
+            player_command: "mpv".to_string(),
+            player_args: Vec::new(),
+            mpv_args_input: None,
+            goto_input: String::new(),
+            now_playing: None,
+            transcript_task: None,
+            last_search_query: String::new(),
+            last_keystroke: None,
+            live_search_delay: None,
+            search_retry_query: None,
+            no_color: false,
+            relative_dates: false,
+            use_short_urls: false,
+            show_ids: false,
+            title_wrap: false,
+            compact_list: false,
+            focus_mode: false,
+            number_style: NumberStyle::Short,
+            thousands_separator: ",".to_string(),
         }
     }
 
@@ -120,112 +595,317 @@ impl App {
         self.apply_filters();
     }
 
-    /// Apply current search query and filters to video list.
+    /// Merge freshly fetched videos into the CurrentView list.
     ///
-    /// # Details
-    /// Filters videos based on:
-    /// - Search query (title, channel, description)
-    /// - Channel filter
-    /// - Duration filters
-    /// - Date filter
-    /// - Hide watched option
+    /// # Arguments
+    /// * `videos` - Newly fetched videos (e.g. on refresh)
     ///
-    /// Only applies when on CurrentView tab.
-    pub fn apply_filters(&mut self) {
-        // Only apply filters when on CurrentView tab
-        if self.active_tab != Tab::CurrentView {
-            return;
-        }
-        let mut filtered: Vec<Video> = self.all_videos.clone();
-
-        // Apply search query
-        if !self.search_query.is_empty() {
-            let query_lower = self.search_query.to_lowercase();
-            filtered.retain(|video| {
-                video.title.to_lowercase().contains(&query_lower)
-                    || video.channel.to_lowercase().contains(&query_lower)
-                    || video.description.to_lowercase().contains(&query_lower)
-            });
-        }
-
-        // Apply channel filter
-        if let Some(ref channel) = self.filters.channel {
-            let channel_lower = channel.to_lowercase();
-            filtered.retain(|video| video.channel.to_lowercase().contains(&channel_lower));
-        }
-
-        // Apply duration filters
-        if let Some(min_duration) = self.filters.min_duration {
-            filtered.retain(|video| video.duration >= min_duration);
-        }
-        if let Some(max_duration) = self.filters.max_duration {
-            filtered.retain(|video| video.duration <= max_duration);
-        }
-
-        // Apply date filter
-        if let Some(ref after_date) = self.filters.after_date
-            && let Ok(filter_date) = chrono::DateTime::parse_from_rfc3339(after_date)
-        {
-            let filter_date_utc = filter_date.with_timezone(&chrono::Utc);
-            filtered.retain(|video| video.published_at >= filter_date_utc);
+    /// # Returns
+    /// * `usize` - Number of videos that weren't listed before
+    ///
+    /// # Details
+    /// Videos already listed are updated in place (view and like counts may
+    /// have changed), new ones are prepended in fetch order and videos missing
+    /// from the new fetch are kept. The selection follows the selected video.
+    pub fn merge_videos(&mut self, videos: Vec<Video>) -> usize {
+        let positions: HashMap<String, usize> = self
+            .all_videos
+            .iter()
+            .enumerate()
+            .map(|(index, video)| (video.id.clone(), index))
+            .collect();
+        let mut added = Vec::new();
+        for video in videos {
+            match positions.get(&video.id) {
+                Some(&index) => self.all_videos[index] = video,
+                None if !added.iter().any(|v: &Video| v.id == video.id) => added.push(video),
+                None => {}
+            }
         }
+        let added_count = added.len();
+        added.append(&mut self.all_videos);
+        self.all_videos = added;
+        self.apply_filters();
+        added_count
+    }
 
-        // Apply hide watched filter
-        if self.hide_watched {
-            filtered.retain(|video| !self.history.is_watched(&video.id));
-        }
+    /// Apply current search query and filters to the video lists.
+    ///
+    /// # Details
+    /// Recomputes the displayed list of every tab from its source list (see
+    /// `view_for_tab`), so the local filter query narrows whichever tab is
+    /// active and changes made from other tabs (e.g. marking a video watched
+    /// in Search) are reflected immediately. The selection follows the
+    /// selected video, or is clamped if that video was filtered out. The
+    /// preview is closed, since its index may point at another video now.
+    pub fn apply_filters(&mut self) {
+        let selected_id = self.selected_video_id();
+        self.preview_index = None;
+        self.filtered_videos = self.view_for_tab(&self.all_videos, Tab::CurrentView);
+        self.search_results = self.view_for_tab(&self.all_search_results, Tab::Search);
+        self.history_videos = self.view_for_tab(&self.all_history_videos, Tab::History);
+        self.saved_videos = self.view_for_tab(&self.all_saved_videos, Tab::Saved);
+        self.reselect(selected_id.as_deref());
+    }
 
-        // Apply sorting
-        self.apply_sorting(&mut filtered);
+    /// Get the ID of the selected video in the active tab.
+    ///
+    /// # Returns
+    /// * `Option<String>` - Video ID, or None if nothing is selected
+    fn selected_video_id(&self) -> Option<String> {
+        self.selected_video_from_tab().map(|video| video.id.clone())
+    }
 
-        self.filtered_videos = filtered;
-        self.selected_index = cmp::min(
-            self.selected_index,
-            self.filtered_videos.len().saturating_sub(1),
-        );
+    /// Select a video by ID after the active tab's list changed.
+    ///
+    /// # Arguments
+    /// * `video_id` - ID of the previously selected video
+    ///
+    /// # Details
+    /// Falls back to clamping the current index when the video is no longer listed.
+    fn reselect(&mut self, video_id: Option<&str>) {
+        let list = self.get_current_video_list();
+        self.selected_index = video_id
+            .and_then(|id| list.iter().position(|video| video.id == id))
+            .unwrap_or_else(|| cmp::min(self.selected_index, list.len().saturating_sub(1)));
     }
 
-    /// Apply current sort mode to video list.
+    /// Apply a sort mode to a video list.
     ///
     /// # Arguments
     /// * `videos` - Mutable reference to video list to sort
+    /// * `mode` - Sort mode to apply
     ///
     /// # Details
-    /// Sorts videos in-place based on current sort_mode.
-    fn apply_sorting(&self, videos: &mut [Video]) {
-        match self.sort_mode {
+    /// Sorts videos in-place. `Relevance` leaves the source order untouched.
+    fn apply_sorting(&self, videos: &mut [Video], mode: SortMode) {
+        match mode {
             SortMode::Date => {
                 // Sort by upload date (newest first)
-                videos.sort_by(|a, b| b.published_at.cmp(&a.published_at));
+                videos.sort_by_key(|v| cmp::Reverse(v.published_at));
             }
             SortMode::Views => {
                 // Sort by view count (highest first)
-                videos.sort_by(|a, b| b.view_count.cmp(&a.view_count));
+                videos.sort_by_key(|v| cmp::Reverse(v.view_count));
             }
             SortMode::UploadDate => {
                 // Sort by upload date (oldest first)
-                videos.sort_by(|a, b| a.published_at.cmp(&b.published_at));
+                videos.sort_by_key(|v| v.published_at);
             }
             SortMode::Creator => {
                 // Sort by creator/channel name (alphabetical)
                 videos.sort_by(|a, b| a.channel.cmp(&b.channel));
             }
+            SortMode::Relevance => {
+                // Keep source order
+            }
+            SortMode::WatchRecency => {
+                // Continue-watching first (most recently paused), then most recently watched,
+                // unwatched videos last
+                videos.sort_by_key(|v| {
+                    let resumable = self.history.progress(&v.id).filter(|p| p.is_resumable());
+                    let last_activity = match resumable {
+                        Some(progress) => {
+                            chrono::DateTime::parse_from_rfc3339(&progress.updated_at)
+                                .ok()
+                                .map(|t| t.with_timezone(&chrono::Utc))
+                        }
+                        None => self.history.watched_at(&v.id),
+                    };
+                    cmp::Reverse((resumable.is_some(), last_activity))
+                });
+            }
+        }
+    }
+
+    /// Get the displayed list for a tab from its source list.
+    ///
+    /// # Arguments
+    /// * `videos` - Source list in original order
+    /// * `tab` - Tab whose filters and sort mode apply
+    ///
+    /// # Returns
+    /// * `Vec<Video>` - Filtered copy, sorted by the tab's sort mode
+    ///
+    /// # Details
+    /// Every tab applies:
+    /// - The tab's own filter query (fuzzy on title and channel, substring of
+    ///   the description) and its operators (see `ParsedQuery`); matches are
+    ///   ranked by score
+    /// - Blocked channels
+    ///
+    /// CurrentView additionally applies:
+    /// - Channel filter
+    /// - Duration filters
+    /// - View count filters
+    /// - Date filter
+    /// - Hide watched option
+    /// - Hide Shorts option
+    /// - Snoozed videos
+    /// - Spam titles (see `is_spam_title`)
+    fn view_for_tab(&self, videos: &[Video], tab: Tab) -> Vec<Video> {
+        let query_text = &self.search_queries[tab.index()];
+        let query = ParsedQuery::parse(query_text);
+        let mut view: Vec<Video> = videos
+            .iter()
+            .filter(|video| query_text.is_empty() || query.matches(video))
+            .filter(|video| !self.is_channel_blocked(&video.channel_id))
+            .filter(|video| tab != Tab::CurrentView || self.passes_view_filters(video))
+            .cloned()
+            .collect();
+
+        // With free text, the best matches come first (ties keep the sort)
+        self.apply_sorting(&mut view, self.sort_modes[tab.index()]);
+        if !query.text.is_empty() {
+            view.sort_by_cached_key(|video| cmp::Reverse(query.text_score(video).unwrap_or(0)));
+        }
+        view
+    }
+
+    /// Check a video against the filters that only apply to CurrentView.
+    ///
+    /// # Arguments
+    /// * `video` - Video to check
+    ///
+    /// # Returns
+    /// * `bool` - True if the video passes the channel, duration, view count,
+    ///   date, watched, Shorts, snooze and spam filters
+    fn passes_view_filters(&self, video: &Video) -> bool {
+        if let Some(ref channel) = self.filters.channel
+            && !video
+                .channel
+                .to_lowercase()
+                .contains(&channel.to_lowercase())
+        {
+            return false;
+        }
+        if self
+            .filters
+            .min_duration
+            .is_some_and(|min| video.duration < min)
+            || self
+                .filters
+                .max_duration
+                .is_some_and(|max| video.duration > max)
+        {
+            return false;
+        }
+        if self
+            .filters
+            .min_views
+            .is_some_and(|min| video.view_count < min)
+            || self
+                .filters
+                .max_views
+                .is_some_and(|max| video.view_count > max)
+        {
+            return false;
+        }
+        if let Some(ref after_date) = self.filters.after_date
+            && let Ok(filter_date) = chrono::DateTime::parse_from_rfc3339(after_date)
+            && video.published_at < filter_date.with_timezone(&chrono::Utc)
+        {
+            return false;
+        }
+        if self.hide_watched && self.history.is_watched(&video.id) {
+            return false;
+        }
+        if self.hide_shorts && is_short(video) {
+            return false;
+        }
+        !self.history.is_snoozed(&video.id, chrono::Utc::now())
+            && !is_spam_title(&video.title, self.min_title_len)
+    }
+
+    /// Check if a channel is on the blocklist.
+    ///
+    /// # Arguments
+    /// * `channel_id` - YouTube channel ID
+    ///
+    /// # Returns
+    /// * `bool` - True if videos from this channel should be hidden
+    pub fn is_channel_blocked(&self, channel_id: &str) -> bool {
+        self.blocked_channels.iter().any(|id| id == channel_id)
+    }
+
+    /// Add the selected video's channel to the blocklist.
+    ///
+    /// # Returns
+    /// * `Option<String>` - Name of the newly blocked channel, or None when
+    ///   nothing is selected or it is already blocked
+    ///
+    /// # Details
+    /// Matches on the channel ID so renames don't unblock it. Reapplies the
+    /// filters so the channel's videos disappear from every tab. The ID is
+    /// recorded in the history, which keeps it for later sessions.
+    pub fn block_selected_channel(&mut self) -> Option<String> {
+        let video = self.selected_video_from_tab()?;
+        if video.channel_id.is_empty() || self.is_channel_blocked(&video.channel_id) {
+            return None;
         }
+        let (channel_id, channel) = (video.channel_id.clone(), video.channel.clone());
+        self.history.block_channel(&channel_id);
+        self.blocked_channels.push(channel_id);
+        self.apply_filters();
+        Some(channel)
+    }
+
+    /// Get the sort mode of the active tab.
+    ///
+    /// # Returns
+    /// * `SortMode` - Sort mode for the active tab
+    pub fn sort_mode(&self) -> SortMode {
+        self.sort_modes[self.active_tab.index()]
+    }
+
+    /// Set the initial sort mode for a tab.
+    ///
+    /// # Arguments
+    /// * `tab` - Tab (data source) to configure
+    /// * `mode` - Sort mode applied when the tab's list is populated
+    pub fn set_initial_sort(&mut self, tab: Tab, mode: SortMode) {
+        self.sort_modes[tab.index()] = mode;
     }
 
     /// Cycle to next sort mode.
     ///
     /// # Details
-    /// Cycles through sort modes: Date -> Views -> UploadDate -> Creator -> Date
-    /// Reapplies filters after changing sort mode.
+    /// Cycles the active tab's sort mode through:
+    /// Date -> Views -> UploadDate -> Creator -> Relevance -> WatchRecency -> Date
+    /// Each tab remembers its own sort mode. Re-sorts the active tab's list.
+    /// The new mode is recorded in the history, so it is kept across sessions.
     pub fn cycle_sort_mode(&mut self) {
-        self.sort_mode = match self.sort_mode {
+        let index = self.active_tab.index();
+        self.sort_modes[index] = match self.sort_modes[index] {
             SortMode::Date => SortMode::Views,
             SortMode::Views => SortMode::UploadDate,
             SortMode::UploadDate => SortMode::Creator,
-            SortMode::Creator => SortMode::Date,
+            SortMode::Creator => SortMode::Relevance,
+            SortMode::Relevance => SortMode::WatchRecency,
+            SortMode::WatchRecency => SortMode::Date,
         };
-        self.apply_filters();
+        self.history.set_sort_mode(
+            self.active_tab.key(),
+            self.sort_modes[index].as_config_str(),
+        );
+        match self.active_tab {
+            Tab::CurrentView => self.apply_filters(),
+            Tab::Search => {
+                let selected_id = self.selected_video_id();
+                self.search_results = self.view_for_tab(&self.all_search_results, Tab::Search);
+                self.reselect(selected_id.as_deref());
+            }
+            Tab::History => {
+                let selected_id = self.selected_video_id();
+                self.history_videos = self.view_for_tab(&self.all_history_videos, Tab::History);
+                self.reselect(selected_id.as_deref());
+            }
+            Tab::Saved => {
+                let selected_id = self.selected_video_id();
+                self.saved_videos = self.view_for_tab(&self.all_saved_videos, Tab::Saved);
+                self.reselect(selected_id.as_deref());
+            }
+        }
     }
 
     /// Get current sort mode as a string.
@@ -233,11 +913,13 @@ impl App {
     /// # Returns
     /// * `&str` - Sort mode name
     pub fn sort_mode_name(&self) -> &str {
-        match self.sort_mode {
+        match self.sort_mode() {
             SortMode::Date => "Date (newest)",
             SortMode::Views => "Views (highest)",
             SortMode::UploadDate => "Upload Date (oldest)",
             SortMode::Creator => "Creator (A-Z)",
+            SortMode::Relevance => "Relevance",
+            SortMode::WatchRecency => "Watch Recency",
         }
     }
 
@@ -255,12 +937,12 @@ impl App {
     /// Updates scroll offset to keep selection centered.
     /// Works with the current tab's video list.
     pub fn move_up(&mut self) {
-        let list = self.get_current_video_list();
-        if list.is_empty() {
+        let len = self.navigable_len();
+        if len == 0 {
             return;
         }
         if self.selected_index == 0 {
-            self.selected_index = list.len() - 1;
+            self.selected_index = len - 1;
         } else {
             self.selected_index -= 1;
         }
@@ -274,266 +956,3018 @@ impl App {
     /// Updates scroll offset to keep selection centered.
     /// Works with the current tab's video list.
     pub fn move_down(&mut self) {
-        let list = self.get_current_video_list();
-        if list.is_empty() {
+        let len = self.navigable_len();
+        if len == 0 {
             return;
         }
-        self.selected_index = (self.selected_index + 1) % list.len();
+        self.selected_index = (self.selected_index + 1) % len;
         self.update_scroll_offset();
     }
 
-    /// Update scroll offset to keep selection centered in the view.
+    /// Move the selection by a page without wrapping.
+    ///
+    /// # Arguments
+    /// * `down` - Move towards the end of the list
+    /// * `page` - Number of entries per page (see `ui::list::page_size`)
     ///
     /// # Details
-    /// Calculates the scroll offset needed to center the selected item.
-    /// This is called automatically when selection changes.
-    /// The actual scroll offset is calculated in the render function based on available height.
-    #[allow(dead_code)] // Scroll offset is calculated in render function, not stored
-    pub fn update_scroll_offset(&mut self) {
-        // Scroll offset is calculated dynamically in the render function
-        // based on the actual available height, so we don't need to store it
+    /// Stops at the first or last entry instead of wrapping like `move_up`/`move_down`.
+    pub fn move_by_page(&mut self, down: bool, page: usize) {
+        let len = self.navigable_len();
+        if len == 0 {
+            return;
+        }
+        self.selected_index = if down {
+            (self.selected_index + page).min(len - 1)
+        } else {
+            self.selected_index.saturating_sub(page)
+        };
+        self.update_scroll_offset();
     }
 
-    /// Add a character to the search query.
+    /// Jump to the first entry of the list.
+    pub fn select_first(&mut self) {
+        self.selected_index = 0;
+        self.update_scroll_offset();
+    }
+
+    /// Handle a press of `g` (vim-style `gg`).
     ///
     /// # Arguments
-    /// * `ch` - Character to add
+    /// * `now` - Time of the key press
+    ///
+    /// # Returns
+    /// * `bool` - True if this completed `gg` and the first entry was selected
     ///
     /// # Details
-    /// Only works in Search mode. Applies filters after adding character.
-    pub fn add_search_char(&mut self, ch: char) {
-        if self.mode == UiMode::Search {
-            self.search_query.push(ch);
-            self.apply_filters();
+    /// The first `g` is remembered; a second one within `PENDING_KEY_WINDOW`
+    /// jumps to the top. Any other key cancels it (see `cancel_pending_key`).
+    pub fn press_g(&mut self, now: Instant) -> bool {
+        let pending = self
+            .pending_g
+            .take()
+            .is_some_and(|at| now.saturating_duration_since(at) <= PENDING_KEY_WINDOW);
+        if pending {
+            self.select_first();
+        } else {
+            self.pending_g = Some(now);
         }
+        pending
     }
 
-    /// Remove last character from search query.
-    ///
-    /// # Details
-    /// Only works in Search mode. Applies filters after removing character.
-    pub fn remove_search_char(&mut self) {
-        if self.mode == UiMode::Search {
-            self.search_query.pop();
-            self.apply_filters();
-        }
+    /// Forget a pending `g` after another key was pressed.
+    pub fn cancel_pending_key(&mut self) {
+        self.pending_g = None;
     }
 
-    /// Clear search query.
-    ///
-    /// # Details
-    /// Clears search and applies filters.
-    #[allow(dead_code)] // Useful for future UI features (e.g., clear button)
-    pub fn clear_search(&mut self) {
-        self.search_query.clear();
-        self.apply_filters();
+    /// Jump to the last entry of the list.
+    pub fn select_last(&mut self) {
+        self.selected_index = self.navigable_len().saturating_sub(1);
+        self.update_scroll_offset();
     }
 
-    /// Toggle hide watched filter.
+    /// Compute the step of a keyboard move and record it.
+    ///
+    /// # Arguments
+    /// * `down` - Direction of the move
+    /// * `now` - Time of the key event
+    ///
+    /// # Returns
+    /// * `usize` - Number of entries to move (1 without acceleration)
     ///
     /// # Details
-    /// Toggles the hide_watched flag and reapplies filters.
-    pub fn toggle_hide_watched(&mut self) {
-        self.hide_watched = !self.hide_watched;
-        self.apply_filters();
+    /// Moves in the same direction less than `SCROLL_REPEAT_WINDOW` apart (key
+    /// repeat) extend a streak; every `SCROLL_MOVES_PER_LEVEL` moves double the
+    /// step, up to 8. A pause or a change of direction starts over at 1.
+    pub fn accelerated_step(&mut self, down: bool, now: Instant) -> usize {
+        if !self.scroll_acceleration {
+            return 1;
+        }
+        let repeated = self.last_move.is_some_and(|(at, was_down)| {
+            was_down == down && now.saturating_duration_since(at) <= SCROLL_REPEAT_WINDOW
+        });
+        self.move_streak = if repeated { self.move_streak + 1 } else { 0 };
+        self.last_move = Some((now, down));
+        1 << (self.move_streak / SCROLL_MOVES_PER_LEVEL).min(SCROLL_MAX_LEVEL)
     }
 
-    /// Set status message.
+    /// Move the selection with the keyboard, accelerating while the key is held.
     ///
     /// # Arguments
-    /// * `message` - Status message to display
-    pub fn set_status(&mut self, message: String) {
-        self.status_message = Some(message);
-    }
-
-    /// Clear status message.
-    #[allow(dead_code)] // Useful for auto-clearing status messages after timeout
-    pub fn clear_status(&mut self) {
-        self.status_message = None;
+    /// * `down` - Move down (true) or up (false)
+    /// * `now` - Time of the key event
+    ///
+    /// # Details
+    /// Single steps wrap around like `move_up`/`move_down`; larger steps stop
+    /// at the first or last entry instead of wrapping.
+    pub fn move_by_key(&mut self, down: bool, now: Instant) {
+        let step = self.accelerated_step(down, now);
+        if step == 1 {
+            if down {
+                self.move_down();
+            } else {
+                self.move_up();
+            }
+            return;
+        }
+        let len = self.navigable_len();
+        if len == 0 {
+            return;
+        }
+        self.selected_index = if down {
+            (self.selected_index + step).min(len - 1)
+        } else {
+            self.selected_index.saturating_sub(step)
+        };
+        self.update_scroll_offset();
     }
 
-    /// Mark selected video as watched.
+    /// Number of entries the selection moves over.
+    ///
+    /// # Returns
+    /// * `usize` - Recent searches while they are shown, otherwise the current list length
+    fn navigable_len(&self) -> usize {
+        if self.showing_recent_searches() {
+            self.history.recent_searches().len()
+        } else {
+            self.get_current_video_list().len()
+        }
+    }
+
+    /// Check if the Search tab shows recent searches instead of results.
+    ///
+    /// # Returns
+    /// * `bool` - True on the Search tab with no results, no query and remembered searches
+    pub fn showing_recent_searches(&self) -> bool {
+        self.active_tab == Tab::Search
+            && self.all_search_results.is_empty()
+            && self.search_query_global.is_empty()
+            && !self.history.recent_searches().is_empty()
+    }
+
+    /// Get the selected recent search query.
+    ///
+    /// # Returns
+    /// * `Option<&String>` - Query under the selection while recent searches are shown
+    pub fn selected_recent_search(&self) -> Option<&String> {
+        if !self.showing_recent_searches() {
+            return None;
+        }
+        self.history.recent_searches().get(self.selected_index)
+    }
+
+    /// Jump to the next video from a different channel.
     ///
     /// # Details
-    /// Marks the currently selected video as watched in history.
-    /// Works with videos from any tab (CurrentView, Search, History).
-    /// If hide_watched is enabled, the video will be removed from the list.
-    pub fn mark_selected_watched(&mut self) {
-        if let Some(video) = self.selected_video_from_tab() {
-            let video_id = video.id.clone();
-            self.history.mark_watched(&video_id);
-            if self.hide_watched && self.active_tab == Tab::CurrentView {
-                self.apply_filters();
-            }
+    /// Skips the remaining videos whose `channel_id` matches the selected one and
+    /// selects the first video from another channel, wrapping around the end of
+    /// the list. Does nothing if every video is from the same channel.
+    pub fn select_next_channel(&mut self) {
+        let list = self.get_current_video_list();
+        let len = list.len();
+        let Some(current) = list.get(self.selected_index) else {
+            return;
+        };
+        let next = (1..len)
+            .map(|offset| (self.selected_index + offset) % len)
+            .find(|&idx| list[idx].channel_id != current.channel_id);
+        if let Some(idx) = next {
+            self.selected_index = idx;
+            self.update_scroll_offset();
         }
     }
 
-    /// Switch to a different tab.
+    /// Preview the next video without moving the selection.
     ///
-    /// # Arguments
-    /// * `tab` - Tab to switch to
+    /// # Details
+    /// Opens the preview on the video after the selected one, or advances it,
+    /// wrapping to the top at the end of the list.
+    pub fn preview_next(&mut self) {
+        let len = self.get_current_video_list().len();
+        if len == 0 {
+            return;
+        }
+        let current = self.preview_index.unwrap_or(self.selected_index);
+        self.preview_index = Some((current + 1) % len);
+    }
+
+    /// Preview the previous video without moving the selection.
     ///
     /// # Details
-    /// Switches the active tab and resets selected index.
-    pub fn switch_tab(&mut self, tab: Tab) {
-        self.active_tab = tab;
-        self.selected_index = 0;
+    /// Opens the preview on the video before the selected one, or moves it back,
+    /// wrapping to the bottom at the start of the list.
+    pub fn preview_prev(&mut self) {
+        let len = self.get_current_video_list().len();
+        if len == 0 {
+            return;
+        }
+        let current = self
+            .preview_index
+            .unwrap_or(self.selected_index)
+            .min(len - 1);
+        self.preview_index = Some(if current == 0 { len - 1 } else { current - 1 });
     }
 
-    /// Get the currently active tab.
+    /// Get the previewed video.
     ///
     /// # Returns
-    /// * `Tab` - Current active tab
-    pub fn active_tab(&self) -> Tab {
-        self.active_tab
+    /// * `Option<&Video>` - Previewed video, or None if the preview is closed or out of range
+    pub fn preview_video(&self) -> Option<&Video> {
+        self.get_current_video_list().get(self.preview_index?)
     }
 
-    /// Get the current video list based on active tab.
+    /// Check if the preview pane is drawn over the list.
     ///
     /// # Returns
-    /// * `&Vec<Video>` - Reference to the appropriate video list
+    /// * `bool` - True in list mode while a video is previewed
+    pub fn preview_shown(&self) -> bool {
+        self.mode == UiMode::List && self.preview_video().is_some()
+    }
+
+    /// Find every place a video currently appears.
+    ///
+    /// # Arguments
+    /// * `video_id` - YouTube video ID
+    ///
+    /// # Returns
+    /// * `Vec<&'static str>` - Names of the tabs whose lists contain the video
+    ///   ("History" also when it is only in the watch history), then "Queue"
     ///
     /// # Details
-    /// Returns the video list for the currently active tab:
-    /// - CurrentView: filtered_videos
-    /// - Search: search_results
-    /// - History: history_videos
-    pub fn get_current_video_list(&self) -> &Vec<Video> {
-        match self.active_tab {
-            Tab::CurrentView => &self.filtered_videos,
-            Tab::Search => &self.search_results,
-            Tab::History => &self.history_videos,
+    /// Checks the unfiltered lists, so videos hidden by filters still count.
+    pub fn video_locations(&self, video_id: &str) -> Vec<&'static str> {
+        let contains = |videos: &[Video]| videos.iter().any(|video| video.id == video_id);
+        let mut locations = Vec::new();
+        if contains(&self.all_videos) {
+            locations.push(Tab::CurrentView.name());
+        }
+        if contains(&self.all_search_results) {
+            locations.push(Tab::Search.name());
+        }
+        if self.history.is_watched(video_id) || contains(&self.all_history_videos) {
+            locations.push(Tab::History.name());
         }
+        if self.saved.contains(video_id) {
+            locations.push(Tab::Saved.name());
+        }
+        if contains(&self.queue) {
+            locations.push("Queue");
+        }
+        locations
     }
 
-    /// Set search results from platform search.
-    ///
-    /// # Arguments
-    /// * `videos` - Videos from search
+    /// Select the previewed video and close the preview.
+    pub fn commit_preview(&mut self) {
+        if let Some(index) = self.preview_index.take()
+            && index < self.get_current_video_list().len()
+        {
+            self.selected_index = index;
+            self.update_scroll_offset();
+        }
+    }
+
+    /// Update scroll offset to keep selection centered in the view.
     ///
     /// # Details
-    /// Stores search results and resets selected index.
-    pub fn set_search_results(&mut self, videos: Vec<Video>) {
-        self.search_results = videos;
-        self.selected_index = 0;
+    /// Calculates the scroll offset needed to center the selected item.
+    /// This is called automatically when selection changes.
+    /// The actual scroll offset is calculated in the render function based on available height.
+    #[allow(dead_code)] // Scroll offset is calculated in render function, not stored
+    pub fn update_scroll_offset(&mut self) {
+        // Scroll offset is calculated dynamically in the render function
+        // based on the actual available height, so we don't need to store it
     }
 
-    /// Set history videos.
+    /// Find the list item drawn at a terminal row.
     ///
     /// # Arguments
-    /// * `videos` - Videos from history
+    /// * `row` - Terminal row (e.g. from a mouse event)
+    ///
+    /// # Returns
+    /// * `Option<usize>` - Index in the current tab's list, or None if no item is there
     ///
     /// # Details
-    /// Stores history videos and resets selected index.
-    pub fn set_history_videos(&mut self, videos: Vec<Video>) {
-        self.history_videos = videos;
-        self.selected_index = 0;
+    /// Binary-searches the rows recorded during the last render, so items of
+    /// different heights are mapped correctly.
+    pub fn index_at_row(&self, row: u16) -> Option<usize> {
+        let pos = self
+            .item_rows
+            .partition_point(|item| item.start_row <= row)
+            .checked_sub(1)?;
+        let item = self.item_rows[pos];
+        (row < item.start_row + item.height).then_some(item.index)
     }
 
-    /// Get the currently selected video from the active tab's list.
+    /// Enter search mode.
+    ///
+    /// # Details
+    /// On the Search tab input goes to the YouTube search; elsewhere the
+    /// previously used target is kept.
+    pub fn start_search_input(&mut self) {
+        if self.active_tab == Tab::Search {
+            self.search_target = SearchTarget::YouTube;
+        }
+        self.mode = UiMode::Search;
+    }
+
+    /// Switch search mode input between filtering the list and searching YouTube.
+    pub fn toggle_search_target(&mut self) {
+        self.search_target = match self.search_target {
+            SearchTarget::Filter => SearchTarget::YouTube,
+            SearchTarget::YouTube => SearchTarget::Filter,
+        };
+    }
+
+    /// Get the local filter query of the active tab.
     ///
     /// # Returns
-    /// * `Option<&Video>` - Selected video or None if list is empty
+    /// * `&str` - Filter query (each tab keeps its own)
+    pub fn search_query(&self) -> &str {
+        &self.search_queries[self.active_tab.index()]
+    }
+
+    /// Get the query that search mode input is routed to.
+    ///
+    /// # Returns
+    /// * `&str` - `search_query` when filtering, `search_query_global` when searching YouTube
+    pub fn search_input(&self) -> &str {
+        match self.search_target {
+            SearchTarget::Filter => self.search_query(),
+            SearchTarget::YouTube => &self.search_query_global,
+        }
+    }
+
+    /// Add a character to the search query.
+    ///
+    /// # Arguments
+    /// * `ch` - Character to add
     ///
     /// # Details
-    /// Returns the selected video from the appropriate list based on active tab.
-    pub fn selected_video_from_tab(&self) -> Option<&Video> {
-        let list = self.get_current_video_list();
-        list.get(self.selected_index)
+    /// Only works in Search mode. Goes to the query of the current search
+    /// target; filters are reapplied only when filtering the list.
+    pub fn add_search_char(&mut self, ch: char) {
+        if self.mode == UiMode::Search {
+            match self.search_target {
+                SearchTarget::Filter => {
+                    self.search_queries[self.active_tab.index()].push(ch);
+                    self.apply_filters();
+                }
+                SearchTarget::YouTube => {
+                    self.search_query_global.push(ch);
+                    self.note_search_keystroke(Instant::now());
+                }
+            }
+        }
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::youtube::Video;
-    use chrono::Utc;
+    /// Remove last character from search query.
+    ///
+    /// # Details
+    /// Only works in Search mode. Uses the query of the current search target.
+    pub fn remove_search_char(&mut self) {
+        if self.mode == UiMode::Search {
+            match self.search_target {
+                SearchTarget::Filter => {
+                    self.search_queries[self.active_tab.index()].pop();
+                    self.apply_filters();
+                }
+                SearchTarget::YouTube => {
+                    self.search_query_global.pop();
+                    self.note_search_keystroke(Instant::now());
+                }
+            }
+        }
+    }
 
-    fn create_test_video(id: &str, title: &str, channel: &str) -> Video {
-        Video::new(
-            id.to_string(),
-            title.to_string(),
-            channel.to_string(),
-            "channel_id".to_string(),
-            "Description".to_string(),
-            100,
-            Utc::now(),
-            "thumb".to_string(),
-            1000,
-        )
+    /// Clear search query.
+    ///
+    /// # Details
+    /// Clears the active tab's filter query and applies filters.
+    #[allow(dead_code)] // Useful for future UI features (e.g., clear button)
+    pub fn clear_search(&mut self) {
+        self.search_queries[self.active_tab.index()].clear();
+        self.apply_filters();
     }
 
-    #[test]
-    fn test_app_new() {
-        let history = History::default();
-        let app = App::new(history, false);
-        assert_eq!(app.all_videos.len(), 0);
-        assert_eq!(app.selected_index, 0);
-        assert_eq!(app.mode, UiMode::List);
+    /// Toggle hide watched filter.
+    ///
+    /// # Details
+    /// Toggles the hide_watched flag and reapplies filters.
+    pub fn toggle_hide_watched(&mut self) {
+        self.hide_watched = !self.hide_watched;
+        self.apply_filters();
     }
 
-    #[test]
-    fn test_app_set_videos() {
-        let history = History::default();
-        let mut app = App::new(history, false);
-        let videos = vec![
-            create_test_video("1", "Video 1", "Channel 1"),
-            create_test_video("2", "Video 2", "Channel 2"),
-        ];
-        app.set_videos(videos);
-        assert_eq!(app.all_videos.len(), 2);
-        assert_eq!(app.filtered_videos.len(), 2);
+    /// Toggle hide Shorts filter.
+    ///
+    /// # Details
+    /// Toggles the hide_shorts flag and reapplies filters.
+    pub fn toggle_hide_shorts(&mut self) {
+        self.hide_shorts = !self.hide_shorts;
+        self.apply_filters();
     }
 
-    #[test]
-    fn test_app_search_filter() {
-        let history = History::default();
-        let mut app = App::new(history, false);
-        let videos = vec![
-            create_test_video("1", "Rust Tutorial", "Channel 1"),
-            create_test_video("2", "Python Guide", "Channel 2"),
-        ];
-        app.set_videos(videos);
-        app.mode = UiMode::Search;
-        app.search_query = "Rust".to_string();
-        app.apply_filters();
-        assert_eq!(app.filtered_videos.len(), 1);
-        assert_eq!(app.filtered_videos[0].title, "Rust Tutorial");
+    /// Update what MPV is playing.
+    ///
+    /// # Arguments
+    /// * `status` - Playback state, or None when MPV isn't running
+    ///
+    /// # Details
+    /// Only requests a redraw when the shown state changed. The position of a
+    /// playing YouTube video is recorded as its watch progress (for Continue
+    /// Watching) once the duration is known.
+    pub fn set_now_playing(&mut self, status: Option<crate::mpv_ipc::PlaybackStatus>) {
+        if let Some(status) = &status
+            && let Some(video_id) = &status.video_id
+            && status.duration > 0.0
+        {
+            let position = status.position.max(0.0) as u64;
+            let unchanged = self
+                .history
+                .progress(video_id)
+                .is_some_and(|progress| progress.position == position);
+            if !unchanged {
+                self.history
+                    .set_progress(video_id, position, status.duration as u64);
+            }
+        }
+        if self.now_playing != status {
+            self.now_playing = status;
+            self.dirty = true;
+        }
     }
 
-    #[test]
-    fn test_app_move_selection() {
-        let history = History::default();
-        let mut app = App::new(history, false);
-        let videos = vec![
-            create_test_video("1", "Video 1", "Channel 1"),
-            create_test_video("2", "Video 2", "Channel 2"),
-            create_test_video("3", "Video 3", "Channel 3"),
-        ];
-        app.set_videos(videos);
-        assert_eq!(app.selected_index, 0);
+    /// Check whether a background task (search, refresh or transcript) is running.
+    pub fn is_loading(&self) -> bool {
+        self.search_task.is_some() || self.refresh_task.is_some() || self.transcript_task.is_some()
+    }
 
-        app.move_down();
-        assert_eq!(app.selected_index, 1);
+    /// Advance the status bar spinner while a background task runs.
+    ///
+    /// # Details
+    /// Called on each poll tick without input; requests a redraw only while
+    /// something is loading so an idle UI isn't redrawn.
+    pub fn tick_spinner(&mut self) {
+        if self.is_loading() {
+            self.spinner_frame = self.spinner_frame.wrapping_add(1);
+            self.dirty = true;
+        }
+    }
 
-        app.move_up();
-        assert_eq!(app.selected_index, 0);
+    /// Get the status message with the spinner appended while loading.
+    ///
+    /// # Returns
+    /// * `Option<String>` - Status message (or just the spinner), or None if
+    ///   there is no message and nothing is loading
+    pub fn status_with_spinner(&self) -> Option<String> {
+        if !self.is_loading() {
+            return self.status_message.clone();
+        }
+        let frame = SPINNER_FRAMES[self.spinner_frame % SPINNER_FRAMES.len()];
+        Some(match &self.status_message {
+            Some(message) => format!("{} {}", message, frame),
+            None => frame.to_string(),
+        })
+    }
 
-        app.move_up(); // Should wrap to end
-        assert_eq!(app.selected_index, 2);
+    /// Set status message.
+    ///
+    /// # Arguments
+    /// * `message` - Status message to display
+    ///
+    /// # Details
+    /// Also appends the message to the message log, keeping at most 100 entries.
+    pub fn set_status(&mut self, message: String) {
+        if self.status_log.len() >= STATUS_LOG_LIMIT {
+            self.status_log.remove(0);
+        }
+        self.status_log.push(message.clone());
+        self.status_message = Some(message);
+        self.dirty = true;
     }
 
-    #[test]
-    fn test_app_hide_watched() {
-        let mut history = History::default();
-        history.mark_watched("1");
-        let mut app = App::new(history, true);
-        let videos = vec![
-            create_test_video("1", "Video 1", "Channel 1"),
-            create_test_video("2", "Video 2", "Channel 2"),
-        ];
+    /// Clear status message.
+    #[allow(dead_code)] // Useful for auto-clearing status messages after timeout
+    pub fn clear_status(&mut self) {
+        self.status_message = None;
+    }
+
+    /// Mark selected video as watched.
+    ///
+    /// # Details
+    /// Marks the currently selected video as watched in history.
+    /// Works with videos from any tab (CurrentView, Search, History).
+    /// If hide_watched is enabled, the CurrentView list is re-filtered even when
+    /// acting from another tab, so the video disappears there as well.
+    pub fn mark_selected_watched(&mut self) {
+        if let Some(video) = self.selected_video_from_tab() {
+            let video_id = video.id.clone();
+            self.history.mark_watched(&video_id);
+            if self.hide_watched {
+                self.apply_filters();
+            }
+        }
+    }
+
+    /// Add the selected video to the playback queue.
+    ///
+    /// # Returns
+    /// * `Option<String>` - Title of the queued video, or None if nothing is
+    ///   selected or the video is already queued
+    pub fn enqueue_selected(&mut self) -> Option<String> {
+        let video = self.selected_video_from_tab()?;
+        if self.queue.iter().any(|queued| queued.id == video.id) {
+            return None;
+        }
+        let video = video.clone();
+        let title = video.title.clone();
+        self.queue.push(video);
+        Some(title)
+    }
+
+    /// Mark or unmark the selected video for opening together with others.
+    ///
+    /// # Returns
+    /// * `Option<bool>` - Whether the video is now marked, or None if nothing is selected
+    pub fn toggle_mark_selected(&mut self) -> Option<bool> {
+        let video = self.selected_video_from_tab()?;
+        if let Some(position) = self.marked.iter().position(|v| v.id == video.id) {
+            self.marked.remove(position);
+            Some(false)
+        } else {
+            let video = video.clone();
+            self.marked.push(video);
+            Some(true)
+        }
+    }
+
+    /// Check if a video is marked.
+    ///
+    /// # Arguments
+    /// * `video_id` - YouTube video ID
+    ///
+    /// # Returns
+    /// * `bool` - True if the video is marked
+    pub fn is_marked(&self, video_id: &str) -> bool {
+        self.marked.iter().any(|video| video.id == video_id)
+    }
+
+    /// Check if opening the marked videos needs a confirmation first.
+    ///
+    /// # Returns
+    /// * `bool` - True if more than `open_confirm_threshold` videos are marked
+    pub fn needs_open_confirmation(&self) -> bool {
+        self.open_confirm_threshold > 0 && self.marked.len() > self.open_confirm_threshold
+    }
+
+    /// Open all marked videos and mark them watched.
+    ///
+    /// # Arguments
+    /// * `play` - Player function taking URLs and playback options
+    ///   (e.g. `player::play_queue`)
+    ///
+    /// # Returns
+    /// * `Result<usize>` - Number of videos opened, or the first player error
+    ///
+    /// # Details
+    /// With `OpenMode::Playlist`, `play` is called once with every URL; with
+    /// `OpenMode::Windows`, once per video. Videos opened before an error stay
+    /// watched and are unmarked; the rest stay marked. Like `finish_queue`,
+    /// re-filters the Current View when hiding watched videos.
+    pub fn play_selected_set<F>(&mut self, mut play: F) -> Result<usize>
+    where
+        F: FnMut(&[String], crate::player::PlayOptions) -> Result<()>,
+    {
+        let batch_size = match self.open_mode {
+            OpenMode::Playlist => self.marked.len().max(1),
+            OpenMode::Windows => 1,
+        };
+        let marked = std::mem::take(&mut self.marked);
+        let mut opened = 0;
+        let mut result = Ok(());
+        for batch in marked.chunks(batch_size) {
+            let urls: Vec<String> = batch.iter().map(|video| video.url.clone()).collect();
+            if let Err(e) = play(&urls, self.play_options()) {
+                result = Err(e);
+                break;
+            }
+            for video in batch {
+                self.history.mark_watched(&video.id);
+            }
+            opened += batch.len();
+        }
+        self.marked = marked[opened..].to_vec();
+        if self.hide_watched && opened > 0 {
+            self.apply_filters();
+        }
+        result.map(|()| opened)
+    }
+
+    /// Take the queued videos for playback and mark them watched.
+    ///
+    /// # Returns
+    /// * `Vec<Video>` - Queued videos in order; the queue is left empty
+    ///
+    /// # Details
+    /// Call after the queue was handed to the player successfully. Like
+    /// `mark_selected_watched`, re-filters the Current View when hiding watched videos.
+    pub fn finish_queue(&mut self) -> Vec<Video> {
+        let queue = std::mem::take(&mut self.queue);
+        for video in &queue {
+            self.history.mark_watched(&video.id);
+        }
+        if self.hide_watched {
+            self.apply_filters();
+        }
+        queue
+    }
+
+    /// Snooze the selected video for 24 hours.
+    ///
+    /// # Returns
+    /// * `Option<String>` - Title of the snoozed video, or None if nothing is selected
+    ///
+    /// # Details
+    /// The video is hidden from the Current View until the snooze ends.
+    pub fn snooze_selected(&mut self) -> Option<String> {
+        let video = self.selected_video_from_tab()?;
+        let (video_id, title) = (video.id.clone(), video.title.clone());
+        self.history
+            .snooze(&video_id, chrono::Utc::now() + chrono::Duration::hours(24));
+        self.apply_filters();
+        Some(title)
+    }
+
+    /// Get the field selected in Filters mode.
+    ///
+    /// # Returns
+    /// * `FilterField` - Selected filter field
+    pub fn selected_filter_field(&self) -> FilterField {
+        FilterField::ALL[self.filters_selected_field.min(FilterField::ALL.len() - 1)]
+    }
+
+    /// Move the Filters mode selection to the next or previous field.
+    ///
+    /// # Arguments
+    /// * `down` - True to select the next field, false for the previous one
+    ///
+    /// # Details
+    /// Wraps around at either end, like the video list.
+    pub fn move_filter_selection(&mut self, down: bool) {
+        let len = FilterField::ALL.len();
+        let current = self.filters_selected_field.min(len - 1);
+        self.filters_selected_field = if down {
+            (current + 1) % len
+        } else {
+            (current + len - 1) % len
+        };
+    }
+
+    /// Get a filter's current value as editable text.
+    ///
+    /// # Arguments
+    /// * `field` - Filter field
+    ///
+    /// # Returns
+    /// * `Option<String>` - Value in the format the editor accepts, or None if unset
+    pub fn filter_value_text(&self, field: FilterField) -> Option<String> {
+        match field {
+            FilterField::Channel => self.filters.channel.clone(),
+            FilterField::MinDuration => self.filters.min_duration.map(format_human_duration),
+            FilterField::MaxDuration => self.filters.max_duration.map(format_human_duration),
+            FilterField::MinViews => self.filters.min_views.map(|views| views.to_string()),
+            FilterField::MaxViews => self.filters.max_views.map(|views| views.to_string()),
+            FilterField::AfterDate => self.filters.after_date.clone(),
+        }
+    }
+
+    /// Start editing a filter.
+    ///
+    /// # Arguments
+    /// * `field` - Which filter to edit
+    ///
+    /// # Details
+    /// Selects the field and pre-fills the input with its current value.
+    pub fn start_filter_input(&mut self, field: FilterField) {
+        self.filters_selected_field = FilterField::ALL
+            .iter()
+            .position(|&f| f == field)
+            .unwrap_or(0);
+        self.filter_input = Some(FilterInput {
+            field,
+            buffer: self.filter_value_text(field).unwrap_or_default(),
+            error: None,
+        });
+    }
+
+    /// Commit the filter being edited.
+    ///
+    /// # Details
+    /// An empty input clears the filter. Valid input sets the filter, reapplies
+    /// filters and closes the input. Invalid input keeps the input open,
+    /// records an error to show inline and reports it in the status bar.
+    pub fn commit_filter_input(&mut self) {
+        let Some(input) = self.filter_input.as_mut() else {
+            return;
+        };
+
+        if let Err(error) = set_filter(&mut self.filters, input.field, &input.buffer) {
+            input.error = Some(error.clone());
+            self.set_status(error);
+            return;
+        }
+        self.filter_input = None;
+        self.apply_filters();
+    }
+
+    /// Show more or fewer info lines per video.
+    ///
+    /// # Arguments
+    /// * `delta` - Levels to add (negative to remove)
+    ///
+    /// # Returns
+    /// * `bool` - True if the level changed (false at the limits)
+    pub fn adjust_detail_level(&mut self, delta: i8) -> bool {
+        let level = self
+            .detail_level
+            .saturating_add_signed(delta)
+            .clamp(1, crate::ui::list::MAX_DETAIL_LEVEL);
+        let changed = level != self.detail_level;
+        self.detail_level = level;
+        changed
+    }
+
+    /// Get the playback settings for the player.
+    ///
+    /// # Returns
+    /// * `PlayOptions` - Player command and arguments, extra MPV arguments,
+    ///   audio-only mode and resolution cap
+    pub fn play_options(&self) -> crate::player::PlayOptions<'_> {
+        crate::player::PlayOptions {
+            command: &self.player_command,
+            player_args: &self.player_args,
+            extra_args: &self.extra_mpv_args,
+            audio_only: self.audio_only,
+            max_resolution: self.max_resolution,
+        }
+    }
+
+    /// Start editing the extra MPV arguments, pre-filled with the current ones.
+    pub fn start_mpv_args_input(&mut self) {
+        self.mpv_args_input = Some(
+            self.extra_mpv_args
+                .iter()
+                .map(|arg| quote_arg(arg))
+                .collect::<Vec<_>>()
+                .join(" "),
+        );
+    }
+
+    /// Commit the extra MPV arguments being edited.
+    ///
+    /// # Details
+    /// The arguments apply to every playback for the rest of the session; an
+    /// empty input clears them. Input with an unclosed quote keeps the prompt
+    /// open and reports the error in the status bar.
+    pub fn commit_mpv_args_input(&mut self) {
+        let Some(input) = self.mpv_args_input.as_ref() else {
+            return;
+        };
+        match crate::player::split_args(input) {
+            Ok(args) => {
+                self.set_status(if args.is_empty() {
+                    "Cleared extra mpv arguments".to_string()
+                } else {
+                    format!("Extra mpv arguments: {}", args.join(" "))
+                });
+                self.extra_mpv_args = args;
+                self.mpv_args_input = None;
+            }
+            Err(e) => self.set_status(format!("Invalid mpv arguments: {}", e)),
+        }
+    }
+
+    /// Start typing a list position to jump to.
+    pub fn start_goto(&mut self) {
+        self.goto_input.clear();
+        self.mode = UiMode::Goto;
+    }
+
+    /// Add a typed character to the pending list position.
+    ///
+    /// # Arguments
+    /// * `c` - Typed character; anything but an ASCII digit is ignored
+    pub fn push_goto_char(&mut self, c: char) {
+        if c.is_ascii_digit() {
+            self.goto_input.push(c);
+        }
+    }
+
+    /// Jump to the typed list position and leave `UiMode::Goto`.
+    ///
+    /// # Returns
+    /// * `Option<usize>` - Selected 0-based index, or None if nothing was
+    ///   typed or the list is empty
+    ///
+    /// # Details
+    /// Positions past either end select the first or last entry.
+    pub fn commit_goto(&mut self) -> Option<usize> {
+        self.mode = UiMode::List;
+        let index = parse_goto_index(&self.goto_input, self.navigable_len());
+        self.goto_input.clear();
+        let index = index?;
+        self.selected_index = index;
+        self.update_scroll_offset();
+        Some(index)
+    }
+
+    /// Leave `UiMode::Goto` without moving the selection.
+    pub fn cancel_goto(&mut self) {
+        self.goto_input.clear();
+        self.mode = UiMode::List;
+    }
+
+    /// Switch to a different tab.
+    ///
+    /// # Arguments
+    /// * `tab` - Tab to switch to
+    ///
+    /// # Details
+    /// Remembers the selected index of the tab being left and restores the
+    /// one the target tab had, clamped to the length of its list.
+    pub fn switch_tab(&mut self, tab: Tab) {
+        self.selected_indices[self.active_tab.index()] = self.selected_index;
+        self.active_tab = tab;
+        self.selected_index = cmp::min(
+            self.selected_indices[tab.index()],
+            self.navigable_len().saturating_sub(1),
+        );
+        self.preview_index = None;
+    }
+
+    /// Select the first video of a tab whose list was replaced.
+    ///
+    /// # Arguments
+    /// * `tab` - Tab whose list changed
+    ///
+    /// # Details
+    /// Only moves the visible selection when the tab is active; otherwise the
+    /// remembered index is reset so switching to the tab starts at the top.
+    fn reset_selection(&mut self, tab: Tab) {
+        self.selected_indices[tab.index()] = 0;
+        if self.active_tab == tab {
+            self.selected_index = 0;
+        }
+    }
+
+    /// Toggle the channel filter for the selected video's channel.
+    ///
+    /// # Returns
+    /// * `Option<bool>` - Whether a filter is now active, or None if no
+    ///   filter was active and nothing is selected
+    ///
+    /// # Details
+    /// With a channel filter active, clears it. Otherwise filters the Current
+    /// View to the selected video's channel and switches to it, keeping that
+    /// video selected when it is listed there.
+    pub fn toggle_channel_filter(&mut self) -> Option<bool> {
+        if self.filters.channel.take().is_some() {
+            self.apply_filters();
+            return Some(false);
+        }
+
+        let video = self.selected_video_from_tab()?;
+        let video_id = video.id.clone();
+        self.filters.channel = Some(video.channel.clone());
+        self.switch_tab(Tab::CurrentView);
+        self.apply_filters();
+        if let Some(index) = self.filtered_videos.iter().position(|v| v.id == video_id) {
+            self.selected_index = index;
+            self.update_scroll_offset();
+        }
+        Some(true)
+    }
+
+    /// Remove the selected video from the watch history (History tab).
+    ///
+    /// # Returns
+    /// * `Option<String>` - Title of the removed video, or None when not on
+    ///   the History tab or nothing is selected (the caller saves the history)
+    ///
+    /// # Details
+    /// Drops the video from the History tab list right away without
+    /// re-fetching, keeps the selection at the same position (clamped to the
+    /// shorter list) and clears its watched marker elsewhere.
+    pub fn remove_selected_from_history(&mut self) -> Option<String> {
+        if self.active_tab != Tab::History {
+            return None;
+        }
+        let video = self.selected_video_from_tab()?;
+        let (video_id, title) = (video.id.clone(), video.title.clone());
+        self.history.remove(&video_id);
+        self.all_history_videos.retain(|video| video.id != video_id);
+        self.history_videos.retain(|video| video.id != video_id);
+        self.selected_index = cmp::min(
+            self.selected_index,
+            self.history_videos.len().saturating_sub(1),
+        );
+        // Watched markers and hide_watched in the other tabs
+        self.apply_filters();
+        Some(title)
+    }
+
+    /// Handle a press of the clear-history key.
+    ///
+    /// # Arguments
+    /// * `now` - Time of the key press
+    ///
+    /// # Returns
+    /// * `bool` - True if the history was cleared (the caller saves it)
+    ///
+    /// # Details
+    /// The first press arms the action and asks for a second one; pressing
+    /// again within `CLEAR_HISTORY_WINDOW` clears all watched videos and the
+    /// History tab list. A later press just arms it again.
+    pub fn request_clear_history(&mut self, now: Instant) -> bool {
+        let armed = self
+            .clear_history_armed_at
+            .is_some_and(|at| now.duration_since(at) <= CLEAR_HISTORY_WINDOW);
+        if !armed {
+            self.clear_history_armed_at = Some(now);
+            self.set_status("Press X again to clear the watch history".to_string());
+            return false;
+        }
+
+        self.clear_history_armed_at = None;
+        self.history.clear();
+        self.all_history_videos.clear();
+        self.history_videos.clear();
+        self.reset_selection(Tab::History);
+        // Watched markers and hide_watched in the Current View
+        self.apply_filters();
+        true
+    }
+
+    /// Pre-fill the Search tab with the search of the last session.
+    ///
+    /// # Arguments
+    /// * `query` - Last query (`Config::last_search`), if any
+    ///
+    /// # Returns
+    /// * `bool` - True if a non-empty query was put into the Search tab
+    ///
+    /// # Details
+    /// The active tab is kept; Enter on the Search tab runs the query.
+    pub fn prefill_last_search(&mut self, query: Option<&str>) -> bool {
+        let Some(query) = query.map(str::trim).filter(|q| !q.is_empty()) else {
+            return false;
+        };
+        self.search_query_global = query.to_string();
+        true
+    }
+
+    /// Keep the state that is restored on the next start.
+    ///
+    /// # Details
+    /// Stores the Search tab query as the history's last search (read into
+    /// `Config::last_search` on startup). An empty query keeps the previous one.
+    pub fn persist_state(&mut self) {
+        if !self.search_query_global.trim().is_empty() {
+            self.history.set_last_search(&self.search_query_global);
+        }
+    }
+
+    /// Check if the search and filter panels are collapsed.
+    ///
+    /// # Returns
+    /// * `bool` - True in focus mode, except while in Search or Filters mode
+    pub fn chrome_collapsed(&self) -> bool {
+        self.focus_mode && !matches!(self.mode, UiMode::Search | UiMode::Filters)
+    }
+
+    /// Get the currently active tab.
+    ///
+    /// # Returns
+    /// * `Tab` - Current active tab
+    pub fn active_tab(&self) -> Tab {
+        self.active_tab
+    }
+
+    /// Get the current video list based on active tab.
+    ///
+    /// # Returns
+    /// * `&Vec<Video>` - Reference to the appropriate video list
+    ///
+    /// # Details
+    /// Returns the video list for the currently active tab:
+    /// - CurrentView: filtered_videos
+    /// - Search: search_results
+    /// - History: history_videos
+    /// - Saved: saved_videos
+    pub fn get_current_video_list(&self) -> &Vec<Video> {
+        match self.active_tab {
+            Tab::CurrentView => &self.filtered_videos,
+            Tab::Search => &self.search_results,
+            Tab::History => &self.history_videos,
+            Tab::Saved => &self.saved_videos,
+        }
+    }
+
+    /// Set search results from platform search.
+    ///
+    /// # Arguments
+    /// * `videos` - Videos from search
+    ///
+    /// # Details
+    /// Stores search results, hides blocked channels, sorts by the Search tab's
+    /// sort mode and resets selected index.
+    pub fn set_search_results(&mut self, videos: Vec<Video>) {
+        self.search_results = self.view_for_tab(&videos, Tab::Search);
+        self.all_search_results = videos;
+        self.reset_selection(Tab::Search);
+    }
+
+    /// Record an edit of the Search tab query for live search.
+    ///
+    /// # Arguments
+    /// * `now` - Time of the keystroke
+    pub fn note_search_keystroke(&mut self, now: Instant) {
+        self.last_keystroke = Some(now);
+    }
+
+    /// Check if a live search should start.
+    ///
+    /// # Arguments
+    /// * `now` - Current time
+    ///
+    /// # Returns
+    /// * `bool` - True when live search is on, the Search tab is shown, no search
+    ///   is running and the query changed since the last search and then stayed
+    ///   the same for `live_search_delay`
+    pub fn live_search_due(&self, now: Instant) -> bool {
+        let (Some(delay), Some(keystroke)) = (self.live_search_delay, self.last_keystroke) else {
+            return false;
+        };
+        let query = self.search_query_global.trim();
+        self.active_tab == Tab::Search
+            && self.search_task.is_none()
+            && !query.is_empty()
+            && query != self.last_search_query.trim()
+            && now.saturating_duration_since(keystroke) >= delay
+    }
+
+    /// Check if finished search results no longer match the typed query.
+    ///
+    /// # Returns
+    /// * `bool` - True with live search on when the query was edited after the
+    ///   search started; the results are then dropped and a new search follows
+    pub fn search_results_stale(&self) -> bool {
+        self.live_search_delay.is_some()
+            && self.search_query_global.trim() != self.last_search_query.trim()
+    }
+
+    /// Store finished platform search results and report the outcome.
+    ///
+    /// # Arguments
+    /// * `videos` - Videos returned for `last_search_query`
+    ///
+    /// # Details
+    /// When nothing is left to show, sets a suggestion in the status bar. If the
+    /// query used operators (e.g. `channel:`), the plain free text is stored in
+    /// `search_retry_query` so it can be re-run with one key.
+    pub fn finish_search(&mut self, videos: Vec<Video>) {
+        self.set_search_results(videos);
+        self.search_retry_query = None;
+
+        if !self.search_results.is_empty() {
+            self.set_status(format!("Found {} videos", self.search_results.len()));
+            return;
+        }
+
+        let query = self.last_search_query.clone();
+        let parsed = ParsedQuery::parse(&query);
+        if !self.all_search_results.is_empty() {
+            self.set_status(format!(
+                "All {} results for '{}' are from blocked channels",
+                self.all_search_results.len(),
+                query
+            ));
+        } else if parsed.has_operators() && !parsed.text.is_empty() {
+            self.set_status(format!(
+                "No results for '{}'. Press Ctrl+R to search '{}' without operators",
+                query, parsed.text
+            ));
+            self.search_retry_query = Some(parsed.text);
+        } else {
+            self.set_status(format!(
+                "No results for '{}'. Try fewer or broader search terms",
+                query
+            ));
+        }
+    }
+
+    /// Append the next page of platform search results.
+    ///
+    /// # Arguments
+    /// * `videos` - Videos of the next page
+    ///
+    /// # Returns
+    /// * `usize` - Number of videos that weren't in the results yet
+    ///
+    /// # Details
+    /// Skips videos already listed, re-sorts with the Search tab's sort mode and
+    /// keeps the selection on the same video.
+    pub fn append_search_results(&mut self, videos: Vec<Video>) -> usize {
+        let before = self.all_search_results.len();
+        for video in videos {
+            if !self.all_search_results.iter().any(|v| v.id == video.id) {
+                self.all_search_results.push(video);
+            }
+        }
+        let selected_id = (self.active_tab == Tab::Search)
+            .then(|| self.selected_video_id())
+            .flatten();
+        self.search_results = self.view_for_tab(&self.all_search_results, Tab::Search);
+        if self.active_tab == Tab::Search {
+            self.reselect(selected_id.as_deref());
+        }
+        self.all_search_results.len() - before
+    }
+
+    /// Store the result of fetching the History tab's video details.
+    ///
+    /// # Arguments
+    /// * `video_ids` - Requested IDs (continue-watching first, then newest watched)
+    /// * `result` - Fetched videos or the fetch error
+    ///
+    /// # Details
+    /// On success, orders videos newest watched first with partially watched ones
+    /// on top. On failure, falls back to an ID-only list built from the local
+    /// history (`Video::id_only`), so the tab isn't empty while offline.
+    pub fn apply_history_fetch(&mut self, video_ids: &[String], result: Result<Vec<Video>>) {
+        let epoch = chrono::DateTime::<chrono::Utc>::UNIX_EPOCH;
+        match result {
+            Ok(mut videos) => {
+                // Sort by watch timestamp (newest first)
+                videos
+                    .sort_by_key(|v| cmp::Reverse(self.history.watched_at(&v.id).unwrap_or(epoch)));
+                // Partially watched videos form a "continue watching" section on top
+                let continue_ids = self.history.continue_watching_ids();
+                videos.sort_by_key(|v| {
+                    continue_ids
+                        .iter()
+                        .position(|id| id == &v.id)
+                        .unwrap_or(usize::MAX)
+                });
+                self.set_history_videos(videos);
+                self.set_status(format!(
+                    "Loaded {} watched videos",
+                    self.history_videos.len()
+                ));
+            }
+            Err(e) => {
+                let videos = video_ids
+                    .iter()
+                    .map(|id| {
+                        Video::id_only(id.clone(), self.history.watched_at(id).unwrap_or(epoch))
+                    })
+                    .collect();
+                self.set_history_videos(videos);
+                self.set_status(format!(
+                    "Failed to load history details ({}); showing offline list",
+                    e
+                ));
+            }
+        }
+    }
+
+    /// Set history videos.
+    ///
+    /// # Arguments
+    /// * `videos` - Videos from history
+    ///
+    /// # Details
+    /// Stores history videos, hides blocked channels, sorts by the History tab's
+    /// sort mode and resets selected index.
+    pub fn set_history_videos(&mut self, videos: Vec<Video>) {
+        self.history_videos = self.view_for_tab(&videos, Tab::History);
+        self.all_history_videos = videos;
+        self.reset_selection(Tab::History);
+    }
+
+    /// Save or unsave the selected video for later.
+    ///
+    /// # Returns
+    /// * `Option<bool>` - Whether the video is now saved, or None if nothing is selected
+    ///
+    /// # Details
+    /// Keeps the Saved tab's list in step, so it only needs fetching once.
+    /// On the Saved tab, unsaving removes the video from the list.
+    pub fn toggle_saved_selected(&mut self) -> Option<bool> {
+        let video = self.selected_video_from_tab()?.clone();
+        let selected_id = self.selected_video_id();
+        let saved = if self.saved.remove(&video.id) {
+            self.all_saved_videos.retain(|v| v.id != video.id);
+            false
+        } else {
+            self.saved.add(&video.id);
+            self.all_saved_videos.insert(0, video);
+            true
+        };
+        self.saved_videos = self.view_for_tab(&self.all_saved_videos, Tab::Saved);
+        if self.active_tab == Tab::Saved {
+            self.reselect(selected_id.as_deref());
+        }
+        Some(saved)
+    }
+
+    /// Get the saved IDs whose details haven't been fetched yet.
+    ///
+    /// # Returns
+    /// * `bool` - True if the Saved tab needs to fetch video details
+    pub fn saved_needs_fetch(&self) -> bool {
+        self.saved.ids().iter().any(|id| {
+            !self
+                .all_saved_videos
+                .iter()
+                .any(|video| &video.id == id && !video.details_missing)
+        })
+    }
+
+    /// Store the fetched details of the saved videos.
+    ///
+    /// # Arguments
+    /// * `videos` - Fetched videos (any order)
+    ///
+    /// # Details
+    /// Orders them most recently saved first, sorts the Saved tab view and
+    /// resets the selection when the Saved tab is active.
+    pub fn set_saved_videos(&mut self, mut videos: Vec<Video>) {
+        let ids = self.saved.ids();
+        videos.retain(|video| ids.contains(&video.id));
+        videos.sort_by_key(|video| ids.iter().position(|id| id == &video.id));
+        self.saved_videos = self.view_for_tab(&videos, Tab::Saved);
+        self.all_saved_videos = videos;
+        self.reset_selection(Tab::Saved);
+    }
+
+    /// Build an m3u playlist of the active tab's list.
+    ///
+    /// # Returns
+    /// * `String` - Extended m3u content
+    ///
+    /// # Details
+    /// Writes an `#EXTM3U` header, then an `#EXTINF:<seconds>,<title>` line and the
+    /// video URL for each video. Unknown durations are written as `-1`.
+    pub fn m3u_playlist(&self) -> String {
+        let mut playlist = String::from("#EXTM3U\n");
+        for video in self.get_current_video_list() {
+            let duration = if video.duration > 0 {
+                video.duration as i64
+            } else {
+                -1
+            };
+            let title = video.title.replace(['\r', '\n'], " ");
+            playlist.push_str(&format!("#EXTINF:{},{}\n{}\n", duration, title, video.url));
+        }
+        playlist
+    }
+
+    /// Export the active tab's list as an m3u playlist.
+    ///
+    /// # Arguments
+    /// * `path` - Playlist file to write
+    ///
+    /// # Returns
+    /// * `Result<usize>` - Number of entries written, or error
+    ///
+    /// # Details
+    /// The playlist can be played later with `mpv playlist.m3u`.
+    pub fn export_m3u(&self, path: &Path) -> Result<usize> {
+        let playlist = self.m3u_playlist();
+        fs::write(path, &playlist)
+            .with_context(|| format!("Failed to write playlist: {}", path.display()))?;
+        Ok(playlist
+            .lines()
+            .filter(|line| line.starts_with("#EXTINF:"))
+            .count())
+    }
+
+    /// Describe the active tab's list as plain text for screen readers.
+    ///
+    /// # Returns
+    /// * `String` - A heading line, then one labeled block per video
+    ///
+    /// # Details
+    /// Uses no box-drawing characters, colors or column alignment, so the text
+    /// reads linearly. Blocks are separated by a blank line.
+    pub fn accessible_text(&self) -> String {
+        let list = self.get_current_video_list();
+        let mut text = format!("{}: {} videos\n", self.active_tab.name(), list.len());
+        for (index, video) in list.iter().enumerate() {
+            let title = video.title.replace(['\r', '\n'], " ");
+            let views = crate::youtube::format_count(
+                video.view_count,
+                self.number_style,
+                &self.thousands_separator,
+            );
+            let watched = if self.history.is_watched(&video.id) {
+                "yes"
+            } else {
+                "no"
+            };
+            text.push_str(&format!(
+                "\nVideo {} of {}\n\
+                 Title: {}\n\
+                 Creator: {}\n\
+                 Duration: {}\n\
+                 Uploaded: {}\n\
+                 Views: {}\n\
+                 Watched: {}\n\
+                 URL: {}\n",
+                index + 1,
+                list.len(),
+                title,
+                video.channel,
+                format_human_duration(video.duration),
+                video.format_date(),
+                views,
+                watched,
+                self.video_url(video)
+            ));
+        }
+        text
+    }
+
+    /// Write the plain-text description of the active tab's list to a file.
+    ///
+    /// # Arguments
+    /// * `path` - Text file to write
+    ///
+    /// # Returns
+    /// * `Result<()>` - Success or error
+    pub fn export_accessible_text(&self, path: &Path) -> Result<()> {
+        fs::write(path, self.accessible_text())
+            .with_context(|| format!("Failed to write text export: {}", path.display()))
+    }
+
+    /// Get the currently selected video from the active tab's list.
+    ///
+    /// # Returns
+    /// * `Option<&Video>` - Selected video or None if list is empty
+    ///
+    /// # Details
+    /// Returns the selected video from the appropriate list based on active tab.
+    pub fn selected_video_from_tab(&self) -> Option<&Video> {
+        let list = self.get_current_video_list();
+        list.get(self.selected_index)
+    }
+
+    /// Get the URL of a video for copying and display.
+    ///
+    /// # Arguments
+    /// * `video` - Video to link to
+    ///
+    /// # Returns
+    /// * `String` - youtu.be link with `use_short_urls`, otherwise the watch URL
+    pub fn video_url(&self, video: &Video) -> String {
+        if self.use_short_urls {
+            video.short_url()
+        } else {
+            video.url.clone()
+        }
+    }
+
+    /// Get the URL of the selected video in the active tab.
+    ///
+    /// # Returns
+    /// * `Option<String>` - URL (see `video_url`), or None if the list is empty
+    pub fn selected_url(&self) -> Option<String> {
+        self.selected_video_from_tab()
+            .map(|video| self.video_url(video))
+    }
+}
+
+/// Check whether a video is likely a YouTube Short.
+///
+/// # Arguments
+/// * `video` - Video to check
+///
+/// # Returns
+/// * `bool` - True for videos of at most `SHORTS_MAX_DURATION` seconds
+///
+/// # Details
+/// The API doesn't say whether a video is a Short or expose its aspect
+/// ratio reliably, so the duration is used. A duration of 0 (live streams,
+/// unknown) doesn't count.
+pub fn is_short(video: &Video) -> bool {
+    video.duration > 0 && video.duration <= SHORTS_MAX_DURATION
+}
+
+/// Check whether a title looks like recommendation spam.
+///
+/// # Arguments
+/// * `title` - Video title
+/// * `min_len` - Minimum length in characters after trimming (0 disables the length check)
+///
+/// # Returns
+/// * `bool` - True if the title is shorter than `min_len` or is symbol-only (see `is_symbol_only`)
+pub fn is_spam_title(title: &str, min_len: usize) -> bool {
+    title.trim().chars().count() < min_len || is_symbol_only(title)
+}
+
+/// Check whether a title has no letters or digits, e.g. emoji-only or empty.
+///
+/// # Arguments
+/// * `title` - Video title
+///
+/// # Returns
+/// * `bool` - True if no character of the title is alphanumeric
+pub fn is_symbol_only(title: &str) -> bool {
+    !title.chars().any(char::is_alphanumeric)
+}
+
+/// Parse a human-friendly duration into seconds.
+///
+/// # Arguments
+/// * `input` - Duration such as "90s", "5m", "1h30m" or "1h 30m"; a bare number means seconds
+///
+/// # Returns
+/// * `Option<u64>` - Duration in seconds, or None if the input is invalid
+///
+/// # Details
+/// Accepts `h`, `m` and `s` units in any combination, each preceded by a number.
+pub fn parse_human_duration(input: &str) -> Option<u64> {
+    let input = input.trim().to_lowercase();
+    if input.is_empty() {
+        return None;
+    }
+    if input.chars().all(|c| c.is_ascii_digit()) {
+        return input.parse().ok();
+    }
+
+    let mut seconds = 0u64;
+    let mut current_num = String::new();
+
+    for ch in input.chars() {
+        match ch {
+            c if c.is_ascii_digit() => current_num.push(c),
+            ' ' => continue,
+            'h' | 'm' | 's' => {
+                let value: u64 = current_num.parse().ok()?;
+                let multiplier = match ch {
+                    'h' => 3600,
+                    'm' => 60,
+                    _ => 1,
+                };
+                seconds = seconds.checked_add(value.checked_mul(multiplier)?)?;
+                current_num.clear();
+            }
+            _ => return None,
+        }
+    }
+
+    // A trailing number without a unit is ambiguous (e.g. "1h30")
+    if !current_num.is_empty() {
+        return None;
+    }
+    Some(seconds)
+}
+
+/// Convert a typed 1-based list position to an index.
+///
+/// # Arguments
+/// * `input` - Typed position, e.g. "12"
+/// * `len` - Number of entries in the list
+///
+/// # Returns
+/// * `Option<usize>` - 0-based index clamped to the list, or None if the
+///   input isn't a number or the list is empty
+pub fn parse_goto_index(input: &str, len: usize) -> Option<usize> {
+    if len == 0 || input.is_empty() || !input.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    // Too many digits for usize still means "past the end"
+    let position = input.parse::<usize>().unwrap_or(usize::MAX);
+    Some(position.clamp(1, len) - 1)
+}
+
+/// Parse a view count.
+///
+/// # Arguments
+/// * `input` - Count such as "5000", "5,000", "10k", "1.5M" or "2B"
+///
+/// # Returns
+/// * `Option<u64>` - Count, or None if the input is invalid
+pub fn parse_count(input: &str) -> Option<u64> {
+    let input: String = input
+        .trim()
+        .to_lowercase()
+        .chars()
+        .filter(|&c| c != ',' && c != '_')
+        .collect();
+    let (number, multiplier) = match input.chars().last()? {
+        'k' => (&input[..input.len() - 1], 1e3),
+        'm' => (&input[..input.len() - 1], 1e6),
+        'b' => (&input[..input.len() - 1], 1e9),
+        _ => return input.parse().ok(),
+    };
+    let value: f64 = number.trim().parse().ok()?;
+    (value.is_finite() && value >= 0.0).then(|| (value * multiplier).round() as u64)
+}
+
+/// Parse a date for the published-after filter.
+///
+/// # Arguments
+/// * `input` - Date as `YYYY-MM-DD` or an RFC3339 timestamp
+///
+/// # Returns
+/// * `Option<String>` - RFC3339 timestamp (midnight UTC for plain dates), or None if invalid
+pub fn parse_filter_date(input: &str) -> Option<String> {
+    let input = input.trim();
+    if chrono::DateTime::parse_from_rfc3339(input).is_ok() {
+        return Some(input.to_string());
+    }
+    let date = chrono::NaiveDate::parse_from_str(input, "%Y-%m-%d").ok()?;
+    Some(format!("{}T00:00:00Z", date.format("%Y-%m-%d")))
+}
+
+/// Set a filter from editor input.
+///
+/// # Arguments
+/// * `filters` - Filter settings to update
+/// * `field` - Filter to set
+/// * `input` - Text typed in the editor; empty clears the filter
+///
+/// # Returns
+/// * `Result<(), String>` - Error message if the input is invalid (filters unchanged)
+fn set_filter(filters: &mut FilterSettings, field: FilterField, input: &str) -> Result<(), String> {
+    let input = input.trim();
+    let invalid = || {
+        format!(
+            "Invalid {} '{}' ({})",
+            field.label().to_lowercase(),
+            input,
+            field.hint()
+        )
+    };
+    let parse = |parse: fn(&str) -> Option<u64>| -> Result<Option<u64>, String> {
+        if input.is_empty() {
+            return Ok(None);
+        }
+        parse(input).map(Some).ok_or_else(invalid)
+    };
+
+    match field {
+        FilterField::Channel => {
+            filters.channel = (!input.is_empty()).then(|| input.to_string());
+        }
+        FilterField::MinDuration => filters.min_duration = parse(parse_human_duration)?,
+        FilterField::MaxDuration => filters.max_duration = parse(parse_human_duration)?,
+        FilterField::MinViews => filters.min_views = parse(parse_count)?,
+        FilterField::MaxViews => filters.max_views = parse(parse_count)?,
+        FilterField::AfterDate if input.is_empty() => filters.after_date = None,
+        FilterField::AfterDate => {
+            filters.after_date = Some(parse_filter_date(input).ok_or_else(invalid)?);
+        }
+    }
+    Ok(())
+}
+
+/// Format seconds as a compact human-friendly duration.
+///
+/// # Arguments
+/// * `seconds` - Duration in seconds
+///
+/// # Returns
+/// * `String` - Duration such as "1h30m", "5m" or "45s"
+pub fn format_human_duration(seconds: u64) -> String {
+    if seconds == 0 {
+        return "0s".to_string();
+    }
+    let hours = seconds / 3600;
+    let minutes = (seconds % 3600) / 60;
+    let secs = seconds % 60;
+
+    let mut result = String::new();
+    if hours > 0 {
+        result.push_str(&format!("{}h", hours));
+    }
+    if minutes > 0 {
+        result.push_str(&format!("{}m", minutes));
+    }
+    if secs > 0 {
+        result.push_str(&format!("{}s", secs));
+    }
+    result
+}
+
+/// Quote an argument so `split_args` turns it back into the same argument.
+///
+/// # Arguments
+/// * `arg` - Argument to quote
+///
+/// # Returns
+/// * `String` - The argument, single-quoted if it contains whitespace or quotes
+fn quote_arg(arg: &str) -> String {
+    if !arg.is_empty() && !arg.contains(|c: char| c.is_whitespace() || "'\"\\".contains(c)) {
+        return arg.to_string();
+    }
+    if !arg.contains('\'') {
+        return format!("'{}'", arg);
+    }
+    format!("\"{}\"", arg.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::youtube::Video;
+    use chrono::Utc;
+
+    fn create_test_video(id: &str, title: &str, channel: &str) -> Video {
+        Video::new(
+            id.to_string(),
+            title.to_string(),
+            channel.to_string(),
+            "channel_id".to_string(),
+            "Description".to_string(),
+            100,
+            Utc::now(),
+            "thumb".to_string(),
+            1000,
+        )
+    }
+
+    #[test]
+    fn test_app_new() {
+        let history = History::default();
+        let app = App::new(history, false);
+        assert_eq!(app.all_videos.len(), 0);
+        assert_eq!(app.selected_index, 0);
+        assert_eq!(app.mode, UiMode::List);
+    }
+
+    #[test]
+    fn test_app_set_videos() {
+        let history = History::default();
+        let mut app = App::new(history, false);
+        let videos = vec![
+            create_test_video("1", "Video 1", "Channel 1"),
+            create_test_video("2", "Video 2", "Channel 2"),
+        ];
+        app.set_videos(videos);
+        assert_eq!(app.all_videos.len(), 2);
+        assert_eq!(app.filtered_videos.len(), 2);
+    }
+
+    #[test]
+    fn test_app_search_filter() {
+        let history = History::default();
+        let mut app = App::new(history, false);
+        let videos = vec![
+            create_test_video("1", "Rust Tutorial", "Channel 1"),
+            create_test_video("2", "Python Guide", "Channel 2"),
+        ];
+        app.set_videos(videos);
+        app.mode = UiMode::Search;
+        app.search_queries[Tab::CurrentView.index()] = "Rust".to_string();
+        app.apply_filters();
+        assert_eq!(app.filtered_videos.len(), 1);
+        assert_eq!(app.filtered_videos[0].title, "Rust Tutorial");
+    }
+
+    #[test]
+    fn test_filter_query_per_tab() {
+        let mut app = App::new(History::default(), false);
+        let videos = vec![
+            create_test_video("1", "Rust Tutorial", "Channel 1"),
+            create_test_video("2", "Python Guide", "Channel 2"),
+        ];
+        app.set_videos(videos.clone());
+        app.set_search_results(videos);
+
+        app.start_search_input();
+        for c in "rust".chars() {
+            app.add_search_char(c);
+        }
+        assert_eq!(app.filtered_videos.len(), 1);
+        // Other tabs aren't filtered by the Current View query
+        assert_eq!(app.search_results.len(), 2);
+
+        app.mode = UiMode::List;
+        app.switch_tab(Tab::Search);
+        assert_eq!(app.search_query(), "");
+        app.switch_tab(Tab::CurrentView);
+        assert_eq!(app.search_query(), "rust");
+        assert_eq!(app.filtered_videos.len(), 1);
+    }
+
+    #[test]
+    fn test_switch_tab_restores_selection() {
+        let mut app = App::new(History::default(), false);
+        let videos: Vec<Video> = (0..5)
+            .map(|i| create_test_video(&i.to_string(), "Video", "Channel"))
+            .collect();
+        app.set_history_videos(videos.clone());
+        app.set_search_results(videos[..2].to_vec());
+
+        app.switch_tab(Tab::History);
+        app.move_down();
+        app.move_down();
+        app.move_down();
+        assert_eq!(app.selected_index, 3);
+
+        app.switch_tab(Tab::Search);
+        assert_eq!(app.selected_index, 0);
+        app.move_down();
+
+        app.switch_tab(Tab::History);
+        assert_eq!(app.selected_index, 3);
+
+        // Restored indices are clamped to the list length
+        app.selected_indices[Tab::Search.index()] = 9;
+        app.switch_tab(Tab::Search);
+        assert_eq!(app.selected_index, 1);
+
+        // New results for a tab in the background start it at the top
+        app.set_history_videos(videos);
+        assert_eq!(app.selected_index, 1);
+        app.switch_tab(Tab::History);
+        assert_eq!(app.selected_index, 0);
+    }
+
+    #[test]
+    fn test_live_search_debounce() {
+        let mut app = App::new(History::default(), false);
+        app.switch_tab(Tab::Search);
+        let start = Instant::now();
+        app.search_query_global = "rust".to_string();
+        app.note_search_keystroke(start);
+        let later = start + Duration::from_millis(500);
+
+        // Off unless a delay is configured
+        assert!(!app.live_search_due(later));
+        app.live_search_delay = Some(Duration::from_millis(400));
+        assert!(!app.live_search_due(start + Duration::from_millis(100)));
+        assert!(app.live_search_due(later));
+
+        // Not again for the query that was just searched
+        app.last_search_query = "rust".to_string();
+        assert!(!app.live_search_due(later));
+        assert!(!app.search_results_stale());
+
+        // Typing while the search runs makes its results stale
+        app.search_query_global = "rust tui".to_string();
+        assert!(app.search_results_stale());
+        assert!(app.live_search_due(later));
+
+        // Only on the Search tab
+        app.switch_tab(Tab::History);
+        assert!(!app.live_search_due(later));
+    }
+
+    #[test]
+    fn test_remove_selected_from_history() {
+        let mut history = History::default();
+        for id in ["1", "2", "3"] {
+            history.mark_watched(id);
+        }
+        let mut app = App::new(history, false);
+        app.set_history_videos(vec![
+            create_test_video("1", "One", "Channel"),
+            create_test_video("2", "Two", "Channel"),
+            create_test_video("3", "Three", "Channel"),
+        ]);
+
+        // Only on the History tab
+        assert_eq!(app.remove_selected_from_history(), None);
+
+        app.switch_tab(Tab::History);
+        app.selected_index = 2;
+        let last = app.selected_video_from_tab().unwrap().clone();
+        assert_eq!(app.remove_selected_from_history(), Some(last.title));
+        assert!(!app.history.is_watched(&last.id));
+        assert_eq!(app.history.watched_count(), 2);
+        assert_eq!(app.history_videos.len(), 2);
+        assert_eq!(app.selected_index, 1);
+
+        app.selected_index = 0;
+        let remaining = app.history_videos[1].id.clone();
+        assert!(app.remove_selected_from_history().is_some());
+        assert_eq!(app.history_videos.len(), 1);
+        assert_eq!(app.selected_video_from_tab().unwrap().id, remaining);
+    }
+
+    #[test]
+    fn test_vim_jump_keys() {
+        let mut app = App::new(History::default(), false);
+        app.set_videos(
+            (0..5)
+                .map(|i| create_test_video(&i.to_string(), "Video", "Channel"))
+                .collect(),
+        );
+        let now = Instant::now();
+
+        // G
+        app.select_last();
+        assert_eq!(app.selected_index, 4);
+
+        // gg
+        assert!(!app.press_g(now));
+        assert_eq!(app.selected_index, 4);
+        assert!(app.press_g(now + Duration::from_millis(200)));
+        assert_eq!(app.selected_index, 0);
+
+        // g, another key, g: no jump
+        app.select_last();
+        app.press_g(now);
+        app.cancel_pending_key();
+        assert!(!app.press_g(now + Duration::from_millis(200)));
+        assert_eq!(app.selected_index, 4);
+
+        // Too slow
+        app.cancel_pending_key();
+        app.press_g(now);
+        assert!(!app.press_g(now + Duration::from_secs(2)));
+        assert_eq!(app.selected_index, 4);
+    }
+
+    #[test]
+    fn test_filter_narrows_search_results() {
+        let mut app = App::new(History::default(), false);
+        app.set_search_results(vec![
+            create_test_video("1", "Rust Tutorial", "Channel 1"),
+            create_test_video("2", "Python Guide", "Channel 2"),
+            create_test_video("3", "Advanced Rust", "Channel 3"),
+        ]);
+        app.switch_tab(Tab::Search);
+        app.move_down();
+
+        // On the Search tab `/` starts in Search YouTube; Tab switches to filtering
+        app.start_search_input();
+        app.toggle_search_target();
+        for ch in "rust".chars() {
+            app.add_search_char(ch);
+        }
+        assert!(app.search_query_global.is_empty());
+        let ids: Vec<&str> = app.search_results.iter().map(|v| v.id.as_str()).collect();
+        assert_eq!(ids.len(), 2);
+        assert!(!ids.contains(&"2"));
+        assert_eq!(app.all_search_results.len(), 3);
+        assert!(!app.showing_recent_searches());
+
+        for _ in 0..4 {
+            app.remove_search_char();
+        }
+        assert_eq!(app.search_results.len(), 3);
+    }
+
+    #[test]
+    fn test_app_move_selection() {
+        let history = History::default();
+        let mut app = App::new(history, false);
+        let videos = vec![
+            create_test_video("1", "Video 1", "Channel 1"),
+            create_test_video("2", "Video 2", "Channel 2"),
+            create_test_video("3", "Video 3", "Channel 3"),
+        ];
+        app.set_videos(videos);
+        assert_eq!(app.selected_index, 0);
+
+        app.move_down();
+        assert_eq!(app.selected_index, 1);
+
+        app.move_up();
+        assert_eq!(app.selected_index, 0);
+
+        app.move_up(); // Should wrap to end
+        assert_eq!(app.selected_index, 2);
+    }
+
+    #[test]
+    fn test_app_hide_watched() {
+        let mut history = History::default();
+        history.mark_watched("1");
+        let mut app = App::new(history, true);
+        let videos = vec![
+            create_test_video("1", "Video 1", "Channel 1"),
+            create_test_video("2", "Video 2", "Channel 2"),
+        ];
+        app.set_videos(videos);
+        assert_eq!(app.filtered_videos.len(), 1);
+        assert_eq!(app.filtered_videos[0].id, "2");
+    }
+
+    #[test]
+    fn test_mark_watched_from_search_updates_current_view() {
+        let history = History::default();
+        let mut app = App::new(history, true);
+        let videos = vec![
+            create_test_video("1", "Video 1", "Channel 1"),
+            create_test_video("2", "Video 2", "Channel 2"),
+        ];
+        app.set_videos(videos.clone());
+        assert_eq!(app.filtered_videos.len(), 2);
+
+        app.switch_tab(Tab::Search);
+        app.set_search_results(vec![videos[0].clone()]);
+        app.mark_selected_watched();
+
+        assert_eq!(app.filtered_videos.len(), 1);
+        assert_eq!(app.filtered_videos[0].id, "2");
+    }
+
+    #[test]
+    fn test_sort_mode_from_str() {
+        assert_eq!("views".parse::<SortMode>().unwrap(), SortMode::Views);
+        assert_eq!(
+            "watch_recency".parse::<SortMode>().unwrap(),
+            SortMode::WatchRecency
+        );
+        assert!("sideways".parse::<SortMode>().is_err());
+    }
+
+    #[test]
+    fn test_play_selected_set_dispatch() {
+        let mut app = App::new(History::default(), false);
+        app.set_videos(vec![
+            create_test_video("1", "One", "Channel"),
+            create_test_video("2", "Two", "Channel"),
+        ]);
+        assert_eq!(app.toggle_mark_selected(), Some(true));
+        app.selected_index = 1;
+        app.toggle_mark_selected();
+        assert!(app.is_marked("1") && app.is_marked("2"));
+
+        // Playlist: one player call with every URL
+        let mut calls: Vec<Vec<String>> = Vec::new();
+        let opened = app
+            .play_selected_set(|urls, _| {
+                calls.push(urls.to_vec());
+                Ok(())
+            })
+            .unwrap();
+        assert_eq!(opened, 2);
+        assert_eq!(calls.len(), 1);
+        assert_eq!(calls[0].len(), 2);
+        assert!(app.marked.is_empty());
+        assert!(app.history.is_watched("1") && app.history.is_watched("2"));
+
+        // Windows: one call per video; a failure keeps the rest marked
+        app.open_mode = OpenMode::Windows;
+        app.toggle_mark_selected();
+        app.selected_index = 0;
+        app.toggle_mark_selected();
+        let mut calls = 0;
+        let result = app.play_selected_set(|_, _| {
+            calls += 1;
+            if calls == 2 {
+                Err(anyhow::anyhow!("mpv missing"))
+            } else {
+                Ok(())
+            }
+        });
+        assert!(result.is_err());
+        assert_eq!(calls, 2);
+        assert_eq!(app.marked.len(), 1);
+        assert_eq!(app.marked[0].id, "1");
+    }
+
+    #[test]
+    fn test_toggle_channel_filter() {
+        let mut app = App::new(History::default(), false);
+        app.set_videos(vec![
+            create_test_video("1", "One", "Alpha"),
+            create_test_video("2", "Two", "Beta"),
+            create_test_video("3", "Three", "Alpha"),
+        ]);
+        let all = app.filtered_videos.len();
+        app.selected_index = app
+            .filtered_videos
+            .iter()
+            .position(|v| v.id == "3")
+            .unwrap();
+
+        assert_eq!(app.toggle_channel_filter(), Some(true));
+        assert_eq!(app.filters.channel.as_deref(), Some("Alpha"));
+        assert_eq!(app.active_tab(), Tab::CurrentView);
+        assert_eq!(app.filtered_videos.len(), 2);
+        assert!(app.filtered_videos.iter().all(|v| v.channel == "Alpha"));
+        assert_eq!(app.selected_video_from_tab().unwrap().id, "3");
+
+        assert_eq!(app.toggle_channel_filter(), Some(false));
+        assert_eq!(app.filters.channel, None);
+        assert_eq!(app.filtered_videos.len(), all);
+    }
+
+    #[test]
+    fn test_toggle_saved_selected() {
+        let mut app = App::new(History::default(), false);
+        app.set_videos(vec![
+            create_test_video("1", "One", "Channel"),
+            create_test_video("2", "Two", "Channel"),
+        ]);
+        assert_eq!(app.toggle_saved_selected(), Some(true));
+        app.selected_index = 1;
+        assert_eq!(app.toggle_saved_selected(), Some(true));
+        assert!(app.saved.contains("1") && app.saved.contains("2"));
+        assert!(!app.saved_needs_fetch());
+        assert!(app.video_locations("1").contains(&"Saved"));
+
+        app.switch_tab(Tab::Saved);
+        assert_eq!(app.get_current_video_list().len(), 2);
+        let first = app.selected_video_from_tab().unwrap().id.clone();
+        assert_eq!(app.toggle_saved_selected(), Some(false));
+        assert!(!app.saved.contains(&first));
+        assert_eq!(app.get_current_video_list().len(), 1);
+
+        // IDs saved in an earlier session need their details fetched
+        app.saved.add("3");
+        assert!(app.saved_needs_fetch());
+        app.set_saved_videos(vec![
+            create_test_video("3", "Three", "Channel"),
+            create_test_video("9", "Unsaved", "Channel"),
+        ]);
+        assert_eq!(app.all_saved_videos.len(), 1);
+        assert_eq!(app.all_saved_videos[0].id, "3");
+    }
+
+    #[test]
+    fn test_set_status_marks_dirty() {
+        let mut app = App::new(History::default(), false);
+        assert!(app.dirty);
+        app.dirty = false;
+        app.set_status("Done".to_string());
+        assert!(app.dirty);
+    }
+
+    #[test]
+    fn test_move_by_page_clamps() {
+        let mut app = App::new(History::default(), false);
+        app.set_videos(
+            (0..10)
+                .map(|i| create_test_video(&i.to_string(), "Title", "Channel"))
+                .collect(),
+        );
+        app.move_by_page(true, 4);
+        assert_eq!(app.selected_index, 4);
+        app.move_by_page(true, 4);
+        assert_eq!(app.selected_index, 8);
+        // Stops at the last item instead of wrapping
+        app.move_by_page(true, 4);
+        assert_eq!(app.selected_index, 9);
+        app.move_by_page(false, 4);
+        assert_eq!(app.selected_index, 5);
+        app.move_by_page(false, 4);
+        app.move_by_page(false, 4);
+        assert_eq!(app.selected_index, 0);
+
+        app.select_last();
+        assert_eq!(app.selected_index, 9);
+        app.select_first();
+        assert_eq!(app.selected_index, 0);
+
+        // Empty list stays put
+        let mut empty = App::new(History::default(), false);
+        empty.move_by_page(true, 4);
+        empty.select_last();
+        assert_eq!(empty.selected_index, 0);
+    }
+
+    #[test]
+    fn test_selected_url() {
+        let mut app = App::new(History::default(), false);
+        assert_eq!(app.selected_url(), None);
+        app.set_videos(vec![
+            create_test_video("1", "One", "Channel"),
+            create_test_video("2", "Two", "Channel"),
+        ]);
+        app.selected_index = 1;
+        let expected = app.filtered_videos[1].url.clone();
+        assert_eq!(app.selected_url(), Some(expected));
+
+        app.use_short_urls = true;
+        assert_eq!(app.selected_url().as_deref(), Some("https://youtu.be/2"));
+    }
+
+    #[test]
+    fn test_open_confirmation_threshold() {
+        let mut app = App::new(History::default(), false);
+        app.open_confirm_threshold = 1;
+        app.marked = vec![create_test_video("1", "One", "Channel")];
+        assert!(!app.needs_open_confirmation());
+        app.marked.push(create_test_video("2", "Two", "Channel"));
+        assert!(app.needs_open_confirmation());
+        app.open_confirm_threshold = 0;
+        assert!(!app.needs_open_confirmation());
+    }
+
+    #[test]
+    fn test_accelerated_step() {
+        let mut app = App::new(History::default(), false);
+        let start = Instant::now();
+        let tick = Duration::from_millis(30);
+
+        // Disabled: always one step
+        for i in 0..30 {
+            assert_eq!(app.accelerated_step(true, start + tick * i), 1);
+        }
+
+        app.scroll_acceleration = true;
+        let steps: Vec<usize> = (0..45)
+            .map(|i| app.accelerated_step(true, start + tick * i))
+            .collect();
+        assert!(steps[..10].iter().all(|&s| s == 1));
+        assert!(steps[10..20].iter().all(|&s| s == 2));
+        assert!(steps[20..30].iter().all(|&s| s == 4));
+        assert!(steps[30..].iter().all(|&s| s == 8));
+
+        // A pause or a change of direction starts over
+        let later = start + tick * 45 + Duration::from_millis(500);
+        assert_eq!(app.accelerated_step(true, later), 1);
+        app.move_streak = 25;
+        assert_eq!(app.accelerated_step(false, later + tick), 1);
+    }
+
+    #[test]
+    fn test_move_by_key_clamps_large_steps() {
+        let mut app = App::new(History::default(), false);
+        app.scroll_acceleration = true;
+        app.set_videos(
+            (0..20)
+                .map(|i| create_test_video(&i.to_string(), "Video", "Channel"))
+                .collect(),
+        );
+        let start = Instant::now();
+        app.last_move = Some((start, true));
+        app.move_streak = 30;
+        app.selected_index = 15;
+        app.move_by_key(true, start + Duration::from_millis(20));
+        assert_eq!(app.selected_index, 19);
+    }
+
+    #[test]
+    fn test_append_search_results_keeps_selection() {
+        let mut app = App::new(History::default(), false);
+        app.switch_tab(Tab::Search);
+        app.set_initial_sort(Tab::Search, SortMode::Creator);
+        app.set_search_results(vec![
+            create_test_video("1", "One", "Bob"),
+            create_test_video("2", "Two", "Dave"),
+        ]);
+        app.selected_index = 1;
+
+        let added = app.append_search_results(vec![
+            create_test_video("2", "Two", "Dave"),
+            create_test_video("3", "Three", "Alice"),
+            create_test_video("4", "Four", "Carol"),
+        ]);
+        assert_eq!(added, 2);
+        assert_eq!(app.search_results.len(), 4);
+        // Sorted by creator, the selected video moved but stays selected
+        assert_eq!(app.selected_video_from_tab().unwrap().id, "2");
+        assert_eq!(app.selected_index, 3);
+    }
+
+    #[test]
+    fn test_video_locations() {
+        let mut history = History::default();
+        history.mark_watched("both");
+        let mut app = App::new(history, false);
+        app.set_videos(vec![
+            create_test_video("both", "Both", "Channel"),
+            create_test_video("trending", "Trending", "Channel"),
+        ]);
+        app.set_search_results(vec![create_test_video("both", "Both", "Channel")]);
+        app.queue.push(create_test_video("both", "Both", "Channel"));
+
+        assert_eq!(
+            app.video_locations("both"),
+            ["Current View", "Search", "History", "Queue"]
+        );
+        assert_eq!(app.video_locations("trending"), ["Current View"]);
+        assert!(app.video_locations("missing").is_empty());
+    }
+
+    #[test]
+    fn test_clear_history_needs_two_presses() {
+        let mut history = History::default();
+        history.mark_watched("1");
+        let mut app = App::new(history, false);
+        app.switch_tab(Tab::History);
+        app.set_history_videos(vec![create_test_video("1", "Watched", "Channel")]);
+
+        let start = Instant::now();
+        assert!(!app.request_clear_history(start));
+        assert!(app.clear_history_armed_at.is_some());
+        assert_eq!(app.history.watched_count(), 1);
+
+        // Too late: arms again instead of clearing
+        let late = start + CLEAR_HISTORY_WINDOW + Duration::from_millis(1);
+        assert!(!app.request_clear_history(late));
+        assert_eq!(app.history.watched_count(), 1);
+
+        assert!(app.request_clear_history(late + Duration::from_millis(500)));
+        assert_eq!(app.history.watched_count(), 0);
+        assert!(app.history_videos.is_empty() && app.all_history_videos.is_empty());
+        assert!(app.clear_history_armed_at.is_none());
+    }
+
+    #[test]
+    fn test_prefill_last_search() {
+        let mut app = App::new(History::default(), false);
+        assert!(!app.prefill_last_search(None));
+        assert!(!app.prefill_last_search(Some("   ")));
+        assert!(app.search_query_global.is_empty());
+
+        assert!(app.prefill_last_search(Some("  rust tui ")));
+        assert_eq!(app.search_query_global, "rust tui");
+        assert_eq!(app.active_tab(), Tab::CurrentView);
+    }
+
+    #[test]
+    fn test_persist_state_keeps_last_search() {
+        let mut app = App::new(History::default(), false);
+        app.search_query_global = "rust tui".to_string();
+        app.persist_state();
+        assert_eq!(app.history.last_search(), Some("rust tui"));
+
+        // An empty query at exit isn't stored
+        app.search_query_global.clear();
+        app.persist_state();
+        assert_eq!(app.history.last_search(), Some("rust tui"));
+    }
+
+    #[test]
+    fn test_accessible_text() {
+        let mut history = History::default();
+        history.mark_watched("2");
+        let mut app = App::new(history, false);
+        app.set_initial_sort(Tab::CurrentView, SortMode::Relevance);
+        let mut first = create_test_video("1", "First", "Alice");
+        first.duration = 95;
+        first.view_count = 1500;
+        app.set_videos(vec![first, create_test_video("2", "Second\nline", "Bob")]);
+
+        let text = app.accessible_text();
+        assert!(text.starts_with("Current View: 2 videos\n\nVideo 1 of 2\nTitle: First\n"));
+        assert!(text.contains("Creator: Alice\nDuration: 1m35s\n"));
+        assert!(text.contains("Views: 1.5K\nWatched: no\n"));
+        assert!(text.contains("Video 2 of 2\nTitle: Second line\nCreator: Bob\n"));
+        assert!(text.contains("Watched: yes\nURL: https://www.youtube.com/watch?v=2\n"));
+        assert!(!text.contains(['│', '─', '┌']));
+    }
+
+    #[test]
+    fn test_is_spam_title() {
+        assert!(!is_spam_title("Rust in 100 seconds", 5));
+        assert!(!is_spam_title("  Hello  ", 5));
+        assert!(is_spam_title("  Hey  ", 5));
+        assert!(is_spam_title("", 1));
+        assert!(is_spam_title("🔥🔥🔥🔥🔥🔥", 1));
+        assert!(is_spam_title("!!! ??? !!!", 1));
+        assert!(!is_spam_title("日本語", 3));
+        // A length of 0 only turns off the length check
+        assert!(!is_spam_title("a", 0));
+        assert!(is_spam_title("", 0));
+        assert!(is_spam_title("🔥", 0));
+    }
+
+    #[test]
+    fn test_is_symbol_only() {
+        assert!(is_symbol_only(""));
+        assert!(is_symbol_only("  🔥 🔥  "));
+        assert!(is_symbol_only("!!! ??? !!!"));
+        assert!(!is_symbol_only("🔥 1 🔥"));
+        assert!(!is_symbol_only("日本語"));
+    }
+
+    #[test]
+    fn test_spam_titles_filtered() {
+        let mut app = App::new(History::default(), false);
+        app.min_title_len = 3;
+        app.set_videos(vec![
+            create_test_video("1", "Good video", "Channel"),
+            create_test_video("2", "🔥🔥🔥", "Channel"),
+            create_test_video("3", "ab", "Channel"),
+            create_test_video("4", "abc", "Channel"),
+        ]);
+        let ids: Vec<&str> = app.filtered_videos.iter().map(|v| v.id.as_str()).collect();
+        assert_eq!(ids.len(), 2);
+        assert!(ids.contains(&"1") && ids.contains(&"4"));
+    }
+
+    #[test]
+    fn test_merge_videos_keeps_selection() {
+        let mut app = App::new(History::default(), false);
+        app.set_initial_sort(Tab::CurrentView, SortMode::Relevance);
+        app.set_videos(vec![
+            create_test_video("a", "A", "Channel"),
+            create_test_video("b", "B", "Channel"),
+        ]);
+        app.selected_index = 1;
+
+        let mut updated = create_test_video("b", "B", "Channel");
+        updated.view_count = 9999;
+        let added = app.merge_videos(vec![
+            create_test_video("c", "C", "Channel"),
+            updated,
+            create_test_video("c", "C", "Channel"),
+        ]);
+
+        assert_eq!(added, 1);
+        let ids: Vec<&str> = app.filtered_videos.iter().map(|v| v.id.as_str()).collect();
+        assert_eq!(ids, ["c", "a", "b"]);
+        assert_eq!(app.selected_index, 2);
+        assert_eq!(app.selected_video_from_tab().unwrap().view_count, 9999);
+    }
+
+    #[test]
+    fn test_sort_mode_config_str_round_trip() {
+        for mode in [
+            SortMode::Date,
+            SortMode::Views,
+            SortMode::UploadDate,
+            SortMode::Creator,
+            SortMode::Relevance,
+            SortMode::WatchRecency,
+        ] {
+            assert_eq!(mode.as_config_str().parse::<SortMode>().unwrap(), mode);
+        }
+
+        let mut app = App::new(History::default(), false);
+        assert_eq!(app.history.sort_mode("current"), None);
+        app.cycle_sort_mode();
+        assert_eq!(app.history.sort_mode("current"), Some("upload_date"));
+    }
+
+    #[test]
+    fn test_initial_sort_per_source() {
+        let mut history = History::default();
+        history.mark_watched("b");
+        let mut app = App::new(history, false);
+        app.set_initial_sort(Tab::CurrentView, SortMode::Views);
+        app.set_initial_sort(Tab::Search, SortMode::Relevance);
+        app.set_initial_sort(Tab::History, SortMode::WatchRecency);
+
+        let mut low = create_test_video("a", "Low", "Channel");
+        low.view_count = 10;
+        let mut high = create_test_video("b", "High", "Channel");
+        high.view_count = 1000;
+
+        // Trending: sorted by views
+        app.set_videos(vec![low.clone(), high.clone()]);
+        assert_eq!(app.filtered_videos[0].id, "b");
+
+        // Search: API order kept
+        app.switch_tab(Tab::Search);
+        app.set_search_results(vec![low.clone(), high.clone()]);
+        assert_eq!(app.sort_mode(), SortMode::Relevance);
+        assert_eq!(app.search_results[0].id, "a");
+
+        // History: watched video first
+        app.switch_tab(Tab::History);
+        app.set_history_videos(vec![low, high]);
+        assert_eq!(app.sort_mode(), SortMode::WatchRecency);
+        assert_eq!(app.history_videos[0].id, "b");
+    }
+
+    #[test]
+    fn test_sort_mode_remembered_per_tab() {
+        let mut app = App::new(History::default(), false);
+        app.set_initial_sort(Tab::CurrentView, SortMode::Date);
+        app.set_initial_sort(Tab::Search, SortMode::Relevance);
+
+        app.cycle_sort_mode();
+        assert_eq!(app.sort_mode(), SortMode::Views);
+
+        app.switch_tab(Tab::Search);
+        assert_eq!(app.sort_mode(), SortMode::Relevance);
+
+        app.switch_tab(Tab::CurrentView);
+        assert_eq!(app.sort_mode(), SortMode::Views);
+    }
+
+    #[test]
+    fn test_parse_human_duration() {
+        assert_eq!(parse_human_duration("90s"), Some(90));
+        assert_eq!(parse_human_duration("5m"), Some(300));
+        assert_eq!(parse_human_duration("1h30m"), Some(5400));
+        assert_eq!(parse_human_duration("1h 30m 15s"), Some(5415));
+        assert_eq!(parse_human_duration("120"), Some(120));
+        assert_eq!(parse_human_duration(""), None);
+        assert_eq!(parse_human_duration("abc"), None);
+        assert_eq!(parse_human_duration("5x"), None);
+        assert_eq!(parse_human_duration("m"), None);
+        assert_eq!(parse_human_duration("1h30"), None);
+    }
+
+    #[test]
+    fn test_format_human_duration() {
+        assert_eq!(format_human_duration(5400), "1h30m");
+        assert_eq!(format_human_duration(300), "5m");
+        assert_eq!(format_human_duration(90), "1m30s");
+        assert_eq!(format_human_duration(0), "0s");
+    }
+
+    #[test]
+    fn test_hide_shorts() {
+        let mut history = History::default();
+        history.mark_watched("600");
+        let mut app = App::new(history, false);
+        app.set_videos(
+            [45, 61, 600, 0]
+                .into_iter()
+                .map(|duration| {
+                    let mut video = create_test_video(&duration.to_string(), "Video", "Channel");
+                    video.duration = duration;
+                    video
+                })
+                .collect(),
+        );
+        assert_eq!(app.filtered_videos.len(), 4);
+
+        app.toggle_hide_shorts();
+        let mut ids: Vec<&str> = app.filtered_videos.iter().map(|v| v.id.as_str()).collect();
+        ids.sort();
+        assert_eq!(ids, ["0", "600", "61"]);
+
+        // Composes with hide_watched
+        app.toggle_hide_watched();
+        let mut ids: Vec<&str> = app.filtered_videos.iter().map(|v| v.id.as_str()).collect();
+        ids.sort();
+        assert_eq!(ids, ["0", "61"]);
+
+        app.toggle_hide_shorts();
+        assert_eq!(app.filtered_videos.len(), 3);
+    }
+
+    #[test]
+    fn test_view_count_filters() {
+        let mut app = App::new(History::default(), false);
+        let mut videos: Vec<Video> = (1..=3)
+            .map(|i| create_test_video(&i.to_string(), "Video", "Channel"))
+            .collect();
+        videos[0].view_count = 10;
+        videos[1].view_count = 1_000;
+        videos[2].view_count = 100_000;
         app.set_videos(videos);
+
+        // Both unset: no-op
+        assert_eq!(app.filtered_videos.len(), 3);
+
+        app.filters.min_views = Some(1_000);
+        app.apply_filters();
+        assert_eq!(app.filtered_videos.len(), 2);
+        assert!(app.filtered_videos.iter().all(|v| v.view_count >= 1_000));
+
+        app.filters.max_views = Some(1_000);
+        app.apply_filters();
         assert_eq!(app.filtered_videos.len(), 1);
         assert_eq!(app.filtered_videos[0].id, "2");
+
+        // A minimum above every video empties the list
+        app.filters.min_views = Some(u64::MAX);
+        app.filters.max_views = None;
+        app.apply_filters();
+        assert!(app.filtered_videos.is_empty());
+        assert!(app.selected_video_from_tab().is_none());
+    }
+
+    #[test]
+    fn test_filter_input_commit() {
+        let mut app = App::new(History::default(), false);
+        app.start_filter_input(FilterField::MinDuration);
+        assert_eq!(app.selected_filter_field(), FilterField::MinDuration);
+        app.filter_input.as_mut().unwrap().buffer = "oops".to_string();
+        app.commit_filter_input();
+        assert!(app.filter_input.as_ref().unwrap().error.is_some());
+        assert!(
+            app.status_message
+                .as_deref()
+                .is_some_and(|status| status.contains("Invalid min duration"))
+        );
+        assert_eq!(app.filters.min_duration, None);
+
+        app.filter_input.as_mut().unwrap().buffer = "5m".to_string();
+        app.commit_filter_input();
+        assert!(app.filter_input.is_none());
+        assert_eq!(app.filters.min_duration, Some(300));
+
+        app.start_filter_input(FilterField::MinDuration);
+        assert_eq!(app.filter_input.as_ref().unwrap().buffer, "5m");
+        app.filter_input.as_mut().unwrap().buffer.clear();
+        app.commit_filter_input();
+        assert_eq!(app.filters.min_duration, None);
+    }
+
+    #[test]
+    fn test_filter_editor_fields() {
+        let mut app = App::new(History::default(), false);
+        app.move_filter_selection(false);
+        assert_eq!(app.selected_filter_field(), FilterField::AfterDate);
+        app.move_filter_selection(true);
+        assert_eq!(app.selected_filter_field(), FilterField::Channel);
+
+        for (field, input) in [
+            (FilterField::Channel, "  Rust  "),
+            (FilterField::MaxViews, "1.5M"),
+            (FilterField::AfterDate, "2024-03-01"),
+        ] {
+            app.start_filter_input(field);
+            app.filter_input.as_mut().unwrap().buffer = input.to_string();
+            app.commit_filter_input();
+            assert!(app.filter_input.is_none());
+        }
+        assert_eq!(app.filters.channel.as_deref(), Some("Rust"));
+        assert_eq!(app.filters.max_views, Some(1_500_000));
+        assert_eq!(
+            app.filters.after_date.as_deref(),
+            Some("2024-03-01T00:00:00Z")
+        );
+
+        for (field, input) in [
+            (FilterField::MinViews, "lots"),
+            (FilterField::AfterDate, "2024-13-01"),
+        ] {
+            app.start_filter_input(field);
+            app.filter_input.as_mut().unwrap().buffer = input.to_string();
+            app.commit_filter_input();
+            assert!(app.filter_input.as_ref().unwrap().error.is_some());
+        }
+        assert_eq!(app.filters.min_views, None);
+        assert_eq!(
+            app.filters.after_date.as_deref(),
+            Some("2024-03-01T00:00:00Z")
+        );
+    }
+
+    #[test]
+    fn test_parse_count() {
+        assert_eq!(parse_count("5000"), Some(5000));
+        assert_eq!(parse_count("5,000"), Some(5000));
+        assert_eq!(parse_count("10k"), Some(10_000));
+        assert_eq!(parse_count("1.5M"), Some(1_500_000));
+        assert_eq!(parse_count("2b"), Some(2_000_000_000));
+        assert_eq!(parse_count(""), None);
+        assert_eq!(parse_count("k"), None);
+        assert_eq!(parse_count("-5k"), None);
+        assert_eq!(parse_count("12x"), None);
+    }
+
+    #[test]
+    fn test_blocked_channels_hidden_in_all_tabs() {
+        let mut app = App::new(History::default(), false);
+        app.blocked_channels = vec!["blocked_id".to_string()];
+
+        let mut blocked = create_test_video("1", "Blocked", "Spam Channel");
+        blocked.channel_id = "blocked_id".to_string();
+        let allowed = create_test_video("2", "Allowed", "Good Channel");
+
+        app.set_videos(vec![blocked.clone(), allowed.clone()]);
+        assert_eq!(app.filtered_videos.len(), 1);
+
+        app.set_search_results(vec![blocked.clone(), allowed.clone()]);
+        assert_eq!(app.search_results.len(), 1);
+        assert_eq!(app.search_results[0].id, "2");
+
+        app.set_history_videos(vec![blocked, allowed]);
+        assert_eq!(app.history_videos.len(), 1);
+        assert_eq!(app.history_videos[0].id, "2");
+    }
+
+    #[test]
+    fn test_now_playing_records_progress() {
+        use crate::mpv_ipc::PlaybackStatus;
+        let mut app = App::new(History::default(), false);
+        let mut status = PlaybackStatus {
+            title: "Video 1".to_string(),
+            position: 120.7,
+            duration: 0.0,
+            paused: false,
+            video_id: Some("1".to_string()),
+        };
+        // Duration not known yet
+        app.set_now_playing(Some(status.clone()));
+        assert!(app.history.progress("1").is_none());
+
+        status.duration = 600.0;
+        app.set_now_playing(Some(status));
+        let progress = app.history.progress("1").unwrap();
+        assert_eq!((progress.position, progress.duration), (120, 600));
+        assert!(progress.is_resumable());
+    }
+
+    #[tokio::test]
+    async fn test_status_spinner() {
+        let mut app = App::new(History::default(), false);
+        app.set_status("Searching YouTube...".to_string());
+        app.dirty = false;
+        app.tick_spinner();
+        assert!(!app.dirty);
+        assert_eq!(
+            app.status_with_spinner().as_deref(),
+            Some("Searching YouTube...")
+        );
+
+        app.transcript_task = Some(tokio::spawn(std::future::pending()));
+        assert_eq!(
+            app.status_with_spinner().as_deref(),
+            Some("Searching YouTube... |")
+        );
+        app.tick_spinner();
+        assert!(app.dirty);
+        assert_eq!(
+            app.status_with_spinner().as_deref(),
+            Some("Searching YouTube... /")
+        );
+        for _ in 0..3 {
+            app.tick_spinner();
+        }
+        assert_eq!(
+            app.status_with_spinner().as_deref(),
+            Some("Searching YouTube... |")
+        );
+
+        // Gone as soon as the task is taken
+        app.transcript_task.take().unwrap().abort();
+        assert_eq!(
+            app.status_with_spinner().as_deref(),
+            Some("Searching YouTube...")
+        );
+    }
+
+    #[test]
+    fn test_parse_goto_index() {
+        assert_eq!(parse_goto_index("1", 10), Some(0));
+        assert_eq!(parse_goto_index("7", 10), Some(6));
+        assert_eq!(parse_goto_index("10", 10), Some(9));
+        // Out of range positions are clamped
+        assert_eq!(parse_goto_index("0", 10), Some(0));
+        assert_eq!(parse_goto_index("11", 10), Some(9));
+        assert_eq!(parse_goto_index("99999999999999999999999", 10), Some(9));
+        assert_eq!(parse_goto_index("", 10), None);
+        assert_eq!(parse_goto_index("1a", 10), None);
+        assert_eq!(parse_goto_index("3", 0), None);
+    }
+
+    #[test]
+    fn test_goto_mode() {
+        let mut app = App::new(History::default(), false);
+        app.set_videos(
+            (1..=5)
+                .map(|i| create_test_video(&i.to_string(), "Video", "Channel"))
+                .collect(),
+        );
+
+        app.start_goto();
+        assert_eq!(app.mode, UiMode::Goto);
+        for c in ['4', 'x', '2'] {
+            app.push_goto_char(c);
+        }
+        assert_eq!(app.goto_input, "42");
+        assert_eq!(app.commit_goto(), Some(4));
+        assert_eq!(app.selected_index, 4);
+        assert_eq!(app.mode, UiMode::List);
+        assert!(app.goto_input.is_empty());
+
+        app.start_goto();
+        app.push_goto_char('2');
+        app.cancel_goto();
+        assert_eq!(app.selected_index, 4);
+        assert_eq!(app.mode, UiMode::List);
+
+        app.start_goto();
+        app.push_goto_char('2');
+        assert_eq!(app.commit_goto(), Some(1));
+        assert_eq!(app.selected_index, 1);
+    }
+
+    #[test]
+    fn test_block_selected_channel() {
+        let mut app = App::new(History::default(), false);
+        let mut first = create_test_video("1", "First", "Spam Channel");
+        first.channel_id = "spam_id".to_string();
+        let mut second = first.clone();
+        second.id = "2".to_string();
+        // Renamed channel, same ID
+        second.channel = "Renamed Spam".to_string();
+        let allowed = create_test_video("3", "Allowed", "Good Channel");
+        app.set_videos(vec![first.clone(), second.clone(), allowed.clone()]);
+        app.set_search_results(vec![second, allowed]);
+
+        app.selected_index = app
+            .filtered_videos
+            .iter()
+            .position(|v| v.id == "1")
+            .unwrap();
+        assert_eq!(
+            app.block_selected_channel().as_deref(),
+            Some("Spam Channel")
+        );
+        assert_eq!(app.blocked_channels, ["spam_id"]);
+        assert_eq!(app.history.blocked_channels(), ["spam_id"]);
+        assert_eq!(app.filtered_videos.len(), 1);
+        assert_eq!(app.filtered_videos[0].id, "3");
+        assert_eq!(app.search_results.len(), 1);
+        assert_eq!(app.search_results[0].id, "3");
+
+        // The remaining video's channel isn't blocked twice
+        app.blocked_channels
+            .push(app.filtered_videos[0].channel_id.clone());
+        assert_eq!(app.block_selected_channel(), None);
+    }
+
+    #[test]
+    fn test_finish_search_empty_suggests_retry() {
+        let mut app = App::new(History::default(), false);
+        app.last_search_query = "rust channel:jon longer:10m".to_string();
+        app.finish_search(Vec::new());
+        assert_eq!(app.search_retry_query.as_deref(), Some("rust"));
+        assert_eq!(
+            app.status_message.as_deref(),
+            Some(
+                "No results for 'rust channel:jon longer:10m'. Press Ctrl+R to search 'rust' without operators"
+            )
+        );
+
+        // Without operators there is nothing to strip, only a suggestion
+        app.last_search_query = "xyzzy".to_string();
+        app.finish_search(Vec::new());
+        assert_eq!(app.search_retry_query, None);
+        assert!(app.status_message.as_deref().unwrap().contains("broader"));
+
+        app.finish_search(vec![create_test_video("1", "Rust", "Jon")]);
+        assert_eq!(app.search_retry_query, None);
+        assert_eq!(app.status_message.as_deref(), Some("Found 1 videos"));
+    }
+
+    #[test]
+    fn test_snoozed_video_hidden() {
+        let mut app = App::new(History::default(), false);
+        app.set_videos(vec![
+            create_test_video("1", "First", "A"),
+            create_test_video("2", "Second", "B"),
+        ]);
+        let selected_id = app.filtered_videos[0].id.clone();
+
+        assert!(app.snooze_selected().is_some());
+        assert_eq!(app.filtered_videos.len(), 1);
+        assert_ne!(app.filtered_videos[0].id, selected_id);
+    }
+
+    #[test]
+    fn test_preview_cycle_and_commit() {
+        let mut app = App::new(History::default(), false);
+        app.set_initial_sort(Tab::CurrentView, SortMode::Relevance);
+        app.set_videos(vec![
+            create_test_video("1", "First", "A"),
+            create_test_video("2", "Second", "B"),
+            create_test_video("3", "Third", "C"),
+        ]);
+
+        app.preview_next();
+        assert_eq!(app.preview_index, Some(1));
+        app.preview_next();
+        app.preview_next();
+        assert_eq!(app.preview_index, Some(0));
+        app.preview_prev();
+        assert_eq!(app.preview_index, Some(2));
+        assert_eq!(app.preview_video().map(|v| v.id.as_str()), Some("3"));
+        // Previewing never moves the selection
+        assert_eq!(app.selected_index, 0);
+
+        app.commit_preview();
+        assert_eq!(app.selected_index, 2);
+        assert_eq!(app.preview_index, None);
+
+        // Re-filtering closes the preview
+        app.preview_next();
+        app.search_queries[Tab::CurrentView.index()] = "Third".to_string();
+        app.apply_filters();
+        assert_eq!(app.preview_index, None);
+    }
+
+    #[test]
+    fn test_history_fetch_error_gives_degraded_list() {
+        let mut history = History::default();
+        history.mark_watched("abc");
+        history.mark_watched("def");
+        let mut app = App::new(history, false);
+        app.switch_tab(Tab::History);
+
+        let ids = vec!["abc".to_string(), "def".to_string()];
+        app.apply_history_fetch(&ids, Err(anyhow::anyhow!("offline")));
+
+        assert_eq!(app.history_videos.len(), 2);
+        assert!(app.history_videos.iter().all(|v| v.details_missing));
+        let mut got: Vec<&str> = app.history_videos.iter().map(|v| v.id.as_str()).collect();
+        got.sort();
+        assert_eq!(got, ["abc", "def"]);
+        assert!(
+            app.status_message
+                .as_deref()
+                .unwrap()
+                .contains("offline list")
+        );
+
+        // A later successful fetch replaces the placeholders
+        app.apply_history_fetch(&ids, Ok(vec![create_test_video("abc", "Title", "A")]));
+        assert_eq!(app.history_videos.len(), 1);
+        assert!(!app.history_videos[0].details_missing);
+    }
+
+    #[test]
+    fn test_select_next_channel() {
+        let mut app = App::new(History::default(), false);
+        app.set_initial_sort(Tab::CurrentView, SortMode::Relevance);
+        let mut videos = vec![
+            create_test_video("1", "A1", "A"),
+            create_test_video("2", "A2", "A"),
+            create_test_video("3", "A3", "A"),
+            create_test_video("4", "B1", "B"),
+            create_test_video("5", "A4", "A"),
+        ];
+        for video in &mut videos {
+            video.channel_id = video.channel.clone();
+        }
+        app.set_videos(videos);
+
+        app.select_next_channel();
+        assert_eq!(app.selected_index, 3);
+        app.select_next_channel();
+        assert_eq!(app.selected_index, 4);
+        // Wraps around and skips the run of "A" videos at the start
+        app.select_next_channel();
+        assert_eq!(app.selected_index, 3);
+    }
+
+    #[test]
+    fn test_index_at_row_variable_heights() {
+        let mut app = App::new(History::default(), false);
+        app.item_rows = vec![
+            ItemRow {
+                index: 4,
+                start_row: 10,
+                height: 6,
+            },
+            ItemRow {
+                index: 5,
+                start_row: 16,
+                height: 9,
+            },
+            ItemRow {
+                index: 6,
+                start_row: 25,
+                height: 2,
+            },
+        ];
+
+        assert_eq!(app.index_at_row(9), None);
+        assert_eq!(app.index_at_row(10), Some(4));
+        assert_eq!(app.index_at_row(15), Some(4));
+        assert_eq!(app.index_at_row(16), Some(5));
+        assert_eq!(app.index_at_row(24), Some(5));
+        assert_eq!(app.index_at_row(26), Some(6));
+        assert_eq!(app.index_at_row(27), None);
+    }
+
+    #[test]
+    fn test_export_m3u() {
+        let mut app = App::new(History::default(), false);
+        app.set_initial_sort(Tab::CurrentView, SortMode::Relevance);
+        let mut unknown = create_test_video("xyz", "Live\nStream", "Channel 2");
+        unknown.duration = 0;
+        app.set_videos(vec![
+            create_test_video("abc", "Video 1", "Channel 1"),
+            unknown,
+        ]);
+
+        let expected = "#EXTM3U\n\
+            #EXTINF:100,Video 1\n\
+            https://www.youtube.com/watch?v=abc\n\
+            #EXTINF:-1,Live Stream\n\
+            https://www.youtube.com/watch?v=xyz\n";
+        assert_eq!(app.m3u_playlist(), expected);
+
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let path = temp_dir.path().join("playlist.m3u");
+        assert_eq!(app.export_m3u(&path).unwrap(), 2);
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_search_input_routing() {
+        let mut app = App::new(History::default(), false);
+        app.set_videos(vec![
+            create_test_video("1", "Rust Tutorial", "Channel 1"),
+            create_test_video("2", "Python Guide", "Channel 2"),
+        ]);
+
+        // Outside the Search tab input filters the list by default
+        app.start_search_input();
+        assert_eq!(app.search_target, SearchTarget::Filter);
+        for c in "rust".chars() {
+            app.add_search_char(c);
+        }
+        assert_eq!(app.search_query(), "rust");
+        assert!(app.search_query_global.is_empty());
+        assert_eq!(app.filtered_videos.len(), 1);
+
+        // Toggled to YouTube, input goes to the platform query
+        app.toggle_search_target();
+        app.add_search_char('x');
+        app.remove_search_char();
+        app.add_search_char('y');
+        assert_eq!(app.search_input(), "y");
+        assert_eq!(app.search_query(), "rust");
+        assert_eq!(app.filtered_videos.len(), 1);
+
+        // The Search tab always starts in YouTube mode
+        app.mode = UiMode::List;
+        app.toggle_search_target();
+        app.switch_tab(Tab::Search);
+        app.start_search_input();
+        assert_eq!(app.search_target, SearchTarget::YouTube);
+    }
+
+    #[test]
+    fn test_mpv_args_input_round_trip() {
+        let mut app = App::new(History::default(), false);
+        app.start_mpv_args_input();
+        app.mpv_args_input = Some(r#"--speed=1.5 --title="it's mine""#.to_string());
+        app.commit_mpv_args_input();
+        assert!(app.mpv_args_input.is_none());
+        assert_eq!(app.extra_mpv_args, ["--speed=1.5", "--title=it's mine"]);
+
+        // Reopening shows the arguments so they split back the same way
+        app.start_mpv_args_input();
+        app.commit_mpv_args_input();
+        assert_eq!(app.extra_mpv_args, ["--speed=1.5", "--title=it's mine"]);
+
+        // Unclosed quotes keep the prompt open
+        app.mpv_args_input = Some("--title='oops".to_string());
+        app.commit_mpv_args_input();
+        assert!(app.mpv_args_input.is_some());
+        assert_eq!(app.extra_mpv_args.len(), 2);
+    }
+
+    #[test]
+    fn test_selection_follows_video_across_sort_and_filter() {
+        let mut app = App::new(History::default(), false);
+        let mut videos = vec![
+            create_test_video("a", "Alpha", "Zed"),
+            create_test_video("b", "Beta", "Amy"),
+            create_test_video("c", "Gamma", "Max"),
+        ];
+        videos[0].view_count = 300;
+        videos[1].view_count = 200;
+        videos[2].view_count = 100;
+        app.set_videos(videos);
+        app.selected_index = 1;
+        assert_eq!(app.selected_video_from_tab().unwrap().id, "b");
+
+        // Views -> UploadDate -> Creator: "b" moves to the top but stays selected
+        app.cycle_sort_mode();
+        app.cycle_sort_mode();
+        assert_eq!(app.sort_mode_name(), "Creator (A-Z)");
+        assert_eq!(app.selected_index, 0);
+        assert_eq!(app.selected_video_from_tab().unwrap().id, "b");
+
+        // Hiding watched videos keeps the selection on "a"
+        app.selected_index = 2;
+        app.history.mark_watched("b");
+        app.toggle_hide_watched();
+        assert_eq!(app.selected_video_from_tab().unwrap().id, "a");
+
+        // A filtered-out selection falls back to the clamped index
+        app.history.mark_watched("a");
+        app.apply_filters();
+        assert_eq!(app.selected_index, 0);
+        assert_eq!(app.selected_video_from_tab().unwrap().id, "c");
+    }
+
+    #[test]
+    fn test_queue_enqueue_and_finish() {
+        let mut app = App::new(History::default(), true);
+        app.set_videos(vec![
+            create_test_video("1", "First", "Channel 1"),
+            create_test_video("2", "Second", "Channel 2"),
+        ]);
+
+        assert_eq!(app.enqueue_selected().as_deref(), Some("First"));
+        // The same video isn't queued twice
+        assert_eq!(app.enqueue_selected(), None);
+        app.move_down();
+        assert_eq!(app.enqueue_selected().as_deref(), Some("Second"));
+        assert_eq!(app.queue.len(), 2);
+
+        let played = app.finish_queue();
+        assert_eq!(played.len(), 2);
+        assert!(app.queue.is_empty());
+        assert!(app.history.is_watched("1") && app.history.is_watched("2"));
+        // Hidden as watched
+        assert!(app.filtered_videos.is_empty());
     }
 }
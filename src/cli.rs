@@ -0,0 +1,160 @@
+//! Command line argument parsing.
+//!
+//! Hand-rolled parser for the few flags the application supports.
+
+use anyhow::Result;
+use std::path::PathBuf;
+
+/// Usage text printed for `--help`.
+pub const USAGE: &str = "Usage: yt-tui [OPTIONS]
+
+Options:
+  --config <FILE>      Use this config file instead of the default one
+  --api-key <KEY>      Use this API key instead of the configured one (not saved)
+  --no-oauth           Ignore stored OAuth tokens (trending instead of personalized videos)
+  --export-m3u <FILE>  Write the Current View list as an m3u playlist and exit
+  --login              Authorize with Google (OAuth) in the browser and save the tokens
+  --compact-history    Reconcile watched videos with their timestamps in the history file and exit
+  --import-history <FILE>
+                       Mark the video IDs in FILE (one per line or a JSON array) as watched and exit
+  --debug              Keep raw API values for the debug overlay (Ctrl+D)
+  -h, --help           Print this help";
+
+/// Parsed command line arguments.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Args {
+    /// Config file to load and save instead of the default one
+    pub config: Option<PathBuf>,
+    /// API key overriding the configured one
+    pub api_key: Option<String>,
+    /// Ignore the stored OAuth tokens
+    pub no_oauth: bool,
+    /// Write the Current View list to this playlist file and exit
+    pub export_m3u: Option<PathBuf>,
+    /// Run the OAuth login flow and exit
+    pub login: bool,
+    /// Compact the history file and exit
+    pub compact_history: bool,
+    /// Import watched video IDs from this file and exit
+    pub import_history: Option<PathBuf>,
+    /// Keep raw API values for the debug overlay
+    pub debug: bool,
+    /// Print usage and exit
+    pub help: bool,
+}
+
+/// Parse command line arguments.
+///
+/// # Arguments
+/// * `args` - Arguments without the program name (e.g. `std::env::args().skip(1)`)
+///
+/// # Returns
+/// * `Result<Args>` - Parsed arguments or error for unknown flags / missing values
+pub fn parse_args<I: IntoIterator<Item = String>>(args: I) -> Result<Args> {
+    let mut parsed = Args::default();
+    let mut args = args.into_iter();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--config" => {
+                let value = args
+                    .next()
+                    .ok_or_else(|| anyhow::anyhow!("--config requires a file path"))?;
+                parsed.config = Some(PathBuf::from(value));
+            }
+            "--api-key" => {
+                let value = args
+                    .next()
+                    .filter(|key| !key.is_empty())
+                    .ok_or_else(|| anyhow::anyhow!("--api-key requires a key"))?;
+                parsed.api_key = Some(value);
+            }
+            "--no-oauth" => parsed.no_oauth = true,
+            "--export-m3u" => {
+                let value = args
+                    .next()
+                    .ok_or_else(|| anyhow::anyhow!("--export-m3u requires a file path"))?;
+                parsed.export_m3u = Some(PathBuf::from(value));
+            }
+            "--login" => parsed.login = true,
+            "--compact-history" => parsed.compact_history = true,
+            "--debug" => parsed.debug = true,
+            "--import-history" => {
+                let value = args
+                    .next()
+                    .ok_or_else(|| anyhow::anyhow!("--import-history requires a file path"))?;
+                parsed.import_history = Some(PathBuf::from(value));
+            }
+            "-h" | "--help" => parsed.help = true,
+            other => return Err(anyhow::anyhow!("Unknown argument: {}\n\n{}", other, USAGE)),
+        }
+    }
+
+    Ok(parsed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(list: &[&str]) -> Vec<String> {
+        list.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn test_parse_args_empty() {
+        assert_eq!(parse_args(args(&[])).unwrap(), Args::default());
+    }
+
+    #[test]
+    fn test_parse_args_export_m3u() {
+        let parsed = parse_args(args(&["--export-m3u", "out.m3u"])).unwrap();
+        assert_eq!(parsed.export_m3u, Some(PathBuf::from("out.m3u")));
+        assert!(parse_args(args(&["--export-m3u"])).is_err());
+    }
+
+    #[test]
+    fn test_parse_args_login() {
+        assert!(parse_args(args(&["--login"])).unwrap().login);
+    }
+
+    #[test]
+    fn test_parse_args_compact_history() {
+        assert!(
+            parse_args(args(&["--compact-history"]))
+                .unwrap()
+                .compact_history
+        );
+    }
+
+    #[test]
+    fn test_parse_args_import_history() {
+        let parsed = parse_args(args(&["--import-history", "ids.txt"])).unwrap();
+        assert_eq!(parsed.import_history, Some(PathBuf::from("ids.txt")));
+        assert!(parse_args(args(&["--import-history"])).is_err());
+    }
+
+    #[test]
+    fn test_parse_args_config_overrides() {
+        let parsed = parse_args(args(&[
+            "--config",
+            "/tmp/cfg.jsonc",
+            "--api-key",
+            "KEY",
+            "--no-oauth",
+        ]))
+        .unwrap();
+        assert_eq!(parsed.config, Some(PathBuf::from("/tmp/cfg.jsonc")));
+        assert_eq!(parsed.api_key.as_deref(), Some("KEY"));
+        assert!(parsed.no_oauth);
+
+        assert!(parse_args(args(&["--config"])).is_err());
+        assert!(parse_args(args(&["--api-key"])).is_err());
+        assert!(parse_args(args(&["--api-key", ""])).is_err());
+    }
+
+    #[test]
+    fn test_parse_args_unknown() {
+        assert!(parse_args(args(&["--bogus"])).is_err());
+    }
+}
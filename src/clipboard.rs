@@ -0,0 +1,76 @@
+//! System clipboard integration.
+//!
+//! Copies text to the clipboard using the platform's command-line tools.
+
+use anyhow::{Context, Result};
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+/// Copy text to the system clipboard.
+///
+/// # Arguments
+/// * `text` - Text to copy
+///
+/// # Returns
+/// * `Result<()>` - Success or error
+///
+/// # Details
+/// Uses `wl-copy` on Wayland, `xclip`/`xsel` on X11 and `pbcopy` on macOS,
+/// trying each in turn until one succeeds.
+pub fn copy_to_clipboard(text: &str) -> Result<()> {
+    // Detect if we're on Wayland
+    let is_wayland = std::env::var("XDG_SESSION_TYPE")
+        .map(|s| s == "wayland")
+        .unwrap_or(false)
+        || std::env::var("WAYLAND_DISPLAY").is_ok();
+
+    let mut candidates: Vec<(&str, Vec<&str>)> = Vec::new();
+    if is_wayland {
+        candidates.push(("wl-copy", vec![]));
+    }
+    candidates.push(("xclip", vec!["-selection", "clipboard"]));
+    candidates.push(("xsel", vec!["--clipboard", "--input"]));
+    candidates.push(("pbcopy", vec![]));
+
+    for (program, args) in &candidates {
+        if copy_with(program, args, text).is_ok() {
+            return Ok(());
+        }
+    }
+
+    Err(anyhow::anyhow!(
+        "No clipboard tool found. Install wl-clipboard (Wayland) or xclip (X11)"
+    ))
+}
+
+/// Pipe text into a clipboard command.
+///
+/// # Arguments
+/// * `program` - Clipboard command to run
+/// * `args` - Arguments for the command
+/// * `text` - Text to write to the command's stdin
+///
+/// # Returns
+/// * `Result<()>` - Success if the command ran and exited successfully
+fn copy_with(program: &str, args: &[&str], text: &str) -> Result<()> {
+    let mut child = Command::new(program)
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .with_context(|| format!("Failed to run {}", program))?;
+
+    child
+        .stdin
+        .take()
+        .context("Failed to open clipboard stdin")?
+        .write_all(text.as_bytes())
+        .context("Failed to write to clipboard")?;
+
+    let status = child.wait().context("Failed to wait for clipboard tool")?;
+    if !status.success() {
+        return Err(anyhow::anyhow!("{} exited with {}", program, status));
+    }
+    Ok(())
+}
@@ -1,6 +1,6 @@
 //! Configuration management for YouTube TUI application.
 //!
-//! Handles loading and saving configuration from JSONC files.
+//! Handles loading and saving configuration from JSONC or TOML files.
 //! Manages API keys, OAuth credentials, and user preferences.
 
 use anyhow::{Context, Result};
@@ -25,12 +25,93 @@ pub struct Config {
     pub oauth_access_token: Option<String>,
     /// OAuth2 refresh token
     pub oauth_refresh_token: Option<String>,
+    /// OAuth2 scopes requested by `--login`
+    pub oauth_scopes: Vec<String>,
+    /// Local port for the `--login` callback (0 picks a free port)
+    pub oauth_redirect_port: u16,
     /// Default filter settings
     pub default_filters: FilterSettings,
     /// Whether to hide watched videos by default
     pub hide_watched: bool,
+    /// Whether to hide Shorts (videos of at most a minute) by default
+    pub hide_shorts: bool,
     /// History file path (relative to config dir or absolute)
     pub history_path: String,
+    /// Saved ("watch later") list path (relative to config dir or absolute)
+    pub saved_path: String,
+    /// Seconds between automatic saves of changed history
+    pub history_save_interval_secs: u64,
+    /// Milliseconds to wait for input before checking background tasks (minimum 10)
+    pub poll_interval_ms: u64,
+    /// Search automatically this many milliseconds after typing stops on the Search tab (0 disables)
+    pub live_search_delay_ms: u64,
+    /// Retries of API requests that failed to connect, timed out or got a 5xx response
+    pub max_retries: u32,
+    /// Videos requested per fetch or search page (clamped to 1-50, the API maximum)
+    pub default_results: u32,
+    /// Daily API quota budget in units; searches stop when it runs low (0 disables)
+    pub daily_quota_budget: u64,
+    /// Hours fetched video details are reused from the disk cache (0 disables the cache)
+    pub cache_ttl_hours: u64,
+    /// Start in audio-only playback mode
+    pub audio_only: bool,
+    /// Command used to play videos ("mpv" gets the output fallbacks and IPC socket)
+    pub player_command: String,
+    /// Arguments passed to the player before the URLs
+    pub player_args: Vec<String>,
+    /// Ask "Play '<title>'? (y/n)" before playing a video
+    pub confirm_play: bool,
+    /// How marked videos are opened: "playlist" (one MPV) or "windows" (one MPV each)
+    pub multi_open: String,
+    /// Ask before opening more than this many marked videos (0 never asks)
+    pub multi_open_confirm_threshold: usize,
+    /// Re-run the search that was open at exit on the next start (costs quota)
+    pub resume_last_search: bool,
+    /// Query pre-filled in the Search tab (replaced by the query open at the
+    /// last exit, which is kept in the history file)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_search: Option<String>,
+    /// Highest video height requested from yt-dlp (0 for no cap)
+    pub max_resolution: u32,
+    /// Debug mode (set by `--debug`, never read from or written to the file)
+    #[serde(skip)]
+    pub debug: bool,
+    /// File the config was loaded from with `--config` (None for the default path)
+    #[serde(skip)]
+    pub path: Option<PathBuf>,
+    /// Credentials from the file replaced by `--api-key`/`--no-oauth`
+    #[serde(skip)]
+    pub file_credentials: FileCredentials,
+    /// List scrolling: "centered" or "anchored"
+    pub scroll_mode: String,
+    /// Move faster while `j`/`k` are held down
+    pub scroll_acceleration: bool,
+    /// Initial sort mode for the Current View tab (trending/recommendations)
+    pub default_sort_current: String,
+    /// Initial sort mode for the Search tab
+    pub default_sort_search: String,
+    /// Initial sort mode for the History tab
+    pub default_sort_history: String,
+    /// Channel IDs whose videos are hidden in every tab
+    pub blocked_channels: Vec<String>,
+    /// Hide Current View videos with shorter titles (0 disables); symbol-only titles are always hidden
+    pub min_title_len: usize,
+    /// Pad sparse personalized recommendations with trending videos
+    pub merge_trending_fallback: bool,
+    /// Thumbnail tiers in order of preference ("maxres", "standard", "high", "medium", "default")
+    pub thumbnail_order: Vec<String>,
+    /// Disable per-channel colors (also enabled by the `NO_COLOR` environment variable)
+    pub no_color: bool,
+    /// Show upload dates in the list as "3 days ago" instead of the calendar date
+    pub relative_dates: bool,
+    /// Show each video on one line ("duration | title — channel (views)")
+    pub compact_list: bool,
+    /// Copy and show youtu.be links instead of full watch URLs
+    pub use_short_urls: bool,
+    /// View count style: "short" (1.5K) or "full" (1,534)
+    pub number_style: String,
+    /// Thousands separator used by the "full" number style
+    pub thousands_separator: String,
 }
 
 impl Default for Config {
@@ -41,13 +122,141 @@ impl Default for Config {
             oauth_client_secret: None,
             oauth_access_token: None,
             oauth_refresh_token: None,
+            oauth_scopes: vec!["https://www.googleapis.com/auth/youtube.readonly".to_string()],
+            oauth_redirect_port: 0,
             default_filters: FilterSettings::default(),
             hide_watched: false,
+            hide_shorts: false,
             history_path: "history.json".to_string(),
+            saved_path: "saved.json".to_string(),
+            history_save_interval_secs: 10,
+            poll_interval_ms: 100,
+            live_search_delay_ms: 0,
+            max_retries: 3,
+            default_results: 50,
+            daily_quota_budget: 10_000,
+            cache_ttl_hours: 6,
+            audio_only: false,
+            player_command: "mpv".to_string(),
+            player_args: Vec::new(),
+            confirm_play: false,
+            multi_open: "playlist".to_string(),
+            multi_open_confirm_threshold: 5,
+            resume_last_search: false,
+            last_search: None,
+            max_resolution: 1080,
+            debug: false,
+            path: None,
+            file_credentials: FileCredentials::default(),
+            scroll_mode: "centered".to_string(),
+            scroll_acceleration: false,
+            default_sort_current: "views".to_string(),
+            default_sort_search: "relevance".to_string(),
+            default_sort_history: "watch_recency".to_string(),
+            blocked_channels: Vec::new(),
+            min_title_len: 1,
+            merge_trending_fallback: false,
+            thumbnail_order: crate::youtube::models::DEFAULT_THUMBNAIL_ORDER
+                .iter()
+                .map(|tier| tier.to_string())
+                .collect(),
+            no_color: false,
+            relative_dates: false,
+            compact_list: false,
+            use_short_urls: false,
+            number_style: "short".to_string(),
+            thousands_separator: ",".to_string(),
         }
     }
 }
 
+/// On-disk configuration format, chosen by file extension.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ConfigFormat {
+    /// JSON with `//` and `/* */` comments (`.jsonc`, `.json` and anything else)
+    Jsonc,
+    /// TOML (`.toml`)
+    Toml,
+}
+
+impl ConfigFormat {
+    /// Detect the format from a config file path.
+    ///
+    /// # Arguments
+    /// * `path` - Config file path
+    ///
+    /// # Returns
+    /// * `ConfigFormat` - `Toml` for a `.toml` extension, `Jsonc` otherwise
+    fn from_path(path: &Path) -> Self {
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some(ext) if ext.eq_ignore_ascii_case("toml") => Self::Toml,
+            _ => Self::Jsonc,
+        }
+    }
+}
+
+/// Values from the config file that command line options replaced for this
+/// run; they are written back instead of the replacements on save.
+#[derive(Debug, Clone, Default)]
+pub struct FileCredentials {
+    /// API key, when replaced by `--api-key`
+    api_key: Option<String>,
+    /// Access and refresh token, when dropped by `--no-oauth`
+    oauth_tokens: Option<(Option<String>, Option<String>)>,
+}
+
+/// OAuth tokens saved by `--login` and token refreshes.
+///
+/// They live in their own file so that saving them never rewrites the
+/// config file (which would drop its comments).
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct StoredTokens {
+    /// OAuth2 access token
+    pub access_token: Option<String>,
+    /// OAuth2 refresh token
+    pub refresh_token: Option<String>,
+}
+
+impl StoredTokens {
+    /// Load tokens from a token file.
+    ///
+    /// # Arguments
+    /// * `path` - Token file path
+    ///
+    /// # Returns
+    /// * `Result<Option<StoredTokens>>` - Tokens, None if the file doesn't
+    ///   exist, or error if it can't be read or parsed
+    pub fn load(path: &Path) -> Result<Option<Self>> {
+        if !path.exists() {
+            return Ok(None);
+        }
+        let content = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read token file: {}", path.display()))?;
+        let tokens = serde_json::from_str(&content)
+            .with_context(|| format!("Failed to parse token file: {}", path.display()))?;
+        Ok(Some(tokens))
+    }
+
+    /// Save tokens to a token file.
+    ///
+    /// # Arguments
+    /// * `path` - Token file path (its directory is created if needed)
+    ///
+    /// # Returns
+    /// * `Result<()>` - Success or error if the file can't be written
+    pub fn save(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).with_context(|| {
+                format!("Failed to create config directory: {}", parent.display())
+            })?;
+        }
+        let json = serde_json::to_string_pretty(self).context("Failed to serialize tokens")?;
+        fs::write(path, json)
+            .with_context(|| format!("Failed to write token file: {}", path.display()))
+    }
+}
+
 /// Filter settings for video filtering.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(default)]
@@ -58,6 +267,10 @@ pub struct FilterSettings {
     pub min_duration: Option<u64>,
     /// Filter by maximum duration in seconds
     pub max_duration: Option<u64>,
+    /// Filter by minimum view count
+    pub min_views: Option<u64>,
+    /// Filter by maximum view count
+    pub max_views: Option<u64>,
     /// Filter by date (videos after this date)
     pub after_date: Option<String>,
 }
@@ -74,10 +287,12 @@ impl Config {
     /// # Details
     /// Searches for config file in:
     /// 1. Provided path (if given)
-    /// 2. `$XDG_CONFIG_HOME/yt-tui/config.jsonc`
-    /// 3. `~/.config/yt-tui/config.jsonc`
+    /// 2. `$XDG_CONFIG_HOME/yt-tui/config.jsonc` (or `config.toml`)
+    /// 3. `~/.config/yt-tui/config.jsonc` (or `config.toml`)
     ///
-    /// If no config file exists, returns default configuration.
+    /// Files ending in `.toml` are parsed as TOML, everything else as JSONC.
+    /// If no config file exists, returns default configuration. A given path
+    /// is remembered in `path`, so `save(None)` writes back to it.
     pub fn load(path: Option<&Path>) -> Result<Self> {
         let config_path = if let Some(p) = path {
             p.to_path_buf()
@@ -86,60 +301,144 @@ impl Config {
         };
 
         if !config_path.exists() {
-            return Ok(Self::default());
+            let mut config = Self {
+                path: path.map(Path::to_path_buf),
+                ..Self::default()
+            };
+            config.load_stored_tokens()?;
+            return Ok(config);
         }
 
         let content = fs::read_to_string(&config_path)
             .with_context(|| format!("Failed to read config file: {}", config_path.display()))?;
 
-        // Parse JSONC (JSON with comments)
-        // Strip // style comments manually
-        let json_content: String = content
-            .lines()
-            .map(|line| {
-                // Remove // comments (but preserve // in strings)
-                if let Some(comment_pos) = line.find("//") {
-                    // Check if // is inside a string (simplified - doesn't handle escaped quotes)
-                    let before_comment = &line[..comment_pos];
-                    let quote_count = before_comment.matches('"').count();
-                    if quote_count % 2 == 0 {
-                        // Not inside a string, remove comment
-                        line[..comment_pos].trim_end()
-                    } else {
-                        // Inside a string, keep as is
-                        line
-                    }
-                } else {
-                    line
-                }
-            })
-            .collect::<Vec<_>>()
-            .join("\n");
-
-        let config: Config =
-            serde_json::from_str(&json_content).with_context(|| "Failed to deserialize config")?;
+        let mut config: Config = match ConfigFormat::from_path(&config_path) {
+            ConfigFormat::Toml => {
+                toml::from_str(&content).with_context(|| "Failed to deserialize config")?
+            }
+            ConfigFormat::Jsonc => {
+                let json = strip_trailing_commas(&strip_jsonc_comments(&content));
+                serde_json::from_str(&json).with_context(|| "Failed to deserialize config")?
+            }
+        };
+        config.path = path.map(Path::to_path_buf);
+        config.load_stored_tokens()?;
 
         Ok(config)
     }
 
+    /// Replace the OAuth tokens with the ones saved by `--login` or a refresh.
+    ///
+    /// # Returns
+    /// * `Result<()>` - Success, or error if the token file can't be read
+    ///
+    /// # Details
+    /// Tokens written to the config file by hand are kept when there is no
+    /// token file, or when it lacks that token.
+    fn load_stored_tokens(&mut self) -> Result<()> {
+        let Ok(path) = self.tokens_file_path() else {
+            return Ok(());
+        };
+        if let Some(tokens) = StoredTokens::load(&path)? {
+            if tokens.access_token.is_some() {
+                self.oauth_access_token = tokens.access_token;
+            }
+            if tokens.refresh_token.is_some() {
+                self.oauth_refresh_token = tokens.refresh_token;
+            }
+        }
+        Ok(())
+    }
+
+    /// Save the OAuth tokens to the token file.
+    ///
+    /// # Returns
+    /// * `Result<()>` - Success or error if the file can't be written
+    ///
+    /// # Details
+    /// The config file itself is left alone, so its comments are kept.
+    pub fn save_tokens(&self) -> Result<()> {
+        StoredTokens {
+            access_token: self.oauth_access_token.clone(),
+            refresh_token: self.oauth_refresh_token.clone(),
+        }
+        .save(&self.tokens_file_path()?)
+    }
+
+    /// Get the OAuth token file path.
+    ///
+    /// # Returns
+    /// * `Result<PathBuf>` - `oauth_tokens.json` next to the config file, or error
+    pub fn tokens_file_path(&self) -> Result<PathBuf> {
+        Ok(self.file_path()?.with_file_name("oauth_tokens.json"))
+    }
+
+    /// Apply the `--api-key` and `--no-oauth` command line options.
+    ///
+    /// # Arguments
+    /// * `api_key` - API key to use instead of the configured one
+    /// * `no_oauth` - Drop the stored OAuth tokens for this run
+    ///
+    /// # Details
+    /// The replaced values are kept and written back by `save`, so neither
+    /// the key given on the command line nor the missing tokens end up in
+    /// the config file.
+    pub fn apply_overrides(&mut self, api_key: Option<String>, no_oauth: bool) {
+        if let Some(api_key) = api_key {
+            let file_key = std::mem::replace(&mut self.api_key, api_key);
+            self.file_credentials.api_key.get_or_insert(file_key);
+        }
+        if no_oauth {
+            let tokens = (
+                self.oauth_access_token.take(),
+                self.oauth_refresh_token.take(),
+            );
+            self.file_credentials.oauth_tokens.get_or_insert(tokens);
+        }
+    }
+
+    /// Get the path the config is saved to by `save(None)`.
+    ///
+    /// # Returns
+    /// * `Result<PathBuf>` - The `--config` file, or the default path
+    pub fn file_path(&self) -> Result<PathBuf> {
+        match &self.path {
+            Some(path) => Ok(path.clone()),
+            None => Self::default_config_path(),
+        }
+    }
+
     /// Save configuration to file.
     ///
     /// # Arguments
-    /// * `path` - Optional path to config file. If None, uses default location.
+    /// * `path` - Optional path to config file. If None, uses the file it was
+    ///   loaded from (see `file_path`).
     ///
     /// # Returns
     /// * `Result<()>` - Success or error
     ///
     /// # Details
-    /// Creates config directory if it doesn't exist.
-    #[allow(dead_code)] // Useful for saving config changes from within the app
+    /// Creates config directory if it doesn't exist. Writes TOML when the path
+    /// ends in `.toml`, JSON otherwise. Credentials replaced by command line
+    /// options are saved with their values from the file. Comments in a JSONC
+    /// file are not kept, so the app itself never saves the user's config
+    /// (state goes to the history and token files instead).
+    #[allow(dead_code)] // Writes config files in tests and tooling
     pub fn save(&self, path: Option<&Path>) -> Result<()> {
-        let config_path = if let Some(p) = path {
-            p.to_path_buf()
-        } else {
-            Self::default_config_path()?
+        let config_path = match path {
+            Some(p) => p.to_path_buf(),
+            None => self.file_path()?,
         };
 
+        let mut config = self.clone();
+        if let Some(api_key) = &self.file_credentials.api_key {
+            config.api_key = api_key.clone();
+        }
+        if let Some((access_token, refresh_token)) = &self.file_credentials.oauth_tokens {
+            config.oauth_access_token = access_token.clone();
+            config.oauth_refresh_token = refresh_token.clone();
+        }
+
         // Create parent directory if it doesn't exist
         if let Some(parent) = config_path.parent() {
             fs::create_dir_all(parent).with_context(|| {
@@ -147,9 +446,16 @@ impl Config {
             })?;
         }
 
-        let json = serde_json::to_string_pretty(self).context("Failed to serialize config")?;
+        let content = match ConfigFormat::from_path(&config_path) {
+            ConfigFormat::Toml => {
+                toml::to_string_pretty(&config).context("Failed to serialize config")?
+            }
+            ConfigFormat::Jsonc => {
+                serde_json::to_string_pretty(&config).context("Failed to serialize config")?
+            }
+        };
 
-        fs::write(&config_path, json)
+        fs::write(&config_path, content)
             .with_context(|| format!("Failed to write config file: {}", config_path.display()))?;
 
         Ok(())
@@ -161,11 +467,37 @@ impl Config {
     /// * `Result<PathBuf>` - Path to config file or error
     ///
     /// # Details
-    /// Returns `$XDG_CONFIG_HOME/yt-tui/config.jsonc` or `~/.config/yt-tui/config.jsonc`.
+    /// Returns `$XDG_CONFIG_HOME/yt-tui/config.jsonc` or `~/.config/yt-tui/config.jsonc`,
+    /// or `config.toml` in the same directory if only that one exists.
     pub fn default_config_path() -> Result<PathBuf> {
         let config_dir =
             config_dir().ok_or_else(|| anyhow::anyhow!("Failed to determine config directory"))?;
-        Ok(config_dir.join("yt-tui").join("config.jsonc"))
+        let jsonc_path = config_dir.join("yt-tui").join("config.jsonc");
+        let toml_path = jsonc_path.with_extension("toml");
+        if !jsonc_path.exists() && toml_path.exists() {
+            return Ok(toml_path);
+        }
+        Ok(jsonc_path)
+    }
+
+    /// Get the video details cache file path.
+    ///
+    /// # Returns
+    /// * `Result<PathBuf>` - `video_cache.json` in the config directory, or error
+    pub fn cache_file_path(&self) -> Result<PathBuf> {
+        let config_dir =
+            config_dir().ok_or_else(|| anyhow::anyhow!("Failed to determine config directory"))?;
+        Ok(config_dir.join("yt-tui").join("video_cache.json"))
+    }
+
+    /// Get the quota usage file path.
+    ///
+    /// # Returns
+    /// * `Result<PathBuf>` - `quota_usage.json` in the config directory, or error
+    pub fn quota_file_path(&self) -> Result<PathBuf> {
+        let config_dir =
+            config_dir().ok_or_else(|| anyhow::anyhow!("Failed to determine config directory"))?;
+        Ok(config_dir.join("yt-tui").join("quota_usage.json"))
     }
 
     /// Get history file path.
@@ -176,16 +508,147 @@ impl Config {
     /// # Details
     /// If history_path is absolute, returns it as-is.
     /// Otherwise, returns path relative to config directory.
+    /// Fails if the resolved path is an existing directory.
     pub fn history_file_path(&self) -> Result<PathBuf> {
-        let history_path = Path::new(&self.history_path);
-        if history_path.is_absolute() {
-            Ok(history_path.to_path_buf())
-        } else {
-            let config_dir = config_dir()
-                .ok_or_else(|| anyhow::anyhow!("Failed to determine config directory"))?;
-            Ok(config_dir.join("yt-tui").join(&self.history_path))
+        data_file_path(&self.history_path, "history_path")
+    }
+
+    /// Get the saved ("watch later") list file path.
+    ///
+    /// # Returns
+    /// * `Result<PathBuf>` - Path to the saved list file or error
+    ///
+    /// # Details
+    /// Resolved like `history_file_path`.
+    pub fn saved_file_path(&self) -> Result<PathBuf> {
+        data_file_path(&self.saved_path, "saved_path")
+    }
+}
+
+/// Resolve a data file option to a path.
+///
+/// # Arguments
+/// * `value` - Configured path (relative to the config directory or absolute)
+/// * `option` - Option name for the error message
+///
+/// # Returns
+/// * `Result<PathBuf>` - Resolved path, or error if it is an existing directory
+fn data_file_path(value: &str, option: &str) -> Result<PathBuf> {
+    let configured = Path::new(value);
+    let path = if configured.is_absolute() {
+        configured.to_path_buf()
+    } else {
+        let config_dir =
+            config_dir().ok_or_else(|| anyhow::anyhow!("Failed to determine config directory"))?;
+        config_dir.join("yt-tui").join(value)
+    };
+    if path.is_dir() {
+        return Err(anyhow::anyhow!(
+            "{} points to a directory, expected a file: {}",
+            option,
+            path.display()
+        ));
+    }
+    Ok(path)
+}
+
+/// Strip `//` line comments and `/* */` block comments from JSONC content.
+///
+/// # Arguments
+/// * `content` - JSONC text
+///
+/// # Returns
+/// * `String` - Plain JSON text
+///
+/// # Details
+/// Tracks string literals (including escaped quotes), so comment markers
+/// inside values such as `"http://..."` are kept. Newlines inside block
+/// comments are kept so parse errors still point at the right line; an
+/// unterminated block comment runs to the end of the content.
+fn strip_jsonc_comments(content: &str) -> String {
+    let mut result = String::with_capacity(content.len());
+    let mut chars = content.chars().peekable();
+    let mut in_string = false;
+
+    while let Some(ch) = chars.next() {
+        if in_string {
+            result.push(ch);
+            match ch {
+                '\\' => result.extend(chars.next()),
+                '"' => in_string = false,
+                _ => {}
+            }
+            continue;
+        }
+
+        match (ch, chars.peek()) {
+            ('"', _) => {
+                in_string = true;
+                result.push(ch);
+            }
+            ('/', Some('/')) => {
+                // Skip to the end of the line, keeping the newline
+                while chars.next_if(|&c| c != '\n').is_some() {}
+            }
+            ('/', Some('*')) => {
+                chars.next();
+                let mut previous = '\0';
+                for c in chars.by_ref() {
+                    if previous == '*' && c == '/' {
+                        break;
+                    }
+                    if c == '\n' {
+                        result.push('\n');
+                    }
+                    previous = c;
+                }
+            }
+            _ => result.push(ch),
+        }
+    }
+    result
+}
+
+/// Remove trailing commas before `}` and `]` from JSON content.
+///
+/// # Arguments
+/// * `content` - JSON text without comments (see `strip_jsonc_comments`)
+///
+/// # Returns
+/// * `String` - JSON text serde_json accepts
+///
+/// # Details
+/// A comma is dropped when only whitespace separates it from a closing
+/// brace or bracket. Commas inside string literals are kept.
+fn strip_trailing_commas(content: &str) -> String {
+    let mut result = String::with_capacity(content.len());
+    let mut in_string = false;
+    let mut chars = content.char_indices();
+
+    while let Some((index, ch)) = chars.next() {
+        if in_string {
+            result.push(ch);
+            match ch {
+                '\\' => result.extend(chars.next().map(|(_, c)| c)),
+                '"' => in_string = false,
+                _ => {}
+            }
+            continue;
+        }
+
+        match ch {
+            '"' => in_string = true,
+            ',' => {
+                let next = content[index + 1..].trim_start().chars().next();
+                if matches!(next, Some('}' | ']')) {
+                    continue;
+                }
+            }
+            _ => {}
         }
+        result.push(ch);
     }
+    result
 }
 
 #[cfg(test)]
@@ -202,6 +665,36 @@ mod tests {
         assert!(!config.hide_watched);
     }
 
+    #[test]
+    fn test_overrides_not_saved() {
+        let temp_dir = TempDir::new().unwrap();
+        let config_path = temp_dir.path().join("custom.jsonc");
+        Config {
+            api_key: "file_key".to_string(),
+            oauth_access_token: Some("access".to_string()),
+            oauth_refresh_token: Some("refresh".to_string()),
+            ..Config::default()
+        }
+        .save(Some(&config_path))
+        .unwrap();
+
+        let mut config = Config::load(Some(&config_path)).unwrap();
+        assert_eq!(config.file_path().unwrap(), config_path);
+        config.apply_overrides(Some("cli_key".to_string()), true);
+        assert_eq!(config.api_key, "cli_key");
+        assert!(config.oauth_access_token.is_none() && config.oauth_refresh_token.is_none());
+
+        // `save(None)` writes all options back to the --config file, with the
+        // credentials from the file rather than the command line overrides
+        config.hide_watched = true;
+        config.save(None).unwrap();
+        let loaded = Config::load(Some(&config_path)).unwrap();
+        assert!(loaded.hide_watched);
+        assert_eq!(loaded.api_key, "file_key");
+        assert_eq!(loaded.oauth_access_token.as_deref(), Some("access"));
+        assert_eq!(loaded.oauth_refresh_token.as_deref(), Some("refresh"));
+    }
+
     #[test]
     fn test_config_save_and_load() {
         let temp_dir = TempDir::new().unwrap();
@@ -219,6 +712,16 @@ mod tests {
         let loaded = Config::load(Some(&config_path)).unwrap();
         assert_eq!(loaded.api_key, "test_key");
         assert!(loaded.hide_watched);
+        assert!(loaded.last_search.is_none());
+    }
+
+    #[test]
+    fn test_config_last_search() {
+        let temp_dir = TempDir::new().unwrap();
+        let config_path = temp_dir.path().join("config.jsonc");
+        fs::write(&config_path, r#"{ "last_search": "rust tui" }"#).unwrap();
+        let loaded = Config::load(Some(&config_path)).unwrap();
+        assert_eq!(loaded.last_search.as_deref(), Some("rust tui"));
     }
 
     #[test]
@@ -238,4 +741,154 @@ mod tests {
         assert_eq!(loaded.api_key, "test_key");
         assert!(loaded.hide_watched);
     }
+
+    #[test]
+    fn test_config_jsonc_with_block_comments() {
+        let temp_dir = TempDir::new().unwrap();
+        let config_path = temp_dir.path().join("config.jsonc");
+
+        let jsonc_content = r#"{
+            /* API key for YouTube Data API v3,
+               spanning two lines */
+            "api_key": /* inline */ "test_key",
+            "player_args": ["--ytdl-raw-options=proxy=http://localhost:8080/*x*/", "say \"//hi\""],
+            "hide_watched": true // trailing
+        }"#;
+
+        fs::write(&config_path, jsonc_content).unwrap();
+
+        let loaded = Config::load(Some(&config_path)).unwrap();
+        assert_eq!(loaded.api_key, "test_key");
+        assert!(loaded.hide_watched);
+        assert_eq!(
+            loaded.player_args,
+            [
+                "--ytdl-raw-options=proxy=http://localhost:8080/*x*/",
+                "say \"//hi\""
+            ]
+        );
+    }
+
+    #[test]
+    fn test_config_jsonc_trailing_commas() {
+        let temp_dir = TempDir::new().unwrap();
+        let config_path = temp_dir.path().join("config.jsonc");
+
+        fs::write(&config_path, r#"{"api_key":"k","hide_watched":true,}"#).unwrap();
+        let loaded = Config::load(Some(&config_path)).unwrap();
+        assert_eq!(loaded.api_key, "k");
+        assert!(loaded.hide_watched);
+
+        // A comment between the comma and the brace, and commas inside strings
+        fs::write(
+            &config_path,
+            r#"{
+                "api_key": "a,}",
+                "player_args": ["--x=1, ]", "--y",
+                ],
+                "hide_watched": true, // last one
+            }"#,
+        )
+        .unwrap();
+        let loaded = Config::load(Some(&config_path)).unwrap();
+        assert_eq!(loaded.api_key, "a,}");
+        assert_eq!(loaded.player_args, ["--x=1, ]", "--y"]);
+    }
+
+    #[test]
+    fn test_strip_jsonc_comments() {
+        assert_eq!(
+            strip_jsonc_comments("{\"url\": \"http://example.com\"} // note"),
+            "{\"url\": \"http://example.com\"} "
+        );
+        // Line numbers survive a multi-line block comment
+        assert_eq!(strip_jsonc_comments("1 /* a\nb */ 2"), "1 \n 2");
+        assert_eq!(strip_jsonc_comments("1 /* never closed"), "1 ");
+    }
+
+    #[test]
+    fn test_config_toml_matches_jsonc() {
+        let temp_dir = TempDir::new().unwrap();
+        let jsonc_path = temp_dir.path().join("config.jsonc");
+        let toml_path = temp_dir.path().join("config.toml");
+
+        fs::write(
+            &jsonc_path,
+            r#"{
+            // API key for YouTube Data API v3
+            "api_key": "test_key",
+            "hide_watched": true,
+            "no_color": true,
+            "blocked_channels": ["Spam"],
+            "default_filters": { "min_duration": 60 }
+        }"#,
+        )
+        .unwrap();
+        fs::write(
+            &toml_path,
+            r#"
+            # API key for YouTube Data API v3
+            api_key = "test_key"
+            hide_watched = true
+            no_color = true
+            blocked_channels = ["Spam"]
+
+            [default_filters]
+            min_duration = 60
+        "#,
+        )
+        .unwrap();
+
+        let from_jsonc = Config::load(Some(&jsonc_path)).unwrap();
+        let from_toml = Config::load(Some(&toml_path)).unwrap();
+        assert_eq!(
+            serde_json::to_value(&from_jsonc).unwrap(),
+            serde_json::to_value(&from_toml).unwrap()
+        );
+        assert_eq!(from_toml.api_key, "test_key");
+        assert_eq!(from_toml.default_filters.min_duration, Some(60));
+    }
+
+    #[test]
+    fn test_config_save_toml() {
+        let temp_dir = TempDir::new().unwrap();
+        let config_path = temp_dir.path().join("config.toml");
+
+        let config = Config {
+            api_key: "test_key".to_string(),
+            oauth_client_id: Some("client".to_string()),
+            ..Config::default()
+        };
+        config.save(Some(&config_path)).unwrap();
+
+        let content = fs::read_to_string(&config_path).unwrap();
+        assert!(content.contains("api_key = \"test_key\""));
+
+        let loaded = Config::load(Some(&config_path)).unwrap();
+        assert_eq!(
+            serde_json::to_value(&loaded).unwrap(),
+            serde_json::to_value(&config).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_history_file_path_directory() {
+        let temp_dir = TempDir::new().unwrap();
+        let config = Config {
+            history_path: temp_dir.path().to_string_lossy().into_owned(),
+            ..Config::default()
+        };
+        let err = config.history_file_path().unwrap_err();
+        assert!(err.to_string().contains("points to a directory"));
+
+        let config = Config {
+            history_path: temp_dir
+                .path()
+                .join("history.json")
+                .to_string_lossy()
+                .into_owned(),
+            ..Config::default()
+        };
+        assert!(config.history_file_path().is_ok());
+    }
 }
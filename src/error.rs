@@ -0,0 +1,71 @@
+//! Top-level error kinds and process exit codes.
+//!
+//! Errors that scripts may want to tell apart are wrapped in `AppError`;
+//! everything else exits with the generic code 1.
+
+use std::path::PathBuf;
+use thiserror::Error;
+
+/// Errors with a dedicated process exit code.
+#[derive(Debug, Error)]
+pub enum AppError {
+    /// No API key configured (exit code 2)
+    #[error("YouTube API key is required. Please create a config file at: {}\nSee config.jsonc.example for template.", .0.display())]
+    MissingApiKey(PathBuf),
+    /// YouTube could not be reached or the fetch failed (exit code 3)
+    #[error("Network failure: {0}")]
+    Network(String),
+    /// Config file or a config value is invalid (exit code 4)
+    #[error("Invalid config: {0}")]
+    InvalidConfig(String),
+}
+
+impl AppError {
+    /// Get the process exit code for this error.
+    ///
+    /// # Returns
+    /// * `i32` - 2 missing API key, 3 network failure, 4 invalid config
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            AppError::MissingApiKey(_) => 2,
+            AppError::Network(_) => 3,
+            AppError::InvalidConfig(_) => 4,
+        }
+    }
+}
+
+/// Map the result of the application to a process exit code.
+///
+/// # Arguments
+/// * `result` - Result returned by the application
+///
+/// # Returns
+/// * `i32` - 0 on success, the `AppError` code if one is found in the chain, 1 otherwise
+pub fn exit_code(result: &anyhow::Result<()>) -> i32 {
+    match result {
+        Ok(()) => 0,
+        Err(e) => e
+            .chain()
+            .find_map(|cause| cause.downcast_ref::<AppError>())
+            .map_or(1, AppError::exit_code),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_exit_codes() {
+        assert_eq!(exit_code(&Ok(())), 0);
+        assert_eq!(
+            exit_code(&Err(AppError::Network("timeout".to_string()).into())),
+            3
+        );
+        assert_eq!(
+            exit_code(&Err(AppError::InvalidConfig("bad".to_string()).into())),
+            4
+        );
+        assert_eq!(exit_code(&Err(anyhow::anyhow!("other"))), 1);
+    }
+}
@@ -3,11 +3,61 @@
 //! Persists watched video IDs to a JSON file and provides query functionality.
 
 use anyhow::{Context, Result};
+use chrono::{DateTime, Local, NaiveDate, TimeZone, Utc};
 use serde::{Deserialize, Serialize};
-use std::collections::HashSet;
+use std::collections::{BTreeSet, HashMap, HashSet};
 use std::fs;
 use std::path::Path;
 
+/// Minimum watched fraction for a video to count as "continue watching".
+const CONTINUE_WATCHING_MIN: f64 = 0.05;
+/// Maximum watched fraction for a video to count as "continue watching".
+const CONTINUE_WATCHING_MAX: f64 = 0.95;
+/// Maximum number of remembered search queries.
+const RECENT_SEARCH_LIMIT: usize = 10;
+
+/// Changes made by `History::compact`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CompactReport {
+    /// Watched videos that got a placeholder (epoch) timestamp
+    pub backfilled: usize,
+    /// Timestamps removed because their video wasn't in the watched set
+    pub orphans_removed: usize,
+}
+
+/// Saved playback position for a partially watched video.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct WatchProgress {
+    /// Playback position in seconds
+    pub position: u64,
+    /// Total video duration in seconds
+    pub duration: u64,
+    /// When the position was last saved (RFC3339)
+    pub updated_at: String,
+}
+
+impl WatchProgress {
+    /// Get the watched fraction of the video.
+    ///
+    /// # Returns
+    /// * `f64` - Position divided by duration (0.0 if duration is unknown)
+    pub fn fraction(&self) -> f64 {
+        if self.duration == 0 {
+            return 0.0;
+        }
+        self.position as f64 / self.duration as f64
+    }
+
+    /// Check if the video qualifies for "continue watching".
+    ///
+    /// # Returns
+    /// * `bool` - True if the position is between 5% and 95% of the duration
+    pub fn is_resumable(&self) -> bool {
+        let fraction = self.fraction();
+        (CONTINUE_WATCHING_MIN..=CONTINUE_WATCHING_MAX).contains(&fraction)
+    }
+}
+
 /// History of watched videos.
 ///
 /// Maintains a set of watched video IDs with timestamps.
@@ -18,7 +68,28 @@ pub struct History {
     watched_videos: HashSet<String>,
     /// Map of video ID to watch timestamp (for future use)
     #[serde(default)]
-    watch_timestamps: std::collections::HashMap<String, String>,
+    watch_timestamps: HashMap<String, String>,
+    /// Map of video ID to saved playback progress
+    #[serde(default)]
+    watch_progress: HashMap<String, WatchProgress>,
+    /// Map of video ID to the time (RFC3339) until which it is snoozed
+    #[serde(default)]
+    snoozed: HashMap<String, String>,
+    /// Recent platform search queries (most recent first)
+    #[serde(default)]
+    recent_searches: Vec<String>,
+    /// Search tab query at the end of the last session
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    last_search: Option<String>,
+    /// Sort mode chosen with `s` per tab (tab key to sort mode name)
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    sort_modes: HashMap<String, String>,
+    /// Channel IDs blocked with `m` (added to the config's `blocked_channels`)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    blocked_channels: Vec<String>,
+    /// Changed since the last save
+    #[serde(skip)]
+    dirty: bool,
 }
 
 impl History {
@@ -32,6 +103,7 @@ impl History {
     ///
     /// # Details
     /// If the file doesn't exist, returns an empty history.
+    /// Snoozes that have run out are dropped.
     pub fn load(path: &Path) -> Result<Self> {
         if !path.exists() {
             return Ok(Self::default());
@@ -40,8 +112,9 @@ impl History {
         let content = fs::read_to_string(path)
             .with_context(|| format!("Failed to read history file: {}", path.display()))?;
 
-        let history: History =
+        let mut history: History =
             serde_json::from_str(&content).with_context(|| "Failed to parse history file")?;
+        history.expire_snoozes(Utc::now());
 
         Ok(history)
     }
@@ -55,8 +128,12 @@ impl History {
     /// * `Result<()>` - Success or error
     ///
     /// # Details
-    /// Creates parent directory if it doesn't exist.
-    pub fn save(&self, path: &Path) -> Result<()> {
+    /// Creates parent directory if it doesn't exist. Clears the dirty flag on success.
+    /// Fails with a clear message if the path is a directory or can't be
+    /// written (see `check_history_path`).
+    pub fn save(&mut self, path: &Path) -> Result<()> {
+        check_history_path(path)?;
+
         // Create parent directory if it doesn't exist
         if let Some(parent) = path.parent() {
             fs::create_dir_all(parent).with_context(|| {
@@ -69,9 +146,25 @@ impl History {
         fs::write(path, json)
             .with_context(|| format!("Failed to write history file: {}", path.display()))?;
 
+        self.dirty = false;
         Ok(())
     }
 
+    /// Save history if it changed since the last save.
+    ///
+    /// # Arguments
+    /// * `path` - Path to history JSON file
+    ///
+    /// # Returns
+    /// * `Result<bool>` - Whether the file was written
+    pub fn flush(&mut self, path: &Path) -> Result<bool> {
+        if !self.dirty {
+            return Ok(false);
+        }
+        self.save(path)?;
+        Ok(true)
+    }
+
     /// Mark a video as watched.
     ///
     /// # Arguments
@@ -83,6 +176,7 @@ impl History {
         self.watched_videos.insert(video_id.to_string());
         self.watch_timestamps
             .insert(video_id.to_string(), chrono::Utc::now().to_rfc3339());
+        self.dirty = true;
     }
 
     /// Check if a video is watched.
@@ -96,33 +190,369 @@ impl History {
         self.watched_videos.contains(video_id)
     }
 
+    /// Get the time a video was watched.
+    ///
+    /// # Arguments
+    /// * `video_id` - YouTube video ID
+    ///
+    /// # Returns
+    /// * `Option<DateTime<Utc>>` - Watch timestamp, or None if unwatched or unparseable
+    pub fn watched_at(&self, video_id: &str) -> Option<chrono::DateTime<chrono::Utc>> {
+        self.watch_timestamps
+            .get(video_id)
+            .and_then(|t| chrono::DateTime::parse_from_rfc3339(t).ok())
+            .map(|t| t.with_timezone(&chrono::Utc))
+    }
+
     /// Get count of watched videos.
     ///
     /// # Returns
     /// * `usize` - Number of watched videos
-    #[allow(dead_code)] // Useful for displaying statistics in UI
     pub fn watched_count(&self) -> usize {
         self.watched_videos.len()
     }
 
+    /// Get the number of distinct days with at least one watched video.
+    ///
+    /// # Returns
+    /// * `usize` - Number of active days (in local time)
+    pub fn days_active(&self) -> usize {
+        self.active_days(&Local).len()
+    }
+
+    /// Get the current daily watch streak.
+    ///
+    /// # Returns
+    /// * `u32` - Number of consecutive days (in local time) with at least one watched video
+    ///
+    /// # Details
+    /// The streak ends today, or yesterday if nothing has been watched yet today,
+    /// so it doesn't reset before the day is over.
+    pub fn current_streak(&self) -> u32 {
+        Self::streak_ending(&self.active_days(&Local), Local::now().date_naive())
+    }
+
+    /// Collect the calendar days on which videos were watched.
+    ///
+    /// # Arguments
+    /// * `tz` - Time zone used to turn UTC timestamps into calendar days
+    ///
+    /// # Returns
+    /// * `BTreeSet<NaiveDate>` - Distinct watch days
+    fn active_days<Tz: TimeZone>(&self, tz: &Tz) -> BTreeSet<NaiveDate> {
+        self.watch_timestamps
+            .values()
+            .filter_map(|t| chrono::DateTime::parse_from_rfc3339(t).ok())
+            .map(|t| t.with_timezone(tz).date_naive())
+            .collect()
+    }
+
+    /// Count consecutive active days ending at `today` (or the day before).
+    ///
+    /// # Arguments
+    /// * `days` - Distinct watch days
+    /// * `today` - Current calendar day
+    ///
+    /// # Returns
+    /// * `u32` - Streak length in days
+    fn streak_ending(days: &BTreeSet<NaiveDate>, today: NaiveDate) -> u32 {
+        let mut day = if days.contains(&today) {
+            today
+        } else {
+            match today.pred_opt() {
+                Some(yesterday) if days.contains(&yesterday) => yesterday,
+                _ => return 0,
+            }
+        };
+
+        let mut streak = 0;
+        while days.contains(&day) {
+            streak += 1;
+            match day.pred_opt() {
+                Some(previous) => day = previous,
+                None => break,
+            }
+        }
+        streak
+    }
+
+    /// Import watched video IDs from a file.
+    ///
+    /// # Arguments
+    /// * `path` - File with one ID per line, or a JSON array of IDs
+    /// * `watched_at` - Watch time to record (None for now)
+    ///
+    /// # Returns
+    /// * `Result<usize>` - Number of newly imported IDs, or error if the file can't be read or parsed
+    ///
+    /// # Details
+    /// Blank lines and lines starting with `#` are skipped. IDs already in the
+    /// history (or repeated in the file) are not imported again, so their
+    /// original watch time is kept.
+    pub fn import_ids(&mut self, path: &Path, watched_at: Option<DateTime<Utc>>) -> Result<usize> {
+        let content = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read import file: {}", path.display()))?;
+
+        let ids: Vec<String> = if content.trim_start().starts_with('[') {
+            serde_json::from_str(&content)
+                .with_context(|| format!("Failed to parse JSON array in {}", path.display()))?
+        } else {
+            content
+                .lines()
+                .map(str::trim)
+                .filter(|line| !line.is_empty() && !line.starts_with('#'))
+                .map(str::to_string)
+                .collect()
+        };
+
+        let timestamp = watched_at.unwrap_or_else(Utc::now).to_rfc3339();
+        let mut imported = 0;
+        for id in ids {
+            let id = id.trim();
+            if id.is_empty() || self.watched_videos.contains(id) {
+                continue;
+            }
+            self.watched_videos.insert(id.to_string());
+            self.watch_timestamps
+                .insert(id.to_string(), timestamp.clone());
+            imported += 1;
+        }
+
+        if imported > 0 {
+            self.dirty = true;
+        }
+        Ok(imported)
+    }
+
+    /// Reconcile the watched set with the watch timestamps.
+    ///
+    /// # Returns
+    /// * `CompactReport` - Number of backfilled and removed entries
+    ///
+    /// # Details
+    /// Every watched video gets a timestamp (the Unix epoch if it had none) and
+    /// timestamps of videos that aren't in the watched set are dropped.
+    pub fn compact(&mut self) -> CompactReport {
+        let mut report = CompactReport::default();
+
+        let before = self.watch_timestamps.len();
+        let watched = &self.watched_videos;
+        self.watch_timestamps.retain(|id, _| watched.contains(id));
+        report.orphans_removed = before - self.watch_timestamps.len();
+
+        for id in &self.watched_videos {
+            if !self.watch_timestamps.contains_key(id) {
+                self.watch_timestamps
+                    .insert(id.clone(), DateTime::<Utc>::UNIX_EPOCH.to_rfc3339());
+                report.backfilled += 1;
+            }
+        }
+
+        if report != CompactReport::default() {
+            self.dirty = true;
+        }
+        report
+    }
+
     /// Clear all history.
     ///
     /// # Details
     /// Removes all watched video entries.
-    #[allow(dead_code)] // Useful for future "clear history" feature
     pub fn clear(&mut self) {
         self.watched_videos.clear();
         self.watch_timestamps.clear();
+        self.watch_progress.clear();
+        self.snoozed.clear();
+        self.dirty = true;
     }
 
     /// Remove a video from history.
     ///
     /// # Arguments
     /// * `video_id` - YouTube video ID to remove
-    #[allow(dead_code)] // Useful for "unwatch" feature
     pub fn remove(&mut self, video_id: &str) {
         self.watched_videos.remove(video_id);
         self.watch_timestamps.remove(video_id);
+        self.watch_progress.remove(video_id);
+        self.dirty = true;
+    }
+
+    /// Record the playback position of a video.
+    ///
+    /// # Arguments
+    /// * `video_id` - YouTube video ID
+    /// * `position` - Playback position in seconds
+    /// * `duration` - Total video duration in seconds
+    ///
+    /// # Details
+    /// Stores the position with the current timestamp, replacing any previous entry.
+    pub fn set_progress(&mut self, video_id: &str, position: u64, duration: u64) {
+        self.watch_progress.insert(
+            video_id.to_string(),
+            WatchProgress {
+                position,
+                duration,
+                updated_at: chrono::Utc::now().to_rfc3339(),
+            },
+        );
+        self.dirty = true;
+    }
+
+    /// Get the saved playback progress of a video.
+    ///
+    /// # Arguments
+    /// * `video_id` - YouTube video ID
+    ///
+    /// # Returns
+    /// * `Option<&WatchProgress>` - Saved progress or None
+    pub fn progress(&self, video_id: &str) -> Option<&WatchProgress> {
+        self.watch_progress.get(video_id)
+    }
+
+    /// Snooze a video so it is hidden until a given time.
+    ///
+    /// # Arguments
+    /// * `video_id` - YouTube video ID
+    /// * `until` - Time at which the video shows up again
+    pub fn snooze(&mut self, video_id: &str, until: DateTime<Utc>) {
+        self.snoozed
+            .insert(video_id.to_string(), until.to_rfc3339());
+        self.dirty = true;
+    }
+
+    /// Check if a video is currently snoozed.
+    ///
+    /// # Arguments
+    /// * `video_id` - YouTube video ID
+    /// * `now` - Current time
+    ///
+    /// # Returns
+    /// * `bool` - True if the video's snooze ends after `now`
+    pub fn is_snoozed(&self, video_id: &str, now: DateTime<Utc>) -> bool {
+        self.snoozed
+            .get(video_id)
+            .and_then(|until| DateTime::parse_from_rfc3339(until).ok())
+            .is_some_and(|until| until > now)
+    }
+
+    /// Drop snoozes that have ended.
+    ///
+    /// # Arguments
+    /// * `now` - Current time
+    ///
+    /// # Details
+    /// Entries with unparseable timestamps are dropped as well.
+    pub fn expire_snoozes(&mut self, now: DateTime<Utc>) {
+        let before = self.snoozed.len();
+        self.snoozed
+            .retain(|_, until| DateTime::parse_from_rfc3339(until).is_ok_and(|until| until > now));
+        self.dirty |= self.snoozed.len() != before;
+    }
+
+    /// Remember a platform search query.
+    ///
+    /// # Arguments
+    /// * `query` - Search query
+    ///
+    /// # Details
+    /// Moves the query to the front (without duplicates) and keeps at most 10 entries.
+    pub fn add_recent_search(&mut self, query: &str) {
+        let query = query.trim();
+        if query.is_empty() {
+            return;
+        }
+        self.recent_searches.retain(|q| q != query);
+        self.recent_searches.insert(0, query.to_string());
+        self.recent_searches.truncate(RECENT_SEARCH_LIMIT);
+        self.dirty = true;
+    }
+
+    /// Get recent platform search queries.
+    ///
+    /// # Returns
+    /// * `&[String]` - Queries, most recent first
+    pub fn recent_searches(&self) -> &[String] {
+        &self.recent_searches
+    }
+
+    /// Remember the Search tab query at the end of a session.
+    ///
+    /// # Arguments
+    /// * `query` - Search query (blank clears it)
+    pub fn set_last_search(&mut self, query: &str) {
+        let query = query.trim();
+        let last_search = (!query.is_empty()).then(|| query.to_string());
+        if last_search != self.last_search {
+            self.last_search = last_search;
+            self.dirty = true;
+        }
+    }
+
+    /// Get the Search tab query of the last session.
+    ///
+    /// # Returns
+    /// * `Option<&str>` - Query, if a search was open at exit
+    pub fn last_search(&self) -> Option<&str> {
+        self.last_search.as_deref()
+    }
+
+    /// Remember the sort mode chosen for a tab.
+    ///
+    /// # Arguments
+    /// * `tab` - Tab key (see `Tab::key`)
+    /// * `mode` - Sort mode name (see `SortMode::as_config_str`)
+    pub fn set_sort_mode(&mut self, tab: &str, mode: &str) {
+        if self.sort_modes.get(tab).map(String::as_str) != Some(mode) {
+            self.sort_modes.insert(tab.to_string(), mode.to_string());
+            self.dirty = true;
+        }
+    }
+
+    /// Get the sort mode last chosen for a tab.
+    ///
+    /// # Arguments
+    /// * `tab` - Tab key (see `Tab::key`)
+    ///
+    /// # Returns
+    /// * `Option<&str>` - Sort mode name, if one was chosen with `s`
+    pub fn sort_mode(&self, tab: &str) -> Option<&str> {
+        self.sort_modes.get(tab).map(String::as_str)
+    }
+
+    /// Add a channel to the blocklist kept in the history.
+    ///
+    /// # Arguments
+    /// * `channel_id` - YouTube channel ID
+    pub fn block_channel(&mut self, channel_id: &str) {
+        if !self.blocked_channels.iter().any(|id| id == channel_id) {
+            self.blocked_channels.push(channel_id.to_string());
+            self.dirty = true;
+        }
+    }
+
+    /// Get the channels blocked with `m`.
+    ///
+    /// # Returns
+    /// * `&[String]` - Channel IDs, in the order they were blocked
+    pub fn blocked_channels(&self) -> &[String] {
+        &self.blocked_channels
+    }
+
+    /// Get IDs of partially watched videos for "continue watching".
+    ///
+    /// # Returns
+    /// * `Vec<String>` - Video IDs sorted by most recently paused first
+    ///
+    /// # Details
+    /// Only includes videos whose saved position is between 5% and 95% of the duration.
+    pub fn continue_watching_ids(&self) -> Vec<String> {
+        let mut entries: Vec<(&String, &WatchProgress)> = self
+            .watch_progress
+            .iter()
+            .filter(|(_, progress)| progress.is_resumable())
+            .collect();
+        entries.sort_by(|a, b| b.1.updated_at.cmp(&a.1.updated_at));
+        entries.into_iter().map(|(id, _)| id.clone()).collect()
     }
 
     /// Get watched videos sorted by timestamp (newest first).
@@ -153,6 +583,92 @@ impl History {
 
         videos
     }
+
+    /// Export the watched videos to a CSV file.
+    ///
+    /// # Arguments
+    /// * `path` - Path of the CSV file to write
+    ///
+    /// # Returns
+    /// * `Result<()>` - Success or error
+    ///
+    /// # Details
+    /// Writes a `video_id,watched_at` header and one row per watched video,
+    /// newest first (same order as `get_watched_videos_sorted`). An empty
+    /// history produces just the header.
+    pub fn export_csv(&self, path: &Path) -> Result<()> {
+        let mut csv = String::from("video_id,watched_at\n");
+        for (video_id, watched_at) in self.get_watched_videos_sorted() {
+            csv.push_str(&format!(
+                "{},{}\n",
+                csv_field(&video_id),
+                csv_field(&watched_at)
+            ));
+        }
+        fs::write(path, csv)
+            .with_context(|| format!("Failed to write history export: {}", path.display()))
+    }
+}
+
+/// Quote a CSV field if it contains a separator, quote or line break.
+///
+/// # Arguments
+/// * `value` - Raw field value
+///
+/// # Returns
+/// * `String` - Field ready to be written to a CSV row
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// Check that a history path can be written as a file.
+///
+/// # Arguments
+/// * `path` - Path to history JSON file
+///
+/// # Returns
+/// * `Result<()>` - Success, or error if the path is a directory, the file
+///   can't be opened for writing or its nearest existing parent directory
+///   doesn't allow creating files
+///
+/// # Details
+/// An existing file is opened for append, which fails the same way writing
+/// would (ownership included). Otherwise a probe file is created and removed
+/// in the closest ancestor that exists, since `History::save` creates missing
+/// parent directories.
+pub fn check_history_path(path: &Path) -> Result<()> {
+    if path.is_dir() {
+        return Err(anyhow::anyhow!(
+            "history_path points to a directory, expected a file: {}",
+            path.display()
+        ));
+    }
+    if path.exists() {
+        fs::OpenOptions::new()
+            .append(true)
+            .open(path)
+            .with_context(|| format!("Cannot save history: {} is not writable", path.display()))?;
+        return Ok(());
+    }
+    if let Some(parent) = path.ancestors().skip(1).find(|dir| dir.exists()) {
+        let probe = parent.join(format!(".yt-tui-write-test-{}", std::process::id()));
+        fs::OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(&probe)
+            .with_context(|| {
+                format!(
+                    "Cannot save history: directory {} is not writable",
+                    parent.display()
+                )
+            })?;
+        let _ = fs::remove_file(&probe);
+    }
+    Ok(())
 }
 
 #[cfg(test)]
@@ -193,6 +709,85 @@ mod tests {
         assert!(loaded.is_watched("video2"));
     }
 
+    #[test]
+    fn test_history_save_to_directory() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut history = History::default();
+        history.mark_watched("video1");
+
+        let err = history.save(temp_dir.path()).unwrap_err();
+        assert!(err.to_string().contains("points to a directory"));
+        assert!(history.dirty);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_history_save_unwritable_parent() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let temp_dir = TempDir::new().unwrap();
+        let locked = temp_dir.path().join("locked");
+        fs::create_dir(&locked).unwrap();
+        let existing = temp_dir.path().join("history.json");
+        fs::write(&existing, "{}").unwrap();
+        fs::set_permissions(&locked, fs::Permissions::from_mode(0o555)).unwrap();
+        fs::set_permissions(&existing, fs::Permissions::from_mode(0o444)).unwrap();
+
+        // Root ignores permission bits, so there is nothing to test
+        if fs::write(locked.join("probe"), "").is_err() {
+            let mut history = History::default();
+            history.mark_watched("video1");
+            let err = history
+                .save(&locked.join("nested").join("history.json"))
+                .unwrap_err();
+            assert!(err.to_string().contains("not writable"));
+            let err = history.save(&existing).unwrap_err();
+            assert!(err.to_string().contains("not writable"));
+            assert!(history.dirty);
+        }
+
+        // Restore permissions so the temp dir can be cleaned up
+        fs::set_permissions(&locked, fs::Permissions::from_mode(0o755)).unwrap();
+    }
+
+    #[test]
+    fn test_check_history_path_leaves_no_files() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("nested").join("history.json");
+        check_history_path(&path).unwrap();
+        assert_eq!(fs::read_dir(temp_dir.path()).unwrap().count(), 0);
+    }
+
+    #[test]
+    fn test_history_export_csv() {
+        let temp_dir = TempDir::new().unwrap();
+        let csv_path = temp_dir.path().join("history_export.csv");
+
+        let mut history = History::default();
+        history.export_csv(&csv_path).unwrap();
+        assert_eq!(
+            fs::read_to_string(&csv_path).unwrap(),
+            "video_id,watched_at\n"
+        );
+
+        history.watched_videos.insert("old".to_string());
+        history
+            .watch_timestamps
+            .insert("old".to_string(), "2024-01-01T00:00:00+00:00".to_string());
+        history.watched_videos.insert("new".to_string());
+        history
+            .watch_timestamps
+            .insert("new".to_string(), "2024-06-01T00:00:00+00:00".to_string());
+        history.export_csv(&csv_path).unwrap();
+        assert_eq!(
+            fs::read_to_string(&csv_path).unwrap(),
+            "video_id,watched_at\n\
+             new,2024-06-01T00:00:00+00:00\n\
+             old,2024-01-01T00:00:00+00:00\n"
+        );
+        assert_eq!(csv_field("a,\"b\""), "\"a,\"\"b\"\"\"");
+    }
+
     #[test]
     fn test_history_clear() {
         let mut history = History::default();
@@ -217,4 +812,251 @@ mod tests {
         assert!(!history.is_watched("video1"));
         assert!(history.is_watched("video2"));
     }
+
+    #[test]
+    fn test_history_continue_watching_ids() {
+        let mut history = History::default();
+        let progress = |position, updated_at: &str| WatchProgress {
+            position,
+            duration: 1000,
+            updated_at: updated_at.to_string(),
+        };
+        history.watch_progress.insert(
+            "barely_started".to_string(),
+            progress(20, "2024-01-03T00:00:00+00:00"),
+        );
+        history.watch_progress.insert(
+            "halfway".to_string(),
+            progress(500, "2024-01-01T00:00:00+00:00"),
+        );
+        history.watch_progress.insert(
+            "recent".to_string(),
+            progress(100, "2024-01-02T00:00:00+00:00"),
+        );
+        history.watch_progress.insert(
+            "almost_done".to_string(),
+            progress(980, "2024-01-04T00:00:00+00:00"),
+        );
+
+        assert_eq!(history.continue_watching_ids(), vec!["recent", "halfway"]);
+    }
+
+    #[test]
+    fn test_history_streak_with_gaps() {
+        let mut history = History::default();
+        for (id, timestamp) in [
+            ("a", "2024-01-10T08:00:00+00:00"),
+            ("b", "2024-01-09T23:30:00+00:00"),
+            ("c", "2024-01-08T12:00:00+00:00"),
+            ("d", "2024-01-08T18:00:00+00:00"),
+            ("e", "2024-01-06T12:00:00+00:00"),
+        ] {
+            history.watched_videos.insert(id.to_string());
+            history
+                .watch_timestamps
+                .insert(id.to_string(), timestamp.to_string());
+        }
+
+        let days = history.active_days(&chrono::Utc);
+        assert_eq!(days.len(), 4);
+
+        let date = |d| NaiveDate::from_ymd_opt(2024, 1, d).unwrap();
+        // Streak covers 8th-10th; the gap on the 7th stops it
+        assert_eq!(History::streak_ending(&days, date(10)), 3);
+        // Nothing watched yet today: yesterday's streak still counts
+        assert_eq!(History::streak_ending(&days, date(11)), 3);
+        // A full day without watching resets the streak
+        assert_eq!(History::streak_ending(&days, date(12)), 0);
+        assert_eq!(History::streak_ending(&BTreeSet::new(), date(10)), 0);
+    }
+
+    #[test]
+    fn test_snooze_and_expiry() {
+        let now = Utc::now();
+        let mut history = History::default();
+        history.snooze("video1", now + chrono::Duration::hours(24));
+        history.snooze("video2", now - chrono::Duration::hours(1));
+
+        assert!(history.is_snoozed("video1", now));
+        assert!(!history.is_snoozed("video1", now + chrono::Duration::hours(25)));
+        assert!(!history.is_snoozed("video2", now));
+        assert!(!history.is_snoozed("video3", now));
+
+        history.expire_snoozes(now);
+        assert!(history.snoozed.contains_key("video1"));
+        assert!(!history.snoozed.contains_key("video2"));
+    }
+
+    #[test]
+    fn test_snooze_expired_on_load() {
+        let temp_dir = TempDir::new().unwrap();
+        let history_path = temp_dir.path().join("history.json");
+        let mut history = History::default();
+        history.snooze("future", Utc::now() + chrono::Duration::hours(1));
+        history.snooze("past", Utc::now() - chrono::Duration::hours(1));
+        history.save(&history_path).unwrap();
+
+        let loaded = History::load(&history_path).unwrap();
+        assert!(loaded.snoozed.contains_key("future"));
+        assert!(!loaded.snoozed.contains_key("past"));
+    }
+
+    #[test]
+    fn test_recent_searches() {
+        let mut history = History::default();
+        history.add_recent_search("rust");
+        history.add_recent_search("  ");
+        history.add_recent_search("tokio");
+        history.add_recent_search("rust");
+        assert_eq!(history.recent_searches(), ["rust", "tokio"]);
+
+        for i in 0..20 {
+            history.add_recent_search(&format!("query {}", i));
+        }
+        assert_eq!(history.recent_searches().len(), RECENT_SEARCH_LIMIT);
+        assert_eq!(history.recent_searches()[0], "query 19");
+    }
+
+    #[test]
+    fn test_last_search_persisted() {
+        let temp_dir = TempDir::new().unwrap();
+        let history_path = temp_dir.path().join("history.json");
+
+        // Files written before the field existed still load
+        fs::write(&history_path, r#"{"watched_videos": ["a"]}"#).unwrap();
+        assert_eq!(History::load(&history_path).unwrap().last_search(), None);
+
+        let mut history = History::default();
+        history.set_last_search(" rust tui ");
+        history.save(&history_path).unwrap();
+        let mut loaded = History::load(&history_path).unwrap();
+        assert_eq!(loaded.last_search(), Some("rust tui"));
+
+        // A blank query clears it and isn't written at all
+        loaded.set_last_search("   ");
+        loaded.save(&history_path).unwrap();
+        assert!(
+            !fs::read_to_string(&history_path)
+                .unwrap()
+                .contains("last_search")
+        );
+        assert_eq!(History::load(&history_path).unwrap().last_search(), None);
+    }
+
+    #[test]
+    fn test_sort_modes_persisted() {
+        let temp_dir = TempDir::new().unwrap();
+        let history_path = temp_dir.path().join("history.json");
+        let mut history = History::default();
+        history.set_sort_mode("saved", "date");
+        history.set_sort_mode("search", "views");
+        assert!(history.dirty);
+        history.save(&history_path).unwrap();
+
+        let mut loaded = History::load(&history_path).unwrap();
+        assert_eq!(loaded.sort_mode("saved"), Some("date"));
+        assert_eq!(loaded.sort_mode("search"), Some("views"));
+        assert_eq!(loaded.sort_mode("current"), None);
+        // Choosing the same mode again changes nothing
+        loaded.set_sort_mode("saved", "date");
+        assert!(!loaded.dirty);
+    }
+
+    #[test]
+    fn test_dirty_flag_and_flush() {
+        let temp_dir = TempDir::new().unwrap();
+        let history_path = temp_dir.path().join("history.json");
+        let mut history = History::default();
+        assert!(!history.dirty);
+        assert!(!history.flush(&history_path).unwrap());
+        assert!(!history_path.exists());
+
+        history.mark_watched("video1");
+        assert!(history.dirty);
+        assert!(history.flush(&history_path).unwrap());
+        assert!(!history.dirty);
+        assert!(history_path.exists());
+        assert!(!history.flush(&history_path).unwrap());
+
+        history.add_recent_search("rust");
+        history.snooze("video2", Utc::now() + chrono::Duration::hours(1));
+        assert!(history.dirty);
+        history.save(&history_path).unwrap();
+        assert!(!history.dirty);
+
+        // Loading doesn't count as a change
+        assert!(!History::load(&history_path).unwrap().dirty);
+    }
+
+    #[test]
+    fn test_compact_reconciles_watched_and_timestamps() {
+        let json = r#"{
+            "watched_videos": ["a", "b"],
+            "watch_timestamps": {
+                "a": "2024-01-01T00:00:00+00:00",
+                "orphan": "2024-01-02T00:00:00+00:00"
+            }
+        }"#;
+        let mut history: History = serde_json::from_str(json).unwrap();
+
+        let report = history.compact();
+        assert_eq!(
+            report,
+            CompactReport {
+                backfilled: 1,
+                orphans_removed: 1
+            }
+        );
+        assert_eq!(history.watch_timestamps.len(), 2);
+        assert_eq!(history.watched_at("b"), Some(DateTime::<Utc>::UNIX_EPOCH));
+        assert!(history.watched_at("a").is_some());
+        assert!(!history.watch_timestamps.contains_key("orphan"));
+
+        // A consistent history is left alone
+        history.dirty = false;
+        assert_eq!(history.compact(), CompactReport::default());
+        assert!(!history.dirty);
+    }
+
+    #[test]
+    fn test_import_ids_plain_text() {
+        let temp_dir = TempDir::new().unwrap();
+        let import_path = temp_dir.path().join("ids.txt");
+        fs::write(
+            &import_path,
+            "# exported ids\nvideo1\n\n  video2  \nvideo1\nexisting\n",
+        )
+        .unwrap();
+
+        let mut history = History::default();
+        history.mark_watched("existing");
+        let original = history.watched_at("existing");
+        let at = DateTime::parse_from_rfc3339("2023-05-01T12:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+
+        assert_eq!(history.import_ids(&import_path, Some(at)).unwrap(), 2);
+        assert_eq!(history.watched_count(), 3);
+        assert_eq!(history.watched_at("video2"), Some(at));
+        // Already watched videos keep their watch time
+        assert_eq!(history.watched_at("existing"), original);
+    }
+
+    #[test]
+    fn test_import_ids_json_array() {
+        let temp_dir = TempDir::new().unwrap();
+        let import_path = temp_dir.path().join("ids.json");
+        fs::write(&import_path, r#"["a", "b", "a", ""]"#).unwrap();
+
+        let mut history = History::default();
+        assert_eq!(history.import_ids(&import_path, None).unwrap(), 2);
+        assert!(history.is_watched("a") && history.is_watched("b"));
+        assert!(history.watched_at("a").is_some());
+
+        // Importing again adds nothing
+        assert_eq!(history.import_ids(&import_path, None).unwrap(), 0);
+
+        fs::write(&import_path, r#"["unterminated"#).unwrap();
+        assert!(history.import_ids(&import_path, None).is_err());
+    }
 }
@@ -3,13 +3,21 @@
 //! Main entry point and event loop for the application.
 
 mod app;
+mod cli;
+mod clipboard;
 mod config;
+mod error;
 mod history;
+mod mpv_ipc;
+mod oauth;
 mod player;
+mod query;
+mod saved;
+mod transcript;
 mod ui;
 mod youtube;
 
-use app::{App, UiMode};
+use app::{App, ItemRow, UiMode};
 use config::Config;
 use crossterm::{
     event::{
@@ -19,48 +27,210 @@ use crossterm::{
     execute,
     terminal::{EnterAlternateScreen, LeaveAlternateScreen, disable_raw_mode, enable_raw_mode},
 };
+use error::AppError;
 use history::History;
-use player::open_in_mpv;
-use ratatui::{Terminal, backend::CrosstermBackend};
+use player::{open_in_browser, open_in_player, play_queue};
+use ratatui::{
+    Terminal,
+    backend::CrosstermBackend,
+    layout::{Constraint, Direction, Layout, Rect},
+};
+use saved::SavedList;
 use std::io;
-use std::time::Duration;
-use youtube::YouTubeClient;
+use std::time::{Duration, Instant};
+use youtube::{KeyStatus, YouTubeClient};
+
+/// Maximum results the API returns per request.
+const MAX_FETCH_SIZE: u32 = youtube::client::MAX_RESULTS_PER_REQUEST;
+/// Minimum number of videos fetched at startup, even on tiny terminals.
+const MIN_FETCH_SIZE: u32 = 10;
+/// Screens' worth of videos fetched at startup so there's enough to scroll.
+const FETCH_SCREENS: u32 = 5;
+/// Time between reads of MPV's playback state for "Now playing".
+const NOW_PLAYING_INTERVAL: Duration = Duration::from_secs(1);
 
 /// Main application entry point.
 ///
+/// # Details
+/// Runs the application and exits with a code scripts can check:
+/// 0 success, 1 other error, 2 missing API key, 3 network failure, 4 invalid config.
+#[tokio::main]
+async fn main() {
+    let result = run().await;
+    if let Err(e) = &result {
+        eprintln!("Error: {:#}", e);
+    }
+    std::process::exit(error::exit_code(&result));
+}
+
+/// Check that an API key is configured.
+///
+/// # Arguments
+/// * `config` - Loaded configuration
+///
+/// # Returns
+/// * `Result<()>` - Success, or `AppError::MissingApiKey` with the expected config path
+fn require_api_key(config: &Config) -> anyhow::Result<()> {
+    if config.api_key.is_empty() {
+        return Err(AppError::MissingApiKey(config.file_path()?).into());
+    }
+    Ok(())
+}
+
+/// Run the application.
+///
 /// # Returns
 /// * `Result<()>` - Success or error
 ///
 /// # Details
 /// Initializes terminal, loads configuration, fetches videos, and runs the event loop.
-#[tokio::main]
-async fn main() -> anyhow::Result<()> {
+async fn run() -> anyhow::Result<()> {
+    let args = cli::parse_args(std::env::args().skip(1))?;
+    if args.help {
+        println!("{}", cli::USAGE);
+        return Ok(());
+    }
+
     // Load configuration
-    let config = Config::load(None)?;
+    if let Some(path) = &args.config {
+        check_config_arg(path, args.login)?;
+    }
+    let mut config = Config::load(args.config.as_deref())
+        .map_err(|e| AppError::InvalidConfig(format!("{:#}", e)))?;
+    config.debug = args.debug;
 
-    if config.api_key.is_empty() {
-        eprintln!("Error: YouTube API key is required.");
-        eprintln!(
-            "Please create a config file at: {}",
-            Config::default_config_path()?.display()
+    if args.login {
+        return oauth::run_login_flow(&mut config).await;
+    }
+    config.apply_overrides(args.api_key.clone(), args.no_oauth);
+
+    if args.compact_history {
+        let history_path = config.history_file_path()?;
+        let mut history = History::load(&history_path)?;
+        let report = history.compact();
+        history.save(&history_path)?;
+        println!(
+            "Compacted {}: {} missing timestamps backfilled, {} orphaned timestamps removed",
+            history_path.display(),
+            report.backfilled,
+            report.orphans_removed
+        );
+        return Ok(());
+    }
+
+    if let Some(import_path) = &args.import_history {
+        let history_path = config.history_file_path()?;
+        let mut history = History::load(&history_path)?;
+        let imported = history.import_ids(import_path, None)?;
+        history.save(&history_path)?;
+        println!(
+            "Imported {} new watched videos from {}",
+            imported,
+            import_path.display()
         );
-        eprintln!("See config.jsonc.example for template.");
-        return Err(anyhow::anyhow!("API key not configured"));
+        return Ok(());
     }
 
+    require_api_key(&config)?;
+
     // Load history
     let history_path = config.history_file_path()?;
     let history = History::load(&history_path)?;
+    if let Some(query) = history.last_search() {
+        config.last_search = Some(query.to_string());
+    }
+    let saved_path = config.saved_file_path()?;
+    let saved = SavedList::load(&saved_path)?;
 
     // Create YouTube client
     let youtube_client = YouTubeClient::new(&config)?;
 
     // Create application state
     let mut app = App::new(history, config.hide_watched);
+    app.hide_shorts = config.hide_shorts;
+    app.saved = saved;
+    app.blocked_channels = config.blocked_channels.clone();
+    for channel_id in app.history.blocked_channels() {
+        if !config.blocked_channels.contains(channel_id) {
+            app.blocked_channels.push(channel_id.clone());
+        }
+    }
+    app.min_title_len = config.min_title_len;
+    app.debug = config.debug;
+    app.audio_only = config.audio_only;
+    app.confirm_play = config.confirm_play;
+    app.scroll_acceleration = config.scroll_acceleration;
+    app.open_confirm_threshold = config.multi_open_confirm_threshold;
+    match config.multi_open.parse() {
+        Ok(mode) => app.open_mode = mode,
+        Err(e) => eprintln!("Warning: {}; using default", e),
+    }
+    app.max_resolution = config.max_resolution;
+    app.player_command = config.player_command.clone();
+    app.player_args = config.player_args.clone();
+    app.relative_dates = config.relative_dates;
+    app.compact_list = config.compact_list;
+    app.use_short_urls = config.use_short_urls;
+    app.live_search_delay = (config.live_search_delay_ms > 0)
+        .then(|| Duration::from_millis(config.live_search_delay_ms));
+    app.no_color = config.no_color || std::env::var_os("NO_COLOR").is_some_and(|v| !v.is_empty());
+    match config.number_style.parse() {
+        Ok(style) => app.number_style = style,
+        Err(e) => eprintln!("Warning: {}; using default", e),
+    }
+    app.thousands_separator = config.thousands_separator.clone();
+    match config.scroll_mode.parse() {
+        Ok(mode) => app.scroll_mode = mode,
+        Err(e) => eprintln!("Warning: {}; using default", e),
+    }
+    for (tab, value) in [
+        (crate::app::Tab::CurrentView, &config.default_sort_current),
+        (crate::app::Tab::Search, &config.default_sort_search),
+        (crate::app::Tab::History, &config.default_sort_history),
+    ] {
+        match value.parse::<crate::app::SortMode>() {
+            Ok(mode) => app.set_initial_sort(tab, mode),
+            Err(e) => eprintln!("Warning: {}; using default", e),
+        }
+    }
+    // Sort modes chosen with 's' in earlier sessions take precedence
+    for tab in crate::app::Tab::ALL {
+        if let Some(Ok(mode)) = app.history.sort_mode(tab.key()).map(str::parse) {
+            app.set_initial_sort(tab, mode);
+        }
+    }
+
+    // Validate the API key before the full fetch for fast, precise feedback
+    let key_status = match youtube_client.validate_key().await {
+        Ok(KeyStatus::KeyInvalid) => {
+            return Err(AppError::InvalidConfig(KeyStatus::KeyInvalid.message()).into());
+        }
+        Ok(status) => status,
+        // Network problems are reported by the fetch below
+        Err(_) => KeyStatus::Valid,
+    };
 
     // Fetch videos
     app.set_status("Fetching recommended videos...".to_string());
-    match youtube_client.fetch_recommended_videos(50).await {
+    let fetch_result = if key_status == KeyStatus::QuotaExceeded {
+        // Skip the expensive fetch; it would fail the same way
+        Err(anyhow::anyhow!(key_status.message()))
+    } else {
+        // A few screens' worth, at most `default_results`
+        let max_results = youtube_client.default_results();
+        // The list starts at full detail
+        let item_height = if config.compact_list {
+            1
+        } else {
+            ui::list::LINES_PER_VIDEO
+        };
+        let fetch_size = crossterm::terminal::size()
+            .map(|(width, height)| initial_fetch_size(width, height, item_height).min(max_results))
+            .unwrap_or(max_results);
+        youtube_client.fetch_recommended_videos(fetch_size).await
+    };
+    let mut fetch_error = None;
+    match fetch_result {
         Ok(videos) => {
             if videos.is_empty() {
                 app.set_status(
@@ -75,9 +245,47 @@ async fn main() -> anyhow::Result<()> {
             let error_msg = format!("Error fetching videos: {}", e);
             eprintln!("{}", error_msg);
             app.set_status(error_msg);
+            fetch_error = Some(format!("{:#}", e));
         }
     }
 
+    // Export the fetched list and exit without starting the TUI
+    if let Some(path) = &args.export_m3u {
+        for warning in youtube_client.take_warnings() {
+            eprintln!("Warning: {}", warning);
+        }
+        if let Some(e) = fetch_error {
+            return Err(AppError::Network(e).into());
+        }
+        let exported = app.export_m3u(path)?;
+        println!("Exported {} videos to {}", exported, path.display());
+        return Ok(());
+    }
+
+    // Pre-fill the last search, so Enter on the Search tab re-runs it; running
+    // it right away is opt-in, since searches cost quota
+    if app.prefill_last_search(config.last_search.as_deref())
+        && config.resume_last_search
+        && key_status != KeyStatus::QuotaExceeded
+    {
+        app.switch_tab(crate::app::Tab::Search);
+        start_search(&mut app, &youtube_client);
+    }
+
+    // Warn early about a missing player instead of failing on the first play;
+    // only mpv needs yt-dlp
+    let missing: Vec<&str> = [
+        (!player::is_player_available(&config.player_command))
+            .then_some(config.player_command.as_str()),
+        (config.player_command == "mpv" && !player::is_ytdlp_available()).then_some("yt-dlp"),
+    ]
+    .into_iter()
+    .flatten()
+    .collect();
+    if let Some(warning) = player::missing_tools_warning(&missing) {
+        app.set_status(warning);
+    }
+
     // Setup terminal
     enable_raw_mode()?;
     let mut stdout = io::stdout();
@@ -86,7 +294,7 @@ async fn main() -> anyhow::Result<()> {
     let mut terminal = Terminal::new(backend)?;
 
     // Run event loop
-    let result = run_app(&mut terminal, &mut app, &config).await;
+    let result = run_app(&mut terminal, &mut app, &config, youtube_client).await;
 
     // Restore terminal
     disable_raw_mode()?;
@@ -100,46 +308,119 @@ async fn main() -> anyhow::Result<()> {
     result
 }
 
-/// Render the complete UI.
+/// Check the file given with `--config`.
 ///
 /// # Arguments
-/// * `f` - Frame to render to
-/// * `app` - Application state
+/// * `path` - Config file path
+/// * `login` - Whether `--login` was given
+///
+/// # Returns
+/// * `Result<()>` - Success, or error if the file doesn't exist
 ///
 /// # Details
-/// Lays out and renders all UI components including list, search, filters, and status.
-fn render_ui(f: &mut ratatui::Frame, app: &App) {
-    let chunks = ratatui::layout::Layout::default()
-        .direction(ratatui::layout::Direction::Vertical)
+/// With `--login` a path that doesn't exist yet is accepted, so signing in
+/// can be the first thing done with a new config (the tokens are saved next
+/// to it).
+fn check_config_arg(path: &std::path::Path, login: bool) -> anyhow::Result<()> {
+    if path.is_file() || (login && !path.exists()) {
+        return Ok(());
+    }
+    Err(AppError::InvalidConfig(format!("Config file not found: {}", path.display())).into())
+}
+
+/// Split the screen into the main UI areas.
+///
+/// # Arguments
+/// * `area` - Full terminal area
+/// * `collapsed` - Collapse search and filters to one line each (focus mode)
+///
+/// # Returns
+/// * `Rc<[Rect]>` - Search, filters, tabs, video list and status bar areas
+fn main_layout(area: Rect, collapsed: bool) -> std::rc::Rc<[Rect]> {
+    let (search_height, filters_height) = if collapsed { (1, 1) } else { (3, 6) };
+    Layout::default()
+        .direction(Direction::Vertical)
         .constraints([
-            ratatui::layout::Constraint::Length(3), // Search bar
-            ratatui::layout::Constraint::Length(6), // Filters
-            ratatui::layout::Constraint::Length(3), // Tabs
-            ratatui::layout::Constraint::Min(0),    // Video list
-            ratatui::layout::Constraint::Length(1), // Status bar
+            Constraint::Length(search_height),  // Search bar
+            Constraint::Length(filters_height), // Filters
+            Constraint::Length(3),              // Tabs
+            Constraint::Min(0),                 // Video list
+            Constraint::Length(1),              // Status bar
         ])
-        .split(f.area());
+        .split(area)
+}
 
-    // Render search
-    ui::render_search(app, chunks[0], f.buffer_mut());
+/// Compute how many videos to fetch at startup for a terminal size.
+///
+/// # Arguments
+/// * `width` - Terminal width in columns
+/// * `height` - Terminal height in rows
+/// * `item_height` - Rows per video (see `ui::list::list_item_height`)
+///
+/// # Returns
+/// * `u32` - A few screens' worth of videos, clamped to `MIN_FETCH_SIZE..=MAX_FETCH_SIZE`
+fn initial_fetch_size(width: u16, height: u16, item_height: u16) -> u32 {
+    let list_area = main_layout(Rect::new(0, 0, width, height), false)[3];
+    // Inside the list block's borders
+    let visible =
+        ui::list::visible_video_count(list_area.height.saturating_sub(2), item_height) as u32;
+    (visible * FETCH_SCREENS).clamp(MIN_FETCH_SIZE, MAX_FETCH_SIZE)
+}
 
-    // Render filters
-    ui::render_filters(app, chunks[1], f.buffer_mut());
+/// Render the complete UI.
+///
+/// # Arguments
+/// * `f` - Frame to render to
+/// * `app` - Application state
+///
+/// # Returns
+/// * `Vec<ItemRow>` - Screen rows of the rendered list items
+///
+/// # Details
+/// Lays out and renders all UI components including list, search, filters, status,
+/// and the message log or stats popup when open.
+fn render_ui(f: &mut ratatui::Frame, app: &App) -> Vec<ItemRow> {
+    let collapsed = app.chrome_collapsed();
+    let chunks = main_layout(f.area(), collapsed);
+
+    // Render search and filters (one summary line each in focus mode)
+    if collapsed {
+        ui::search::render_search_summary(app, chunks[0], f.buffer_mut());
+        ui::filters::render_filters_summary(app, chunks[1], f.buffer_mut());
+    } else {
+        ui::render_search(app, chunks[0], f.buffer_mut());
+        ui::render_filters(app, chunks[1], f.buffer_mut());
+    }
 
     // Render tabs
     ui::render_tabs(app, chunks[2], f.buffer_mut());
 
     // Render video list
-    ui::render_list(app, chunks[3], f.buffer_mut());
+    let mut item_rows = ui::render_list(app, chunks[3], f.buffer_mut());
+
+    // Render the preview pane over the bottom of the list
+    if app.preview_shown() {
+        let area = ui::preview::preview_area(chunks[3]);
+        ui::render_preview(app, area, f.buffer_mut());
+        // Items hidden behind the pane can't be clicked
+        item_rows.retain(|row| row.start_row < area.y);
+    }
+
+    // Render popups over the list
+    match app.mode {
+        UiMode::Log => ui::render_log(app, chunks[3], f.buffer_mut()),
+        UiMode::Chapters => ui::render_chapters(app, chunks[3], f.buffer_mut()),
+        UiMode::Debug => ui::render_debug(app, chunks[3], f.buffer_mut()),
+        UiMode::Detail => ui::render_detail(app, chunks[3], f.buffer_mut()),
+        UiMode::Stats => ui::render_stats(app, chunks[3], f.buffer_mut()),
+        UiMode::Filters => ui::filters::render_filter_editor(app, chunks[3], f.buffer_mut()),
+        _ => {}
+    }
 
     // Render status bar
-    let status_text = app
-        .status_message
-        .as_deref()
-        .unwrap_or("Press 'q' to quit, '/' to search, 'f' for filters, 'h' to toggle hide watched, 's' to change sort, '1/2/3' or Tab to switch tabs");
-    let status = ratatui::widgets::Paragraph::new(ratatui::text::Line::from(status_text))
-        .block(ratatui::widgets::Block::default().borders(ratatui::widgets::Borders::ALL));
-    f.render_widget(status, chunks[4]);
+    ui::render_status(app, chunks[4], f.buffer_mut());
+
+    item_rows
 }
 
 /// Main event loop.
@@ -158,40 +439,67 @@ async fn run_app(
     terminal: &mut Terminal<CrosstermBackend<io::Stdout>>,
     app: &mut App,
     config: &Config,
+    youtube_client: YouTubeClient,
 ) -> anyhow::Result<()> {
     // Calculate video list area boundaries (will be updated on each render)
     let mut list_area = ratatui::layout::Rect::default();
     let mut tabs_area = ratatui::layout::Rect::default();
+    let mut item_rows = Vec::new();
 
-    // Create YouTube client for async operations
-    let youtube_client = YouTubeClient::new(config)?;
+    // Changed history is written at most once per interval, and on exit
+    let history_path = config.history_file_path()?;
+    let saved_path = config.saved_file_path()?;
+    let save_interval = Duration::from_secs(config.history_save_interval_secs);
+    let mut last_history_flush = Instant::now();
+    let mut last_now_playing = Instant::now();
+    let mpv_socket = mpv_ipc::socket_path();
+    // The IPC reads block (up to the socket timeout), so they run off the UI task
+    let mut now_playing_task: Option<tokio::task::JoinHandle<Option<mpv_ipc::PlaybackStatus>>> =
+        None;
+    // Polling too often would keep a core busy while idle
+    let poll_interval = Duration::from_millis(config.poll_interval_ms.max(10));
 
     loop {
-        terminal.draw(|f| {
-            let chunks = ratatui::layout::Layout::default()
-                .direction(ratatui::layout::Direction::Vertical)
-                .constraints([
-                    ratatui::layout::Constraint::Length(3), // Search bar
-                    ratatui::layout::Constraint::Length(6), // Filters
-                    ratatui::layout::Constraint::Length(3), // Tabs
-                    ratatui::layout::Constraint::Min(0),    // Video list
-                    ratatui::layout::Constraint::Length(1), // Status bar
-                ])
-                .split(f.area());
-            list_area = chunks[3]; // Store list area for mouse click detection (updated index)
-            tabs_area = chunks[2]; // Store tabs area for mouse click detection
-            render_ui(f, app);
-        })?;
+        // Only redraw when something changed (input, status or finished tasks)
+        if app.dirty {
+            app.dirty = false;
+            terminal.draw(|f| {
+                let chunks = main_layout(f.area(), app.chrome_collapsed());
+                list_area = chunks[3]; // Store list area for mouse click detection (updated index)
+                tabs_area = chunks[2]; // Store tabs area for mouse click detection
+                item_rows = render_ui(f, app);
+            })?;
+            app.item_rows = std::mem::take(&mut item_rows);
+        }
 
         // Check for completed search task after rendering (non-blocking)
         if let Some(task) = &mut app.search_task
             && task.is_finished()
             && let Some(handle) = app.search_task.take()
         {
+            let loading_more = std::mem::take(&mut app.search_loading_more);
+            app.dirty = true;
             match handle.await {
-                Ok(Ok(videos)) => {
-                    app.set_search_results(videos);
-                    app.set_status(format!("Found {} videos", app.search_results.len()));
+                // The query was edited meanwhile; live search runs the new one
+                Ok(_) if !loading_more && app.search_results_stale() => {}
+                Ok(Ok((videos, next_page))) => {
+                    app.search_next_page = next_page;
+                    if loading_more {
+                        let added = app.append_search_results(videos);
+                        app.set_status(format!(
+                            "Loaded {} more videos ({} total)",
+                            added,
+                            app.search_results.len()
+                        ));
+                    } else {
+                        app.finish_search(videos);
+                        if app.search_next_page.is_some() && !app.search_results.is_empty() {
+                            app.set_status(format!(
+                                "Found {} videos (Ctrl+L loads more)",
+                                app.search_results.len()
+                            ));
+                        }
+                    }
                 }
                 Ok(Err(e)) => {
                     app.set_status(format!("Search failed: {}", e));
@@ -202,8 +510,58 @@ async fn run_app(
             }
         }
 
+        // Check for a completed CurrentView refresh
+        if let Some(task) = &mut app.refresh_task
+            && task.is_finished()
+            && let Some(handle) = app.refresh_task.take()
+        {
+            app.dirty = true;
+            match handle.await {
+                Ok(Ok(videos)) => {
+                    let added = app.merge_videos(videos);
+                    app.set_status(format!("Refreshed recommendations ({} new)", added));
+                }
+                Ok(Err(e)) => {
+                    app.set_status(format!("Refresh failed: {:#}", e));
+                }
+                Err(e) => {
+                    app.set_status(format!("Refresh task error: {}", e));
+                }
+            }
+        }
+
+        // Live search: start once typing a YouTube search has paused
+        if app.live_search_due(Instant::now()) {
+            start_search(app, &youtube_client);
+        }
+
+        // Check for completed transcript task (non-blocking)
+        if let Some(task) = &mut app.transcript_task
+            && task.is_finished()
+            && let Some(handle) = app.transcript_task.take()
+        {
+            app.dirty = true;
+            match handle.await {
+                Ok(Ok(text)) => match clipboard::copy_to_clipboard(&text) {
+                    Ok(()) => app.set_status(format!(
+                        "Copied transcript ({} lines) to clipboard",
+                        text.lines().count()
+                    )),
+                    Err(e) => app.set_status(format!("Failed to copy transcript: {}", e)),
+                },
+                Ok(Err(e)) => {
+                    app.set_status(format!("Transcript unavailable: {}", e));
+                }
+                Err(e) => {
+                    app.set_status(format!("Transcript task error: {}", e));
+                }
+            }
+        }
+
         // Use non-blocking event polling with timeout to keep UI responsive
-        if event::poll(Duration::from_millis(100))? {
+        if event::poll(poll_interval)? {
+            // Any input (including resizes) may change what is shown
+            app.dirty = true;
             match event::read()? {
                 Event::Key(key) => {
                     if key.kind != KeyEventKind::Press {
@@ -211,112 +569,432 @@ async fn run_app(
                     }
 
                     match app.mode {
+                        UiMode::List if app.mpv_args_input.is_some() => match key.code {
+                            KeyCode::Enter => {
+                                app.commit_mpv_args_input();
+                            }
+                            KeyCode::Esc => {
+                                app.mpv_args_input = None;
+                            }
+                            KeyCode::Backspace => {
+                                if let Some(input) = app.mpv_args_input.as_mut() {
+                                    input.pop();
+                                }
+                            }
+                            KeyCode::Char(c) => {
+                                if let Some(input) = app.mpv_args_input.as_mut() {
+                                    input.push(c);
+                                }
+                            }
+                            _ => {}
+                        },
                         UiMode::List => {
-                            // Handle Search tab input when on Search tab
+                            // Search tab keys; the query itself is typed after `/`, so
+                            // letters reach the list bindings below like on other tabs
                             if app.active_tab() == crate::app::Tab::Search {
                                 match key.code {
                                     KeyCode::Enter => {
                                         // Alt+Enter: Play selected video
                                         // Note: Ctrl+Enter is not reliably detected by most terminals
                                         if key.modifiers.contains(event::KeyModifiers::ALT) {
-                                            if let Some(video) = app.selected_video_from_tab() {
-                                                let video_url = video.url.clone();
-                                                let video_title = video.title.clone();
-                                                match open_in_mpv(&video_url) {
-                                                    Ok(()) => {
-                                                        app.mark_selected_watched();
-                                                        let history_path =
-                                                            config.history_file_path()?;
-                                                        if let Err(e) =
-                                                            app.history.save(&history_path)
-                                                        {
-                                                            app.set_status(format!(
-                                                                "Failed to save history: {}",
-                                                                e
-                                                            ));
-                                                        } else {
-                                                            app.set_status(format!(
-                                                                "Opened: {}",
-                                                                video_title
-                                                            ));
-                                                        }
-                                                    }
-                                                    Err(e) => {
-                                                        app.set_status(format!(
-                                                            "Failed to open video: {}",
-                                                            e
-                                                        ));
-                                                    }
-                                                }
-                                            }
+                                            request_play(app, &history_path);
                                             // Skip the rest of the event processing
                                             continue;
                                         }
 
-                                        // Regular Enter (without Alt): Perform search
+                                        // Regular Enter (without Alt): Perform search,
+                                        // or run the selected recent search
+                                        if let Some(query) = app.selected_recent_search() {
+                                            app.search_query_global = query.clone();
+                                        }
                                         if !app.search_query_global.is_empty()
                                             && app.search_task.is_none()
                                         {
-                                            app.set_status("Searching YouTube...".to_string());
-                                            let query = app.search_query_global.clone();
-                                            let client = youtube_client.clone();
-                                            app.search_task = Some(tokio::spawn(async move {
-                                                client.search_videos(&query, 50).await
-                                            }));
+                                            start_search(app, &youtube_client);
                                         }
                                         // Skip the rest of the event processing for regular Enter too
                                         continue;
                                     }
-                                    KeyCode::Backspace => {
-                                        app.search_query_global.pop();
+                                    // Ctrl+R: Retry an empty search without operators
+                                    KeyCode::Char('r')
+                                        if key.modifiers.contains(event::KeyModifiers::CONTROL) =>
+                                    {
+                                        if app.search_task.is_none()
+                                            && let Some(query) = app.search_retry_query.take()
+                                        {
+                                            app.search_query_global = query;
+                                            start_search(app, &youtube_client);
+                                        }
+                                        continue;
                                     }
-                                    KeyCode::Char(c) => {
-                                        app.search_query_global.push(c);
+                                    // Ctrl+L: Load the next page of results
+                                    KeyCode::Char('l')
+                                        if key.modifiers.contains(event::KeyModifiers::CONTROL) =>
+                                    {
+                                        if app.search_task.is_none() {
+                                            load_more_search_results(app, &youtube_client);
+                                        }
+                                        continue;
                                     }
                                     _ => {}
                                 }
                             }
+                            // Any key but `g` cancels a pending `gg`
+                            if key.code != KeyCode::Char('g') {
+                                app.cancel_pending_key();
+                            }
                             // Handle normal list navigation
                             match key.code {
                                 KeyCode::Char('q') => break,
                                 KeyCode::Char('Q') => break,
+                                KeyCode::Esc if app.preview_index.is_some() => {
+                                    app.preview_index = None;
+                                }
                                 KeyCode::Esc => break,
-                                KeyCode::Up | KeyCode::Char('k') => app.move_up(),
-                                KeyCode::Down | KeyCode::Char('j') => app.move_down(),
-                                KeyCode::Enter => {
-                                    // Only handle Enter if not on Search tab (Search tab handles it above)
-                                    if app.active_tab() != crate::app::Tab::Search
-                                        && let Some(video) = app.selected_video_from_tab()
-                                    {
-                                        let video_url = video.url.clone();
-                                        let video_title = video.title.clone();
-                                        match open_in_mpv(&video_url) {
-                                            Ok(()) => {
-                                                app.mark_selected_watched();
-                                                let history_path = config.history_file_path()?;
-                                                if let Err(e) = app.history.save(&history_path) {
-                                                    app.set_status(format!(
-                                                        "Failed to save history: {}",
-                                                        e
-                                                    ));
-                                                } else {
-                                                    app.set_status(format!(
-                                                        "Opened: {}",
-                                                        video_title
-                                                    ));
-                                                }
-                                            }
+                                KeyCode::Up | KeyCode::Char('k') => {
+                                    app.move_by_key(false, Instant::now())
+                                }
+                                KeyCode::Down | KeyCode::Char('j') => {
+                                    app.move_by_key(true, Instant::now())
+                                }
+                                // Only handle Enter if not on Search tab (Search tab handles it above)
+                                KeyCode::Enter if app.active_tab() != crate::app::Tab::Search => {
+                                    request_play(app, &history_path);
+                                }
+                                KeyCode::Char('/') => {
+                                    app.start_search_input();
+                                }
+                                KeyCode::Char('r')
+                                    if app.active_tab() == crate::app::Tab::CurrentView =>
+                                {
+                                    start_refresh(app, &youtube_client);
+                                }
+                                KeyCode::PageDown => app.move_by_page(
+                                    true,
+                                    ui::list::page_size(
+                                        list_area.height.saturating_sub(ui::preview::covered_rows(
+                                            app,
+                                            list_area.height,
+                                        )),
+                                        &ui::list::list_heights(
+                                            app,
+                                            list_area.width.saturating_sub(2) as usize,
+                                        ),
+                                        app.selected_index,
+                                    ),
+                                ),
+                                KeyCode::PageUp => app.move_by_page(
+                                    false,
+                                    ui::list::page_size(
+                                        list_area.height.saturating_sub(ui::preview::covered_rows(
+                                            app,
+                                            list_area.height,
+                                        )),
+                                        &ui::list::list_heights(
+                                            app,
+                                            list_area.width.saturating_sub(2) as usize,
+                                        ),
+                                        app.selected_index,
+                                    ),
+                                ),
+                                KeyCode::Home => app.select_first(),
+                                KeyCode::End | KeyCode::Char('G') => app.select_last(),
+                                KeyCode::Char(':') => {
+                                    app.start_goto();
+                                }
+                                KeyCode::Char('g') => {
+                                    app.press_g(Instant::now());
+                                }
+                                KeyCode::Char('L') => {
+                                    app.mode = UiMode::Log;
+                                }
+                                KeyCode::F(11) | KeyCode::Char('F') => {
+                                    app.focus_mode = !app.focus_mode;
+                                }
+                                KeyCode::Char('I') => {
+                                    app.show_ids = !app.show_ids;
+                                }
+                                KeyCode::Char('n') => app.select_next_channel(),
+                                KeyCode::Char(']') => app.preview_next(),
+                                KeyCode::Char('[') => app.preview_prev(),
+                                KeyCode::Char('c')
+                                    if !key.modifiers.contains(event::KeyModifiers::CONTROL) =>
+                                {
+                                    app.commit_preview()
+                                }
+                                KeyCode::Char('z') => {
+                                    if let Some(title) = app.snooze_selected() {
+                                        if let Err(e) = app.history.save(&history_path) {
+                                            app.set_status(format!(
+                                                "Failed to save history: {}",
+                                                e
+                                            ));
+                                        } else {
+                                            app.set_status(format!("Snoozed for 24h: {}", title));
+                                        }
+                                    }
+                                }
+                                KeyCode::Char('v') => {
+                                    app.compact_list = !app.compact_list;
+                                    app.set_status(if app.compact_list {
+                                        "Compact list: one line per video".to_string()
+                                    } else {
+                                        "Full list".to_string()
+                                    });
+                                }
+                                KeyCode::Char('w') => {
+                                    app.title_wrap = !app.title_wrap;
+                                    app.set_status(if app.title_wrap {
+                                        "Wrapping long titles".to_string()
+                                    } else {
+                                        "Truncating long titles".to_string()
+                                    });
+                                }
+                                KeyCode::Char('S') => {
+                                    app.mode = UiMode::Stats;
+                                }
+                                KeyCode::Char('A') => {
+                                    app.start_mpv_args_input();
+                                }
+                                KeyCode::Char('+') | KeyCode::Char('=') | KeyCode::Char('-') => {
+                                    let delta = if key.code == KeyCode::Char('-') {
+                                        -1
+                                    } else {
+                                        1
+                                    };
+                                    if app.adjust_detail_level(delta) {
+                                        app.set_status(format!(
+                                            "Detail level {}/{}",
+                                            app.detail_level,
+                                            ui::list::MAX_DETAIL_LEVEL
+                                        ));
+                                    }
+                                }
+                                KeyCode::Char('e')
+                                    if app.active_tab() == crate::app::Tab::History =>
+                                {
+                                    let export_path =
+                                        history_path.with_file_name("history_export.csv");
+                                    match app.history.export_csv(&export_path) {
+                                        Ok(()) => app.set_status(format!(
+                                            "Exported watch history to {}",
+                                            export_path.display()
+                                        )),
+                                        Err(e) => app.set_status(format!("Export failed: {:#}", e)),
+                                    }
+                                }
+                                KeyCode::Char('x') | KeyCode::Delete
+                                    if app.active_tab() == crate::app::Tab::History =>
+                                {
+                                    if let Some(title) = app.remove_selected_from_history() {
+                                        match app.history.save(&history_path) {
+                                            Ok(()) => app.set_status(format!(
+                                                "Removed from history: {}",
+                                                title
+                                            )),
+                                            Err(e) => app.set_status(format!(
+                                                "Failed to save history: {}",
+                                                e
+                                            )),
+                                        }
+                                    }
+                                }
+                                KeyCode::Char('X')
+                                    if app.active_tab() == crate::app::Tab::History =>
+                                {
+                                    // The first press only asks for a second one
+                                    let cleared = app.request_clear_history(Instant::now());
+                                    if cleared {
+                                        match app.history.save(&history_path) {
+                                            Ok(()) => app.set_status("History cleared".to_string()),
+                                            Err(e) => app.set_status(format!(
+                                                "Failed to save history: {}",
+                                                e
+                                            )),
+                                        }
+                                    }
+                                }
+                                KeyCode::Char('T') => {
+                                    let export_path =
+                                        history_path.with_file_name("current_view.txt");
+                                    match app.export_accessible_text(&export_path) {
+                                        Ok(()) => app.set_status(format!(
+                                            "Wrote list as plain text to {}",
+                                            export_path.display()
+                                        )),
+                                        Err(e) => app.set_status(format!("Export failed: {:#}", e)),
+                                    }
+                                }
+                                KeyCode::Char('b') => {
+                                    app.audio_only = !app.audio_only;
+                                    app.set_status(format!(
+                                        "Audio only: {}",
+                                        if app.audio_only { "on" } else { "off" }
+                                    ));
+                                }
+                                KeyCode::Char('d')
+                                    if key.modifiers.contains(event::KeyModifiers::CONTROL) =>
+                                {
+                                    if app.debug {
+                                        app.mode = UiMode::Debug;
+                                    } else {
+                                        app.set_status(
+                                            "Start yt-tui with --debug to inspect raw API values"
+                                                .to_string(),
+                                        );
+                                    }
+                                }
+                                KeyCode::Char('d') if app.selected_video_from_tab().is_some() => {
+                                    app.detail_scroll = 0;
+                                    app.mode = UiMode::Detail;
+                                }
+                                KeyCode::Char('C') => match app.toggle_channel_filter() {
+                                    Some(true) => {
+                                        let channel =
+                                            app.filters.channel.clone().unwrap_or_default();
+                                        app.set_status(format!(
+                                            "Showing only videos by {} (C to clear)",
+                                            channel
+                                        ));
+                                    }
+                                    Some(false) => {
+                                        app.set_status("Channel filter cleared".to_string())
+                                    }
+                                    None => {}
+                                },
+                                KeyCode::Char('B') => match app.toggle_saved_selected() {
+                                    Some(true) => app.set_status(format!(
+                                        "Saved for later ({} saved)",
+                                        app.saved.ids().len()
+                                    )),
+                                    Some(false) => {
+                                        app.set_status("Removed from saved videos".to_string())
+                                    }
+                                    None => {}
+                                },
+                                KeyCode::Char('y') => {
+                                    if let Some(url) = app.selected_url() {
+                                        match clipboard::copy_to_clipboard(&url) {
+                                            Ok(()) => app
+                                                .set_status("Copied URL to clipboard".to_string()),
                                             Err(e) => {
+                                                app.set_status(format!("Failed to copy URL: {}", e))
+                                            }
+                                        }
+                                    }
+                                }
+                                KeyCode::Char('a') => match app.enqueue_selected() {
+                                    Some(title) => app.set_status(format!(
+                                        "Queued ({}): {}",
+                                        app.queue.len(),
+                                        title
+                                    )),
+                                    None if app.selected_video_from_tab().is_some() => {
+                                        app.set_status("Already in the queue".to_string());
+                                    }
+                                    None => {}
+                                },
+                                KeyCode::Char('p') => {
+                                    let urls: Vec<String> =
+                                        app.queue.iter().map(|video| video.url.clone()).collect();
+                                    if urls.is_empty() {
+                                        app.set_status(
+                                            "The queue is empty (press 'a' to add videos)"
+                                                .to_string(),
+                                        );
+                                    } else {
+                                        match play_queue(&urls, app.play_options()) {
+                                            Ok(()) => {
+                                                let played = app.finish_queue();
                                                 app.set_status(format!(
-                                                    "Failed to open video: {}",
-                                                    e
+                                                    "Playing {} queued videos",
+                                                    played.len()
                                                 ));
                                             }
+                                            Err(e) => app
+                                                .set_status(format!("Failed to play queue: {}", e)),
                                         }
                                     }
                                 }
-                                KeyCode::Char('/') => {
-                                    app.mode = UiMode::Search;
+                                KeyCode::Char(' ') => match app.toggle_mark_selected() {
+                                    Some(true) => app
+                                        .set_status(format!("Marked {} videos", app.marked.len())),
+                                    Some(false) => app.set_status(format!(
+                                        "Unmarked ({} marked)",
+                                        app.marked.len()
+                                    )),
+                                    None => {}
+                                },
+                                KeyCode::Char('o') => {
+                                    // Opening the page doesn't count as watching
+                                    if let Some(url) = app.selected_url() {
+                                        match open_in_browser(&url) {
+                                            Ok(()) => {
+                                                app.set_status("Opened in browser".to_string())
+                                            }
+                                            Err(e) => app.set_status(format!(
+                                                "Failed to open browser: {}",
+                                                e
+                                            )),
+                                        }
+                                    }
+                                }
+                                KeyCode::Char('O') => {
+                                    if app.marked.is_empty() {
+                                        app.set_status(
+                                            "No videos marked (press Space to mark)".to_string(),
+                                        );
+                                    } else if app.needs_open_confirmation() {
+                                        app.mode = UiMode::ConfirmOpenMarked;
+                                    } else {
+                                        open_marked(app);
+                                    }
+                                }
+                                KeyCode::Char('P') => {
+                                    match mpv_ipc::get_chapters(&mpv_ipc::socket_path()) {
+                                        Ok(chapters) if chapters.is_empty() => {
+                                            app.set_status(
+                                                "The playing video has no chapters".to_string(),
+                                            );
+                                        }
+                                        Ok(chapters) => {
+                                            app.chapters = chapters;
+                                            app.chapter_index = 0;
+                                            app.mode = UiMode::Chapters;
+                                        }
+                                        Err(e) => app.set_status(format!("{:#}", e)),
+                                    }
+                                }
+                                KeyCode::Char('m') => {
+                                    if let Some(channel) = app.block_selected_channel() {
+                                        app.set_status(format!("Blocked channel: {}", channel));
+                                    }
+                                }
+                                KeyCode::Char('M') => {
+                                    let playlist_path = history_path.with_file_name("playlist.m3u");
+                                    match app.export_m3u(&playlist_path) {
+                                        Ok(exported) => app.set_status(format!(
+                                            "Exported {} videos to {}",
+                                            exported,
+                                            playlist_path.display()
+                                        )),
+                                        Err(e) => app.set_status(format!(
+                                            "Failed to export playlist: {}",
+                                            e
+                                        )),
+                                    }
+                                }
+                                KeyCode::Char('t') => {
+                                    if app.transcript_task.is_none()
+                                        && let Some(video) = app.selected_video_from_tab()
+                                    {
+                                        let video_id = video.id.clone();
+                                        app.set_status("Fetching transcript...".to_string());
+                                        app.transcript_task =
+                                            Some(tokio::task::spawn_blocking(move || {
+                                                transcript::fetch_transcript(&video_id)
+                                            }));
+                                    }
                                 }
                                 KeyCode::Char('f')
                                     if key.modifiers.contains(event::KeyModifiers::CONTROL) =>
@@ -326,6 +1004,9 @@ async fn run_app(
                                 KeyCode::Char('h') => {
                                     app.toggle_hide_watched();
                                 }
+                                KeyCode::Char('H') => {
+                                    app.toggle_hide_shorts();
+                                }
                                 KeyCode::Char('s') => {
                                     app.cycle_sort_mode();
                                     app.set_status(format!("Sort: {}", app.sort_mode_name()));
@@ -334,7 +1015,6 @@ async fn run_app(
                                     handle_tab_switch(
                                         app,
                                         &youtube_client,
-                                        config,
                                         crate::app::Tab::CurrentView,
                                     )
                                     .await?;
@@ -343,7 +1023,6 @@ async fn run_app(
                                     handle_tab_switch(
                                         app,
                                         &youtube_client,
-                                        config,
                                         crate::app::Tab::Search,
                                     )
                                     .await?;
@@ -352,30 +1031,33 @@ async fn run_app(
                                     handle_tab_switch(
                                         app,
                                         &youtube_client,
-                                        config,
                                         crate::app::Tab::History,
                                     )
                                     .await?;
                                 }
+                                KeyCode::Char('4') => {
+                                    handle_tab_switch(app, &youtube_client, crate::app::Tab::Saved)
+                                        .await?;
+                                }
                                 KeyCode::Tab => {
                                     // Cycle to next tab (forward)
                                     let next_tab = match app.active_tab() {
                                         crate::app::Tab::CurrentView => crate::app::Tab::Search,
                                         crate::app::Tab::Search => crate::app::Tab::History,
-                                        crate::app::Tab::History => crate::app::Tab::CurrentView,
+                                        crate::app::Tab::History => crate::app::Tab::Saved,
+                                        crate::app::Tab::Saved => crate::app::Tab::CurrentView,
                                     };
-                                    handle_tab_switch(app, &youtube_client, config, next_tab)
-                                        .await?;
+                                    handle_tab_switch(app, &youtube_client, next_tab).await?;
                                 }
                                 KeyCode::BackTab => {
                                     // Cycle to previous tab (backward, Shift+Tab)
                                     let prev_tab = match app.active_tab() {
-                                        crate::app::Tab::CurrentView => crate::app::Tab::History,
+                                        crate::app::Tab::CurrentView => crate::app::Tab::Saved,
                                         crate::app::Tab::Search => crate::app::Tab::CurrentView,
                                         crate::app::Tab::History => crate::app::Tab::Search,
+                                        crate::app::Tab::Saved => crate::app::Tab::History,
                                     };
-                                    handle_tab_switch(app, &youtube_client, config, prev_tab)
-                                        .await?;
+                                    handle_tab_switch(app, &youtube_client, prev_tab).await?;
                                 }
                                 KeyCode::Char('c')
                                     if key.modifiers.contains(event::KeyModifiers::CONTROL) =>
@@ -386,6 +1068,21 @@ async fn run_app(
                             }
                         }
                         UiMode::Search => match key.code {
+                            KeyCode::Tab => {
+                                app.toggle_search_target();
+                            }
+                            KeyCode::Enter
+                                if app.search_target == crate::app::SearchTarget::YouTube =>
+                            {
+                                app.mode = UiMode::List;
+                                if app.active_tab() != crate::app::Tab::Search {
+                                    app.switch_tab(crate::app::Tab::Search);
+                                }
+                                if !app.search_query_global.is_empty() && app.search_task.is_none()
+                                {
+                                    start_search(app, &youtube_client);
+                                }
+                            }
                             KeyCode::Enter | KeyCode::Esc => {
                                 app.mode = UiMode::List;
                             }
@@ -397,41 +1094,425 @@ async fn run_app(
                             }
                             _ => {}
                         },
+                        UiMode::Filters if app.filter_input.is_some() => match key.code {
+                            KeyCode::Enter => {
+                                app.commit_filter_input();
+                            }
+                            KeyCode::Esc => {
+                                app.filter_input = None;
+                            }
+                            KeyCode::Backspace => {
+                                if let Some(input) = app.filter_input.as_mut() {
+                                    input.buffer.pop();
+                                }
+                            }
+                            KeyCode::Char(c) => {
+                                if let Some(input) = app.filter_input.as_mut() {
+                                    input.buffer.push(c);
+                                }
+                            }
+                            _ => {}
+                        },
                         UiMode::Filters => match key.code {
+                            KeyCode::Down | KeyCode::Char('j') => app.move_filter_selection(true),
+                            KeyCode::Up | KeyCode::Char('k') => app.move_filter_selection(false),
+                            KeyCode::Enter => {
+                                app.start_filter_input(app.selected_filter_field());
+                            }
+                            KeyCode::Char('n') => {
+                                app.start_filter_input(crate::app::FilterField::MinDuration);
+                            }
+                            KeyCode::Char('x') => {
+                                app.start_filter_input(crate::app::FilterField::MaxDuration);
+                            }
                             KeyCode::Esc | KeyCode::Char('f') => {
                                 app.mode = UiMode::List;
                             }
                             KeyCode::Char('h') => {
                                 app.toggle_hide_watched();
                             }
+                            KeyCode::Char('H') => {
+                                app.toggle_hide_shorts();
+                            }
                             KeyCode::Char('s') => {
                                 app.cycle_sort_mode();
                                 app.set_status(format!("Sort: {}", app.sort_mode_name()));
                             }
-                            // Filter editing would go here in a more complete implementation
+                            _ => {}
+                        },
+                        UiMode::Stats => match key.code {
+                            KeyCode::Esc | KeyCode::Char('S') | KeyCode::Char('q') => {
+                                app.mode = UiMode::List;
+                            }
+                            _ => {}
+                        },
+                        UiMode::ConfirmOpenMarked => match key.code {
+                            KeyCode::Char('y') | KeyCode::Char('Y') => {
+                                app.mode = UiMode::List;
+                                open_marked(app);
+                            }
+                            KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Esc => {
+                                app.mode = UiMode::List;
+                                app.set_status("Opening cancelled".to_string());
+                            }
+                            _ => {}
+                        },
+                        UiMode::Goto => match key.code {
+                            KeyCode::Enter => {
+                                app.commit_goto();
+                            }
+                            KeyCode::Esc => app.cancel_goto(),
+                            KeyCode::Backspace => {
+                                app.goto_input.pop();
+                            }
+                            KeyCode::Char(c) => app.push_goto_char(c),
+                            _ => {}
+                        },
+                        UiMode::ConfirmPlay => match key.code {
+                            KeyCode::Char('y') | KeyCode::Char('Y') => {
+                                app.mode = UiMode::List;
+                                play_selected(app, &history_path);
+                            }
+                            KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Esc => {
+                                app.mode = UiMode::List;
+                                app.set_status("Playback cancelled".to_string());
+                            }
+                            _ => {}
+                        },
+                        UiMode::Debug => match key.code {
+                            KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('d') => {
+                                app.mode = UiMode::List;
+                            }
+                            _ => {}
+                        },
+                        UiMode::Detail => match key.code {
+                            KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('d') => {
+                                app.mode = UiMode::List;
+                            }
+                            KeyCode::Down | KeyCode::Char('j') => scroll_detail(app, list_area, 1),
+                            KeyCode::Up | KeyCode::Char('k') => scroll_detail(app, list_area, -1),
+                            KeyCode::PageDown => {
+                                let page = list_area.height.saturating_sub(8).max(1) as i32;
+                                scroll_detail(app, list_area, page);
+                            }
+                            KeyCode::PageUp => {
+                                let page = list_area.height.saturating_sub(8).max(1) as i32;
+                                scroll_detail(app, list_area, -page);
+                            }
+                            _ => {}
+                        },
+                        UiMode::Log => match key.code {
+                            KeyCode::Esc | KeyCode::Char('L') | KeyCode::Char('q') => {
+                                app.mode = UiMode::List;
+                            }
+                            _ => {}
+                        },
+                        UiMode::Chapters => match key.code {
+                            KeyCode::Esc | KeyCode::Char('P') | KeyCode::Char('q') => {
+                                app.mode = UiMode::List;
+                            }
+                            KeyCode::Down | KeyCode::Char('j')
+                                if app.chapter_index + 1 < app.chapters.len() =>
+                            {
+                                app.chapter_index += 1;
+                            }
+                            KeyCode::Up | KeyCode::Char('k') => {
+                                app.chapter_index = app.chapter_index.saturating_sub(1);
+                            }
+                            KeyCode::Enter => {
+                                if let Some(chapter) = app.chapters.get(app.chapter_index) {
+                                    let title = chapter.title.clone();
+                                    match mpv_ipc::set_chapter(
+                                        &mpv_ipc::socket_path(),
+                                        app.chapter_index,
+                                    ) {
+                                        Ok(()) => {
+                                            app.set_status(format!("Jumped to chapter: {}", title))
+                                        }
+                                        Err(e) => app.set_status(format!(
+                                            "Failed to jump to chapter: {:#}",
+                                            e
+                                        )),
+                                    }
+                                }
+                                app.mode = UiMode::List;
+                            }
                             _ => {}
                         },
                     }
                 }
                 Event::Mouse(mouse) => {
-                    handle_mouse_event(mouse, app, config, list_area, tabs_area, &youtube_client)
-                        .await?;
+                    handle_mouse_event(
+                        mouse,
+                        app,
+                        &history_path,
+                        list_area,
+                        tabs_area,
+                        &youtube_client,
+                    )
+                    .await?;
                 }
                 _ => {}
             }
+        } else {
+            // No input within the poll interval: animate running tasks
+            app.tick_spinner();
+        }
+
+        // Problems that didn't fail a request (e.g. a failed cache write)
+        for warning in youtube_client.take_warnings() {
+            app.set_status(warning);
+        }
+
+        if let Some(task) = &now_playing_task
+            && task.is_finished()
+            && let Some(handle) = now_playing_task.take()
+            && let Ok(status) = handle.await
+        {
+            app.set_now_playing(status);
+        }
+        if now_playing_task.is_none() && last_now_playing.elapsed() >= NOW_PLAYING_INTERVAL {
+            last_now_playing = Instant::now();
+            let socket = mpv_socket.clone();
+            now_playing_task = Some(tokio::task::spawn_blocking(move || {
+                mpv_ipc::query_playback(&socket)
+            }));
+        }
+
+        if last_history_flush.elapsed() >= save_interval {
+            last_history_flush = Instant::now();
+            if let Err(e) = app.history.flush(&history_path) {
+                app.set_status(format!("Failed to save history: {}", e));
+            }
+            if let Err(e) = app.saved.flush(&saved_path) {
+                app.set_status(format!("Failed to save saved list: {}", e));
+            }
         }
-        // If no event, continue loop to redraw UI (keeps it responsive)
     }
 
+    app.persist_state();
+    app.history.flush(&history_path)?;
+    app.saved.flush(&saved_path)?;
     Ok(())
 }
 
+/// Start a platform search for the Search tab query in the background.
+///
+/// # Arguments
+/// * `app` - Application state
+/// * `youtube_client` - YouTube API client
+///
+/// # Details
+/// Records the query in `last_search_query` so the completion handler can
+/// report on it and adds it to the recent searches, then spawns the search task.
+/// Asks for fewer results as the quota budget runs low (see
+/// `search_page_size`) and skips the search (and says so) once it is nearly
+/// used up.
+fn start_search(app: &mut App, youtube_client: &YouTubeClient) {
+    if !youtube_client.can_search() {
+        app.set_status(quota_low_message(youtube_client));
+        return;
+    }
+    let max_results = search_page_size(app, youtube_client);
+    if max_results == youtube_client.default_results() {
+        app.set_status("Searching YouTube...".to_string());
+    }
+    let query = app.search_query_global.clone();
+    app.last_search_query = query.clone();
+    app.history.add_recent_search(&query);
+    app.search_next_page = None;
+    app.search_loading_more = false;
+    let client = youtube_client.clone();
+    app.search_task = Some(tokio::spawn(async move {
+        client.search_videos_page(&query, max_results, None).await
+    }));
+}
+
+/// Get the number of results to request for a search page.
+///
+/// # Arguments
+/// * `app` - Application state (for the status message)
+/// * `youtube_client` - YouTube API client
+///
+/// # Returns
+/// * `u32` - `default_results`, lowered as the quota budget runs out (see
+///   `YouTubeClient::effective_max_results`)
+///
+/// # Details
+/// Warns in the status bar when fewer results than configured are requested.
+fn search_page_size(app: &mut App, youtube_client: &YouTubeClient) -> u32 {
+    let requested = youtube_client.default_results();
+    let max_results = youtube_client.effective_max_results(requested);
+    if max_results < requested {
+        app.set_status(format!(
+            "Searching YouTube (quota low: ~{} of {} units used, limited to {} results)...",
+            youtube_client.quota_used(),
+            youtube_client.quota_budget(),
+            max_results
+        ));
+    }
+    max_results
+}
+
+/// Build the status message for a search skipped to save quota.
+///
+/// # Arguments
+/// * `youtube_client` - YouTube API client
+///
+/// # Returns
+/// * `String` - Message with the estimated usage and the budget
+fn quota_low_message(youtube_client: &YouTubeClient) -> String {
+    format!(
+        "Search skipped: quota low (~{} of {} units used today; see daily_quota_budget)",
+        youtube_client.quota_used(),
+        youtube_client.quota_budget()
+    )
+}
+
+/// Re-fetch the CurrentView recommendations in the background.
+///
+/// # Arguments
+/// * `app` - Application state
+/// * `youtube_client` - YouTube API client
+///
+/// # Details
+/// Fetches `default_results` videos; when the task finishes they are merged
+/// into the list (see `App::merge_videos`), so nothing already listed
+/// disappears and the selection stays on the same video. Does nothing while
+/// a refresh is already running.
+fn start_refresh(app: &mut App, youtube_client: &YouTubeClient) {
+    if app.refresh_task.is_some() {
+        return;
+    }
+    app.set_status("Refreshing recommendations...".to_string());
+    let max_results = youtube_client.default_results();
+    let client = youtube_client.clone();
+    app.refresh_task = Some(tokio::spawn(async move {
+        client.fetch_recommended_videos(max_results).await
+    }));
+}
+
+/// Fetch the next page of the last platform search in the background.
+///
+/// # Arguments
+/// * `app` - Application state
+/// * `youtube_client` - YouTube API client
+///
+/// # Details
+/// Uses `search_next_page` from the previous page; the results are appended
+/// to the Search tab when the task finishes. Reports when there are no more,
+/// or when the quota budget runs low.
+fn load_more_search_results(app: &mut App, youtube_client: &YouTubeClient) {
+    let Some(page_token) = app.search_next_page.clone() else {
+        app.set_status("No more search results".to_string());
+        return;
+    };
+    if !youtube_client.can_search() {
+        app.set_status(quota_low_message(youtube_client));
+        return;
+    }
+    let max_results = search_page_size(app, youtube_client);
+    if max_results == youtube_client.default_results() {
+        app.set_status(format!(
+            "Loading more results for '{}'...",
+            app.last_search_query
+        ));
+    }
+    let query = app.last_search_query.clone();
+    app.search_loading_more = true;
+    let client = youtube_client.clone();
+    app.search_task = Some(tokio::spawn(async move {
+        client
+            .search_videos_page(&query, max_results, Some(&page_token))
+            .await
+    }));
+}
+
+/// Play the selected video, or ask for confirmation first.
+///
+/// # Arguments
+/// * `app` - Application state
+/// * `history_path` - History file the watched video is saved to
+///
+/// # Details
+/// With `confirm_play` enabled, switches to `UiMode::ConfirmPlay` and leaves
+/// playback to `y`; otherwise plays right away (see `play_selected`).
+fn request_play(app: &mut App, history_path: &std::path::Path) {
+    if app.selected_video_from_tab().is_none() {
+        return;
+    }
+    if app.confirm_play {
+        app.mode = UiMode::ConfirmPlay;
+        return;
+    }
+    play_selected(app, history_path);
+}
+
+/// Open the selected video in MPV and mark it as watched.
+///
+/// # Arguments
+/// * `app` - Application state
+/// * `history_path` - History file the watched video is saved to
+///
+/// # Details
+/// Saves the history right away and reports the outcome in the status bar.
+fn play_selected(app: &mut App, history_path: &std::path::Path) {
+    let Some(video) = app.selected_video_from_tab() else {
+        return;
+    };
+    let video_url = video.url.clone();
+    let video_title = video.title.clone();
+    match open_in_player(&video_url, app.play_options()) {
+        Ok(()) => {
+            app.mark_selected_watched();
+            if let Err(e) = app.history.save(history_path) {
+                app.set_status(format!("Failed to save history: {}", e));
+            } else {
+                app.set_status(format!("Opened: {}", video_title));
+            }
+        }
+        Err(e) => {
+            app.set_status(format!("Failed to open video: {}", e));
+        }
+    }
+}
+
+/// Scroll the description in the detail popup.
+///
+/// # Arguments
+/// * `app` - Application state
+/// * `area` - Area the popup is drawn in (the list area)
+/// * `delta` - Lines to scroll (negative scrolls up)
+///
+/// # Details
+/// Stops once the end of the description is visible.
+fn scroll_detail(app: &mut App, area: ratatui::layout::Rect, delta: i32) {
+    let max = app
+        .selected_video_from_tab()
+        .map_or(0, |video| ui::detail::max_detail_scroll(video, area));
+    app.detail_scroll = (i32::from(app.detail_scroll) + delta).clamp(0, i32::from(max)) as u16;
+}
+
+/// Open the marked videos and report the outcome.
+///
+/// # Arguments
+/// * `app` - Application state
+///
+/// # Details
+/// Opens them as one playlist or in separate windows depending on the open
+/// mode (see `App::play_selected_set`). The history is saved by the periodic flush.
+fn open_marked(app: &mut App) {
+    match app.play_selected_set(play_queue) {
+        Ok(count) => app.set_status(format!("Opened {} videos", count)),
+        Err(e) => app.set_status(format!("Failed to open videos: {}", e)),
+    }
+}
+
 /// Handle tab switching with data fetching.
 ///
 /// # Arguments
 /// * `app` - Application state
 /// * `youtube_client` - YouTube API client
-/// * `config` - Configuration
 /// * `tab` - Tab to switch to
 ///
 /// # Returns
@@ -442,7 +1523,6 @@ async fn run_app(
 async fn handle_tab_switch(
     app: &mut App,
     youtube_client: &YouTubeClient,
-    _config: &Config,
     tab: crate::app::Tab,
 ) -> anyhow::Result<()> {
     app.switch_tab(tab);
@@ -454,60 +1534,45 @@ async fn handle_tab_switch(
                 && !app.search_query_global.is_empty()
                 && app.search_task.is_none()
             {
-                app.set_status("Searching YouTube...".to_string());
-                let query = app.search_query_global.clone();
-                let client = youtube_client.clone();
-                app.search_task = Some(tokio::spawn(async move {
-                    client.search_videos(&query, 50).await
-                }));
+                start_search(app, youtube_client);
             }
         }
         crate::app::Tab::History => {
-            // Fetch history videos if not already loaded
-            if app.history_videos.is_empty() {
+            // Fetch history videos if not already loaded (or only the offline list is shown)
+            if app.history_videos.is_empty() || app.history_videos.iter().any(|v| v.details_missing)
+            {
                 app.set_status("Loading watch history...".to_string());
                 let watched_videos = app.history.get_watched_videos_sorted();
-                if !watched_videos.is_empty() {
-                    let video_ids: Vec<String> =
-                        watched_videos.iter().map(|(id, _)| id.clone()).collect();
-                    match youtube_client.fetch_history_videos(&video_ids).await {
-                        Ok(mut videos) => {
-                            // Sort by watch timestamp (newest first)
-                            // Create a map for quick lookup
-                            let timestamp_map: std::collections::HashMap<String, String> =
-                                watched_videos.into_iter().collect();
-                            videos.sort_by(|a, b| {
-                                let time_a = timestamp_map
-                                    .get(&a.id)
-                                    .and_then(|t| chrono::DateTime::parse_from_rfc3339(t).ok())
-                                    .unwrap_or_else(|| {
-                                        chrono::DateTime::parse_from_rfc3339("1970-01-01T00:00:00Z")
-                                            .unwrap()
-                                    });
-                                let time_b = timestamp_map
-                                    .get(&b.id)
-                                    .and_then(|t| chrono::DateTime::parse_from_rfc3339(t).ok())
-                                    .unwrap_or_else(|| {
-                                        chrono::DateTime::parse_from_rfc3339("1970-01-01T00:00:00Z")
-                                            .unwrap()
-                                    });
-                                time_b.cmp(&time_a) // Reverse for newest first
-                            });
-                            app.set_history_videos(videos);
-                            app.set_status(format!(
-                                "Loaded {} watched videos",
-                                app.history_videos.len()
-                            ));
-                        }
-                        Err(e) => {
-                            app.set_status(format!("Failed to load history: {}", e));
-                        }
+                let mut video_ids = app.history.continue_watching_ids();
+                for (id, _) in &watched_videos {
+                    if !video_ids.contains(id) {
+                        video_ids.push(id.clone());
                     }
+                }
+                if !video_ids.is_empty() {
+                    let result = youtube_client.fetch_history_videos(&video_ids).await;
+                    app.apply_history_fetch(&video_ids, result);
                 } else {
                     app.set_status("No watch history".to_string());
                 }
             }
         }
+        crate::app::Tab::Saved => {
+            // Fetch details of videos saved in earlier sessions
+            if app.saved_needs_fetch() {
+                app.set_status("Loading saved videos...".to_string());
+                let video_ids = app.saved.ids().to_vec();
+                match youtube_client.fetch_history_videos(&video_ids).await {
+                    Ok(videos) => {
+                        app.set_saved_videos(videos);
+                        app.set_status(format!("Loaded {} saved videos", app.saved_videos.len()));
+                    }
+                    Err(e) => app.set_status(format!("Failed to load saved videos: {}", e)),
+                }
+            } else if app.saved.ids().is_empty() {
+                app.set_status("No saved videos (press B to save one)".to_string());
+            }
+        }
         crate::app::Tab::CurrentView => {
             // No action needed, already using filtered_videos
         }
@@ -521,7 +1586,7 @@ async fn handle_tab_switch(
 /// # Arguments
 /// * `mouse` - Mouse event
 /// * `app` - Application state
-/// * `config` - Configuration
+/// * `history_path` - History file (for playing a clicked video)
 /// * `list_area` - Area of the video list widget
 /// * `tabs_area` - Area of the tabs widget
 /// * `youtube_client` - YouTube API client
@@ -534,21 +1599,28 @@ async fn handle_tab_switch(
 async fn handle_mouse_event(
     mouse: MouseEvent,
     app: &mut App,
-    config: &Config,
+    history_path: &std::path::Path,
     list_area: ratatui::layout::Rect,
     tabs_area: ratatui::layout::Rect,
     youtube_client: &YouTubeClient,
 ) -> anyhow::Result<()> {
+    // Only y/n/Esc answer a confirmation
+    if matches!(app.mode, UiMode::ConfirmPlay | UiMode::ConfirmOpenMarked) {
+        return Ok(());
+    }
+
     match mouse.kind {
-        MouseEventKind::ScrollUp => {
-            if app.mode == UiMode::List {
-                app.move_up();
-            }
+        MouseEventKind::ScrollUp if app.mode == UiMode::List => {
+            app.move_up();
         }
-        MouseEventKind::ScrollDown => {
-            if app.mode == UiMode::List {
-                app.move_down();
-            }
+        MouseEventKind::ScrollDown if app.mode == UiMode::List => {
+            app.move_down();
+        }
+        MouseEventKind::ScrollUp if app.mode == UiMode::Detail => {
+            scroll_detail(app, list_area, -1);
+        }
+        MouseEventKind::ScrollDown if app.mode == UiMode::Detail => {
+            scroll_detail(app, list_area, 1);
         }
         MouseEventKind::Down(MouseButton::Left) => {
             // Check if click is on tabs area
@@ -558,17 +1630,13 @@ async fn handle_mouse_event(
                 && mouse.row < tabs_area.y + tabs_area.height
             {
                 // Calculate which tab was clicked
-                // Tabs are roughly: "Current View" (14 chars) | "Search" (6 chars) | "History" (7 chars)
-                // Approximate positions
-                let tab_width = tabs_area.width / 3;
-                let clicked_tab = if mouse.column < tabs_area.x + tab_width {
-                    crate::app::Tab::CurrentView
-                } else if mouse.column < tabs_area.x + tab_width * 2 {
-                    crate::app::Tab::Search
-                } else {
-                    crate::app::Tab::History
-                };
-                handle_tab_switch(app, youtube_client, config, clicked_tab).await?;
+                // Tabs are roughly: "Current View" | "Search" | "History" | "Saved"
+                // Approximate positions: equal slices of the tab bar
+                let tabs = crate::app::Tab::ALL;
+                let tab_width = (tabs_area.width / tabs.len() as u16).max(1);
+                let slot = ((mouse.column - tabs_area.x) / tab_width) as usize;
+                let clicked_tab = tabs[slot.min(tabs.len() - 1)];
+                handle_tab_switch(app, youtube_client, clicked_tab).await?;
             }
             // Check if click is within the video list area
             // Account for list widget borders (1 line for top border)
@@ -578,37 +1646,13 @@ async fn handle_mouse_event(
                 && mouse.row > list_area.y // Skip top border
                 && mouse.row < list_area.y + list_area.height
             {
-                // Calculate which video was clicked
-                // Each video takes 6 lines (1 for title + 4 for info + 1 separator)
-                // Account for the top border (1 line)
-                let lines_per_video = 6;
-                let click_y = mouse.row - list_area.y - 1; // Subtract border
-                let video_index = (click_y / lines_per_video) as usize;
-
-                let current_list = app.get_current_video_list();
-                if video_index < current_list.len() {
+                // Look up which video was drawn at the clicked row
+                if let Some(video_index) = app.index_at_row(mouse.row) {
                     // Set selection to clicked video
                     app.selected_index = video_index;
 
-                    // Play the video
-                    if let Some(video) = app.selected_video_from_tab() {
-                        let video_url = video.url.clone();
-                        let video_title = video.title.clone();
-                        match open_in_mpv(&video_url) {
-                            Ok(()) => {
-                                app.mark_selected_watched();
-                                let history_path = config.history_file_path()?;
-                                if let Err(e) = app.history.save(&history_path) {
-                                    app.set_status(format!("Failed to save history: {}", e));
-                                } else {
-                                    app.set_status(format!("Opened: {}", video_title));
-                                }
-                            }
-                            Err(e) => {
-                                app.set_status(format!("Failed to open video: {}", e));
-                            }
-                        }
-                    }
+                    // Play the video (or ask first)
+                    request_play(app, history_path);
                 }
             }
         }
@@ -617,3 +1661,63 @@ async fn handle_mouse_event(
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_main_layout_collapsed_vs_expanded() {
+        let area = Rect::new(0, 0, 80, 40);
+
+        let expanded = main_layout(area, false);
+        let heights: Vec<u16> = expanded.iter().map(|r| r.height).collect();
+        assert_eq!(heights, [3, 6, 3, 27, 1]);
+
+        let collapsed = main_layout(area, true);
+        let heights: Vec<u16> = collapsed.iter().map(|r| r.height).collect();
+        assert_eq!(heights, [1, 1, 3, 34, 1]);
+        // The list gains the 7 rows freed by the search and filter panels
+        assert_eq!(collapsed[3].y, 5);
+        assert_eq!(collapsed[3].height - expanded[3].height, 7);
+    }
+
+    #[test]
+    fn test_check_config_arg() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let existing = temp_dir.path().join("config.jsonc");
+        std::fs::write(&existing, "{}").unwrap();
+        let missing = temp_dir.path().join("new.jsonc");
+
+        assert!(check_config_arg(&existing, false).is_ok());
+        assert!(check_config_arg(&missing, false).is_err());
+        assert!(check_config_arg(&missing, true).is_ok());
+        // A directory is never a config file
+        assert!(check_config_arg(temp_dir.path(), true).is_err());
+    }
+
+    #[test]
+    fn test_initial_fetch_size() {
+        // 80x40: 25 list rows fit 4 videos
+        let full = ui::list::LINES_PER_VIDEO;
+        assert_eq!(initial_fetch_size(80, 40, full), 20);
+        // Short terminals still fetch a minimum
+        assert_eq!(initial_fetch_size(80, 10, full), MIN_FETCH_SIZE);
+        // Tall terminals are capped at the API limit
+        assert_eq!(initial_fetch_size(200, 120, full), MAX_FETCH_SIZE);
+        // 25 rows hold 25 compact videos; 5 screens exceed the limit
+        assert_eq!(initial_fetch_size(80, 40, 1), MAX_FETCH_SIZE);
+    }
+
+    #[test]
+    fn test_missing_api_key_exit_code() {
+        let config = Config::default();
+        assert_eq!(error::exit_code(&require_api_key(&config)), 2);
+
+        let config = Config {
+            api_key: "key".to_string(),
+            ..Config::default()
+        };
+        assert_eq!(error::exit_code(&require_api_key(&config)), 0);
+    }
+}
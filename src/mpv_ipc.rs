@@ -0,0 +1,419 @@
+//! MPV JSON IPC integration.
+//!
+//! Talks to the MPV instance started by the player through its IPC socket,
+//! e.g. to read the chapter list of the playing video and jump to a chapter,
+//! or to show what is playing.
+
+use anyhow::{Context, Result};
+use serde_json::{Value, json};
+use std::path::{Path, PathBuf};
+
+/// Request ID of the first command on a connection (later ones count up), so
+/// replies can be told apart from events and from each other.
+const REQUEST_ID: u64 = 1;
+
+/// A chapter of the playing video.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Chapter {
+    /// Chapter title (falls back to "Chapter N" when MPV reports none)
+    pub title: String,
+    /// Start time in seconds
+    pub time: f64,
+}
+
+impl Chapter {
+    /// Format the start time for display.
+    ///
+    /// # Returns
+    /// * `String` - `M:SS`, or `H:MM:SS` for chapters starting after an hour
+    pub fn display_time(&self) -> String {
+        format_time(self.time)
+    }
+}
+
+/// Playback state of the video playing in MPV.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlaybackStatus {
+    /// Media title (the video title for YouTube URLs), or the file name
+    pub title: String,
+    /// Playback position in seconds
+    pub position: f64,
+    /// Duration in seconds (0 while unknown)
+    pub duration: f64,
+    /// Whether playback is paused
+    pub paused: bool,
+    /// YouTube video ID taken from the playing URL, if it is one
+    pub video_id: Option<String>,
+}
+
+impl PlaybackStatus {
+    /// Format the status for display.
+    ///
+    /// # Returns
+    /// * `String` - e.g. "Now playing: Title 1:23", with "(paused)" appended
+    ///   while paused
+    pub fn display(&self) -> String {
+        let text = format!("Now playing: {} {}", self.title, format_time(self.position));
+        if self.paused {
+            format!("{} (paused)", text)
+        } else {
+            text
+        }
+    }
+}
+
+/// Extract the YouTube video ID from a URL MPV plays.
+///
+/// # Arguments
+/// * `url` - URL such as `https://www.youtube.com/watch?v=ID` or `https://youtu.be/ID`
+///
+/// # Returns
+/// * `Option<String>` - Video ID, or None for other URLs and local files
+pub fn video_id_from_url(url: &str) -> Option<String> {
+    let id = if let Some((_, rest)) = url.split_once("youtu.be/") {
+        rest.split(['?', '&', '#', '/']).next()?
+    } else if url.contains("youtube.com/") {
+        let (_, query) = url.split_once('?')?;
+        query
+            .split(['&', '#'])
+            .find_map(|param| param.strip_prefix("v="))?
+    } else {
+        return None;
+    };
+    (!id.is_empty()).then(|| id.to_string())
+}
+
+/// Format a time in seconds.
+///
+/// # Arguments
+/// * `time` - Seconds (negative values count as 0)
+///
+/// # Returns
+/// * `String` - `M:SS`, or `H:MM:SS` from an hour on
+fn format_time(time: f64) -> String {
+    let total = time.max(0.0) as u64;
+    let (hours, minutes, seconds) = (total / 3600, (total % 3600) / 60, total % 60);
+    if hours > 0 {
+        format!("{}:{:02}:{:02}", hours, minutes, seconds)
+    } else {
+        format!("{}:{:02}", minutes, seconds)
+    }
+}
+
+/// Get the path of the IPC socket MPV is started with.
+///
+/// # Returns
+/// * `PathBuf` - Socket path in the temporary directory
+///
+/// # Details
+/// The path includes the process ID, so several running instances of the
+/// TUI each talk to their own MPV.
+pub fn socket_path() -> PathBuf {
+    std::env::temp_dir().join(format!("yt-tui-mpv-{}.sock", std::process::id()))
+}
+
+/// Parse the `chapter-list` property into chapters.
+///
+/// # Arguments
+/// * `data` - The `data` field of the `get_property chapter-list` reply
+///
+/// # Returns
+/// * `Vec<Chapter>` - Chapters in playback order (empty if the video has none)
+///
+/// # Details
+/// Entries without a numeric `time` are skipped; missing or blank titles are
+/// replaced with "Chapter N".
+pub fn parse_chapter_list(data: &Value) -> Vec<Chapter> {
+    let Some(entries) = data.as_array() else {
+        return Vec::new();
+    };
+    entries
+        .iter()
+        .enumerate()
+        .filter_map(|(index, entry)| {
+            let time = entry.get("time")?.as_f64()?;
+            let title = entry
+                .get("title")
+                .and_then(Value::as_str)
+                .map(str::trim)
+                .filter(|t| !t.is_empty())
+                .map(str::to_string)
+                .unwrap_or_else(|| format!("Chapter {}", index + 1));
+            Some(Chapter { title, time })
+        })
+        .collect()
+}
+
+/// Read the chapter list of the video playing in MPV.
+///
+/// # Arguments
+/// * `socket` - IPC socket path
+///
+/// # Returns
+/// * `Result<Vec<Chapter>>` - Chapters, or error if MPV isn't reachable
+pub fn get_chapters(socket: &Path) -> Result<Vec<Chapter>> {
+    let data = send_command(socket, &[json!("get_property"), json!("chapter-list")])?;
+    Ok(parse_chapter_list(&data))
+}
+
+/// Read what MPV is playing.
+///
+/// # Arguments
+/// * `socket` - IPC socket path
+///
+/// # Returns
+/// * `Option<PlaybackStatus>` - Playback state, or None if no MPV is
+///   listening (never started, or exited and removed the socket) or nothing
+///   is loaded
+///
+/// # Details
+/// All properties are read over one connection.
+pub fn query_playback(socket: &Path) -> Option<PlaybackStatus> {
+    if !socket.exists() {
+        return None;
+    }
+    let names = ["media-title", "path", "time-pos", "duration", "pause"];
+    let commands: Vec<Vec<Value>> = names
+        .iter()
+        .map(|name| vec![json!("get_property"), json!(name)])
+        .collect();
+    // Properties that are unavailable (e.g. while loading) come back as errors
+    let [title, path, position, duration, paused]: [Option<Value>; 5] =
+        send_commands(socket, &commands)
+            .ok()?
+            .into_iter()
+            .map(Result::ok)
+            .collect::<Vec<_>>()
+            .try_into()
+            .ok()?;
+    let path = path.and_then(|data| data.as_str().map(str::to_string));
+    let title = title
+        .and_then(|data| data.as_str().map(str::to_string))
+        .filter(|title| !title.is_empty())
+        .or_else(|| path.clone())?;
+    Some(PlaybackStatus {
+        title,
+        position: position.and_then(|data| data.as_f64()).unwrap_or(0.0),
+        duration: duration.and_then(|data| data.as_f64()).unwrap_or(0.0),
+        paused: paused.and_then(|data| data.as_bool()).unwrap_or(false),
+        video_id: path.as_deref().and_then(video_id_from_url),
+    })
+}
+
+/// Jump to a chapter of the video playing in MPV.
+///
+/// # Arguments
+/// * `socket` - IPC socket path
+/// * `index` - Zero-based chapter index
+///
+/// # Returns
+/// * `Result<()>` - Success or error
+pub fn set_chapter(socket: &Path, index: usize) -> Result<()> {
+    send_command(
+        socket,
+        &[json!("set_property"), json!("chapter"), json!(index)],
+    )?;
+    Ok(())
+}
+
+/// Send a command to MPV and wait for its reply.
+///
+/// # Arguments
+/// * `socket` - IPC socket path
+/// * `command` - Command name followed by its arguments
+///
+/// # Returns
+/// * `Result<Value>` - The reply's `data` field (`Null` if absent)
+fn send_command(socket: &Path, command: &[Value]) -> Result<Value> {
+    send_commands(socket, &[command.to_vec()])?
+        .pop()
+        .unwrap_or_else(|| Err(anyhow::anyhow!("mpv sent no reply")))
+}
+
+/// Send several commands to MPV over one connection and wait for the replies.
+///
+/// # Arguments
+/// * `socket` - IPC socket path
+/// * `commands` - Commands, each a name followed by its arguments
+///
+/// # Returns
+/// * `Result<Vec<Result<Value>>>` - Each reply's `data` field (`Null` if
+///   absent) in command order, or error if MPV isn't reachable
+///
+/// # Details
+/// Event messages MPV sends in between are skipped. Replies with an error
+/// other than "success" are returned as errors.
+#[cfg(unix)]
+fn send_commands(socket: &Path, commands: &[Vec<Value>]) -> Result<Vec<Result<Value>>> {
+    use std::io::{BufRead, BufReader, Write};
+    use std::os::unix::net::UnixStream;
+    use std::time::Duration;
+
+    let mut stream = UnixStream::connect(socket).with_context(|| {
+        format!(
+            "No video is playing in mpv (IPC socket {} unavailable)",
+            socket.display()
+        )
+    })?;
+    stream.set_read_timeout(Some(Duration::from_secs(2)))?;
+
+    for (offset, command) in (0..).zip(commands) {
+        let request = json!({ "command": command, "request_id": REQUEST_ID + offset });
+        writeln!(stream, "{}", request).context("Failed to send command to mpv")?;
+    }
+
+    let mut replies: Vec<Option<Result<Value>>> = commands.iter().map(|_| None).collect();
+    let mut pending = commands.len();
+    let reader = BufReader::new(stream);
+    for line in reader.lines() {
+        if pending == 0 {
+            break;
+        }
+        let line = line.context("Failed to read reply from mpv")?;
+        let Ok(message) = serde_json::from_str::<Value>(&line) else {
+            continue;
+        };
+        let Some(slot) = message
+            .get("request_id")
+            .and_then(Value::as_u64)
+            .and_then(|id| id.checked_sub(REQUEST_ID))
+            .and_then(|index| replies.get_mut(index as usize))
+            .filter(|slot| slot.is_none())
+        else {
+            continue;
+        };
+        *slot = Some(match message.get("error").and_then(Value::as_str) {
+            Some("success") | None => Ok(message.get("data").cloned().unwrap_or(Value::Null)),
+            Some(error) => Err(anyhow::anyhow!("mpv: {}", error)),
+        });
+        pending -= 1;
+    }
+    if pending > 0 {
+        return Err(anyhow::anyhow!("mpv closed the IPC connection"));
+    }
+    Ok(replies.into_iter().flatten().collect())
+}
+
+/// Send commands to MPV (unsupported on this platform).
+#[cfg(not(unix))]
+fn send_commands(_socket: &Path, _commands: &[Vec<Value>]) -> Result<Vec<Result<Value>>> {
+    Err(anyhow::anyhow!(
+        "mpv IPC is only supported on Unix-like systems"
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_chapter_list() {
+        let data = json!([
+            { "title": "Intro", "time": 0.0 },
+            { "title": "  ", "time": 95.5 },
+            { "time": 3725 },
+            { "title": "Broken" }
+        ]);
+        let chapters = parse_chapter_list(&data);
+        assert_eq!(chapters.len(), 3);
+        assert_eq!(chapters[0].title, "Intro");
+        assert_eq!(chapters[0].display_time(), "0:00");
+        assert_eq!(chapters[1].title, "Chapter 2");
+        assert_eq!(chapters[1].display_time(), "1:35");
+        assert_eq!(chapters[2].title, "Chapter 3");
+        assert_eq!(chapters[2].display_time(), "1:02:05");
+
+        // Videos without chapters
+        assert!(parse_chapter_list(&json!([])).is_empty());
+        assert!(parse_chapter_list(&Value::Null).is_empty());
+    }
+
+    #[test]
+    fn test_playback_status_display() {
+        let mut status = PlaybackStatus {
+            title: "My video".to_string(),
+            position: 83.4,
+            duration: 600.0,
+            paused: false,
+            video_id: None,
+        };
+        assert_eq!(status.display(), "Now playing: My video 1:23");
+        status.paused = true;
+        status.position = 3725.0;
+        assert_eq!(status.display(), "Now playing: My video 1:02:05 (paused)");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_query_playback() {
+        use std::io::{BufRead, BufReader, Write};
+        use std::os::unix::net::UnixListener;
+
+        let dir = tempfile::tempdir().unwrap();
+        let socket = dir.path().join("mpv.sock");
+        assert_eq!(query_playback(&socket), None);
+
+        let listener = UnixListener::bind(&socket).unwrap();
+        let server = std::thread::spawn(move || {
+            // All properties are asked for on one connection
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            let mut replies = Vec::new();
+            for _ in 0..5 {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                let request: Value = serde_json::from_str(&line).unwrap();
+                let reply = match request["command"][1].as_str().unwrap() {
+                    "media-title" => json!({ "data": "My video", "error": "success" }),
+                    "path" => {
+                        json!({ "data": "https://www.youtube.com/watch?v=abc123&t=5", "error": "success" })
+                    }
+                    "time-pos" => json!({ "data": 65.2, "error": "success" }),
+                    "duration" => json!({ "error": "property unavailable" }),
+                    "pause" => json!({ "data": true, "error": "success" }),
+                    other => panic!("unexpected property {}", other),
+                };
+                let mut reply = reply;
+                reply["request_id"] = request["request_id"].clone();
+                replies.push(reply);
+            }
+            // Replies may come in any order, with events in between
+            replies.reverse();
+            for reply in replies {
+                writeln!(stream, "{}", json!({ "event": "playback-restart" })).unwrap();
+                writeln!(stream, "{}", reply).unwrap();
+            }
+        });
+
+        assert_eq!(
+            query_playback(&socket),
+            Some(PlaybackStatus {
+                title: "My video".to_string(),
+                position: 65.2,
+                duration: 0.0,
+                paused: true,
+                video_id: Some("abc123".to_string()),
+            })
+        );
+        server.join().unwrap();
+
+        // MPV exited without removing its socket
+        assert_eq!(query_playback(&socket), None);
+    }
+
+    #[test]
+    fn test_video_id_from_url() {
+        let id = |url| video_id_from_url(url);
+        assert_eq!(
+            id("https://www.youtube.com/watch?v=abc123").as_deref(),
+            Some("abc123")
+        );
+        assert_eq!(
+            id("https://www.youtube.com/watch?feature=x&v=abc123#t=5").as_deref(),
+            Some("abc123")
+        );
+        assert_eq!(id("https://youtu.be/abc123?t=5").as_deref(), Some("abc123"));
+        assert_eq!(id("https://youtu.be/").as_deref(), None);
+        assert_eq!(id("/home/user/video.mkv"), None);
+    }
+}
@@ -0,0 +1,435 @@
+//! OAuth2 login flow for personalized recommendations.
+//!
+//! Implements the installed-app loopback flow: a local HTTP server waits for
+//! the redirect carrying the authorization code, which is exchanged for
+//! tokens that are then written to the token file next to the config.
+
+use crate::config::Config;
+use crate::player::open_in_browser;
+use anyhow::{Context, Result};
+use reqwest::Url;
+use serde::Deserialize;
+use std::io::{BufRead, BufReader, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::mpsc;
+
+/// Google OAuth2 consent page.
+const AUTH_URL: &str = "https://accounts.google.com/o/oauth2/v2/auth";
+
+/// Google OAuth2 token endpoint.
+pub const TOKEN_URL: &str = "https://oauth2.googleapis.com/token";
+
+/// Page shown in the browser after the redirect.
+const CALLBACK_PAGE: &str =
+    "<html><body><h1>yt-tui</h1><p>Login finished. You can close this tab.</p></body></html>";
+
+/// Successful token endpoint response.
+#[derive(Debug, Clone, Deserialize, PartialEq, Eq)]
+pub struct TokenResponse {
+    /// Short-lived access token
+    pub access_token: String,
+    /// Long-lived refresh token (only sent on first consent)
+    #[serde(default)]
+    pub refresh_token: Option<String>,
+    /// Access token lifetime in seconds
+    #[serde(default)]
+    pub expires_in: Option<u64>,
+    /// Granted scopes (space separated)
+    #[serde(default)]
+    pub scope: Option<String>,
+    /// Token type (normally "Bearer")
+    #[serde(default)]
+    pub token_type: Option<String>,
+}
+
+/// Error body returned by the token endpoint.
+#[derive(Debug, Deserialize)]
+struct TokenErrorResponse {
+    /// Error code (e.g. "invalid_grant")
+    error: String,
+    /// Human readable explanation
+    #[serde(default)]
+    error_description: Option<String>,
+}
+
+/// Run the interactive `--login` flow and store the tokens in the config.
+///
+/// # Arguments
+/// * `config` - Configuration with `oauth_client_id` and `oauth_client_secret` set
+///
+/// # Returns
+/// * `Result<()>` - Success or error
+///
+/// # Details
+/// Listens on `127.0.0.1:<oauth_redirect_port>` (0 picks a free port), opens the
+/// consent page in the browser (the URL is also printed), waits for the redirect,
+/// exchanges the code for tokens and saves them with `Config::save_tokens`.
+pub async fn run_login_flow(config: &mut Config) -> Result<()> {
+    let config_path = config.file_path()?;
+    let client_id = config
+        .oauth_client_id
+        .clone()
+        .filter(|id| !id.is_empty())
+        .with_context(|| {
+            format!(
+                "oauth_client_id must be set in {} to log in",
+                config_path.display()
+            )
+        })?;
+    let client_secret = config
+        .oauth_client_secret
+        .clone()
+        .filter(|secret| !secret.is_empty())
+        .with_context(|| {
+            format!(
+                "oauth_client_secret must be set in {} to log in",
+                config_path.display()
+            )
+        })?;
+
+    let listener =
+        TcpListener::bind(("127.0.0.1", config.oauth_redirect_port)).with_context(|| {
+            format!(
+                "Failed to listen on port {} for the OAuth callback",
+                config.oauth_redirect_port
+            )
+        })?;
+    let port = listener
+        .local_addr()
+        .context("Failed to read callback address")?
+        .port();
+    let redirect_uri = format!("http://127.0.0.1:{}", port);
+    let state = new_state()?;
+    let auth_url = authorization_url(&client_id, &redirect_uri, &config.oauth_scopes, &state)?;
+
+    println!("Opening the Google consent page in your browser.");
+    println!("If it doesn't open, visit:\n\n{}\n", auth_url);
+    if let Err(e) = open_in_browser(auth_url.as_str()) {
+        eprintln!("{}", e);
+    }
+
+    let code = tokio::task::spawn_blocking(move || wait_for_callback(&listener, &state))
+        .await
+        .context("OAuth callback task failed")??;
+
+    let response = reqwest::Client::new()
+        .post(TOKEN_URL)
+        .form(&[
+            ("code", code.as_str()),
+            ("client_id", client_id.as_str()),
+            ("client_secret", client_secret.as_str()),
+            ("redirect_uri", redirect_uri.as_str()),
+            ("grant_type", "authorization_code"),
+        ])
+        .send()
+        .await
+        .context("Failed to reach the OAuth token endpoint")?;
+    let status = response.status().as_u16();
+    let body = response
+        .text()
+        .await
+        .context("Failed to read token response")?;
+    let tokens = parse_token_response(status, &body)?;
+
+    config.oauth_access_token = Some(tokens.access_token);
+    if tokens.refresh_token.is_some() {
+        config.oauth_refresh_token = tokens.refresh_token;
+    }
+    config.save_tokens()?;
+    println!(
+        "Saved OAuth tokens to {}",
+        config.tokens_file_path()?.display()
+    );
+    Ok(())
+}
+
+/// Build the consent page URL.
+///
+/// # Arguments
+/// * `client_id` - OAuth2 client ID
+/// * `redirect_uri` - Loopback redirect URI
+/// * `scopes` - Requested scopes
+/// * `state` - Anti-forgery token echoed back in the redirect
+///
+/// # Returns
+/// * `Result<Url>` - Consent URL or error
+///
+/// # Details
+/// Requests offline access and forces the consent prompt so a refresh token is issued.
+pub fn authorization_url(
+    client_id: &str,
+    redirect_uri: &str,
+    scopes: &[String],
+    state: &str,
+) -> Result<Url> {
+    let scope = scopes.join(" ");
+    Url::parse_with_params(
+        AUTH_URL,
+        &[
+            ("client_id", client_id),
+            ("redirect_uri", redirect_uri),
+            ("response_type", "code"),
+            ("scope", scope.as_str()),
+            ("access_type", "offline"),
+            ("prompt", "consent"),
+            ("state", state),
+        ],
+    )
+    .context("Failed to build authorization URL")
+}
+
+/// Reply to requests other than the redirect (e.g. `/favicon.ico`).
+const NOT_FOUND_RESPONSE: &str =
+    "HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\nConnection: close\r\n\r\n";
+
+/// Wait for the redirect request and answer it with a short page.
+///
+/// # Arguments
+/// * `listener` - Bound callback listener
+/// * `state` - Expected anti-forgery token
+///
+/// # Returns
+/// * `Result<String>` - Authorization code or error
+///
+/// # Details
+/// Browsers may open extra connections (speculative preconnects that never
+/// send anything, `/favicon.ico` requests), so connections are accepted until
+/// one carries the redirect (see `is_callback_request`). Each connection is
+/// read on its own thread so an idle one can't hold up the others; other
+/// requests get a 404.
+fn wait_for_callback(listener: &TcpListener, state: &str) -> Result<String> {
+    let listener = listener
+        .try_clone()
+        .context("Failed to accept OAuth callback")?;
+    let (sender, receiver) = mpsc::channel::<(TcpStream, String)>();
+    std::thread::spawn(move || {
+        for stream in listener.incoming().flatten() {
+            let sender = sender.clone();
+            std::thread::spawn(move || {
+                let mut request_line = String::new();
+                if BufReader::new(&stream).read_line(&mut request_line).is_ok() {
+                    let _ = sender.send((stream, request_line));
+                }
+            });
+        }
+    });
+
+    for (mut stream, request_line) in receiver {
+        if !is_callback_request(&request_line) {
+            let _ = stream.write_all(NOT_FOUND_RESPONSE.as_bytes());
+            continue;
+        }
+        let response = format!(
+            "HTTP/1.1 200 OK\r\nContent-Type: text/html; charset=utf-8\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+            CALLBACK_PAGE.len(),
+            CALLBACK_PAGE
+        );
+        let _ = stream.write_all(response.as_bytes());
+        return parse_callback_request(&request_line, state);
+    }
+    Err(anyhow::anyhow!("Failed to accept OAuth callback"))
+}
+
+/// Check whether a request line is the OAuth redirect.
+///
+/// # Arguments
+/// * `request_line` - First HTTP line of a request to the callback listener
+///
+/// # Returns
+/// * `bool` - True for a request to the redirect path (`/`) with a `code` or
+///   `error` parameter
+fn is_callback_request(request_line: &str) -> bool {
+    request_line
+        .split_whitespace()
+        .nth(1)
+        .and_then(|target| Url::parse(&format!("http://127.0.0.1{}", target)).ok())
+        .is_some_and(|url| {
+            url.path() == "/"
+                && url
+                    .query_pairs()
+                    .any(|(key, _)| key == "code" || key == "error")
+        })
+}
+
+/// Extract the authorization code from the redirect's request line.
+///
+/// # Arguments
+/// * `request_line` - First HTTP line, e.g. `GET /?code=...&state=... HTTP/1.1`
+/// * `expected_state` - State sent with the consent URL
+///
+/// # Returns
+/// * `Result<String>` - Authorization code, or error if consent was denied or the state differs
+pub fn parse_callback_request(request_line: &str, expected_state: &str) -> Result<String> {
+    let target = request_line
+        .split_whitespace()
+        .nth(1)
+        .context("Malformed OAuth callback request")?;
+    let url = Url::parse(&format!("http://127.0.0.1{}", target))
+        .context("Malformed OAuth callback URL")?;
+
+    let mut code = None;
+    let mut state = None;
+    for (key, value) in url.query_pairs() {
+        match key.as_ref() {
+            "code" => code = Some(value.into_owned()),
+            "state" => state = Some(value.into_owned()),
+            "error" => return Err(anyhow::anyhow!("Authorization failed: {}", value)),
+            _ => {}
+        }
+    }
+
+    if state.as_deref() != Some(expected_state) {
+        return Err(anyhow::anyhow!("OAuth state mismatch, aborting login"));
+    }
+    code.context("OAuth callback did not include an authorization code")
+}
+
+/// Parse the token endpoint response.
+///
+/// # Arguments
+/// * `status` - HTTP status code
+/// * `body` - Response body
+///
+/// # Returns
+/// * `Result<TokenResponse>` - Tokens, or error with the endpoint's explanation
+pub fn parse_token_response(status: u16, body: &str) -> Result<TokenResponse> {
+    if (200..300).contains(&status) {
+        return serde_json::from_str(body).context("Failed to parse token response");
+    }
+    match serde_json::from_str::<TokenErrorResponse>(body) {
+        Ok(err) => Err(anyhow::anyhow!(
+            "Token exchange failed ({}): {}{}",
+            status,
+            err.error,
+            err.error_description
+                .map(|d| format!(" - {}", d))
+                .unwrap_or_default()
+        )),
+        Err(_) => Err(anyhow::anyhow!(
+            "Token exchange failed ({}): {}",
+            status,
+            body
+        )),
+    }
+}
+
+/// Generate a state token for the consent request.
+///
+/// # Returns
+/// * `Result<String>` - 16 random bytes from the OS, hex-encoded
+fn new_state() -> Result<String> {
+    let mut bytes = [0u8; 16];
+    getrandom::fill(&mut bytes)
+        .map_err(|e| anyhow::anyhow!("Failed to generate the OAuth state: {}", e))?;
+    Ok(bytes.iter().map(|b| format!("{:02x}", b)).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_token_response_success() {
+        let body = r#"{
+            "access_token": "ya29.abc",
+            "expires_in": 3599,
+            "refresh_token": "1//refresh",
+            "scope": "https://www.googleapis.com/auth/youtube.readonly",
+            "token_type": "Bearer"
+        }"#;
+        let tokens = parse_token_response(200, body).unwrap();
+        assert_eq!(tokens.access_token, "ya29.abc");
+        assert_eq!(tokens.refresh_token.as_deref(), Some("1//refresh"));
+        assert_eq!(tokens.expires_in, Some(3599));
+    }
+
+    #[test]
+    fn test_parse_token_response_without_refresh_token() {
+        let tokens =
+            parse_token_response(200, r#"{"access_token": "ya29.abc", "expires_in": 3599}"#)
+                .unwrap();
+        assert_eq!(tokens.refresh_token, None);
+        assert!(parse_token_response(200, r#"{"expires_in": 3599}"#).is_err());
+    }
+
+    #[test]
+    fn test_parse_token_response_error() {
+        let body = r#"{"error": "invalid_grant", "error_description": "Bad Request"}"#;
+        let err = parse_token_response(400, body).unwrap_err().to_string();
+        assert!(err.contains("invalid_grant"));
+        assert!(err.contains("Bad Request"));
+        assert!(parse_token_response(500, "oops").is_err());
+    }
+
+    #[test]
+    fn test_parse_callback_request() {
+        let line = "GET /?state=abc&code=4%2F0Ad&scope=x HTTP/1.1\r\n";
+        assert_eq!(parse_callback_request(line, "abc").unwrap(), "4/0Ad");
+        assert!(parse_callback_request(line, "other").is_err());
+        assert!(
+            parse_callback_request("GET /?error=access_denied&state=abc HTTP/1.1", "abc").is_err()
+        );
+    }
+
+    #[test]
+    fn test_wait_for_callback_skips_other_requests() {
+        use std::io::Read;
+
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+        let browser = std::thread::spawn(move || {
+            // A preconnect that never sends a request
+            let idle = TcpStream::connect(address).unwrap();
+            let mut favicon = TcpStream::connect(address).unwrap();
+            favicon
+                .write_all(b"GET /favicon.ico HTTP/1.1\r\n\r\n")
+                .unwrap();
+            let mut reply = String::new();
+            favicon.read_to_string(&mut reply).unwrap();
+            assert!(reply.starts_with("HTTP/1.1 404"));
+
+            let mut callback = TcpStream::connect(address).unwrap();
+            callback
+                .write_all(b"GET /?state=abc&code=xyz HTTP/1.1\r\n\r\n")
+                .unwrap();
+            let mut reply = String::new();
+            callback.read_to_string(&mut reply).unwrap();
+            assert!(reply.starts_with("HTTP/1.1 200"));
+            drop(idle);
+        });
+
+        assert_eq!(wait_for_callback(&listener, "abc").unwrap(), "xyz");
+        browser.join().unwrap();
+    }
+
+    #[test]
+    fn test_is_callback_request() {
+        assert!(is_callback_request("GET /?code=x&state=s HTTP/1.1\r\n"));
+        assert!(is_callback_request(
+            "GET /?error=access_denied HTTP/1.1\r\n"
+        ));
+        assert!(!is_callback_request("GET /favicon.ico HTTP/1.1\r\n"));
+        assert!(!is_callback_request("GET /?foo=bar HTTP/1.1\r\n"));
+        assert!(!is_callback_request("GET /other?code=x HTTP/1.1\r\n"));
+        assert!(!is_callback_request(""));
+    }
+
+    #[test]
+    fn test_authorization_url() {
+        let scopes = vec!["https://www.googleapis.com/auth/youtube.readonly".to_string()];
+        let url = authorization_url("id", "http://127.0.0.1:8080", &scopes, "s").unwrap();
+        let pairs: Vec<(String, String)> = url.query_pairs().into_owned().collect();
+        assert!(pairs.contains(&("scope".to_string(), scopes[0].clone())));
+        assert!(pairs.contains(&("access_type".to_string(), "offline".to_string())));
+        assert!(pairs.contains(&("state".to_string(), "s".to_string())));
+    }
+
+    #[test]
+    fn test_new_state_is_random_hex() {
+        let first = new_state().unwrap();
+        let second = new_state().unwrap();
+        assert_eq!(first.len(), 32);
+        assert!(first.chars().all(|c| c.is_ascii_hexdigit()));
+        assert_ne!(first, second);
+    }
+}
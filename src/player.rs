@@ -1,14 +1,58 @@
 //! Video player integration for MPV.
 //!
-//! Handles opening YouTube videos in the MPV video player.
+//! Handles opening YouTube videos in the MPV video player (or a configured
+//! replacement command).
 
 use anyhow::{Context, Result};
-use std::process::Command;
+use std::ffi::OsStr;
+use std::path::Path;
+use std::process::{Command, Stdio};
 
-/// Open a YouTube video in MPV player.
+/// Playback settings shared by every player invocation.
+#[derive(Debug, Clone, Copy)]
+pub struct PlayOptions<'a> {
+    /// Player command (`player_command`, "mpv" by default)
+    pub command: &'a str,
+    /// Arguments from `player_args`, passed before the extra arguments
+    pub player_args: &'a [String],
+    /// Additional MPV arguments (e.g. `--speed=1.5`), passed before the URLs
+    pub extra_args: &'a [String],
+    /// Play only the audio track (`--no-video`, best audio format)
+    pub audio_only: bool,
+    /// Highest video height to request (0 for no cap)
+    pub max_resolution: u32,
+}
+
+/// Build the `--ytdl-format` selector.
+///
+/// # Arguments
+/// * `max_resolution` - Highest video height (e.g. 720, 1080, 2160), 0 for no cap
+/// * `audio_only` - Select the best audio-only format instead
+///
+/// # Returns
+/// * `String` - yt-dlp format selector
+///
+/// # Details
+/// Prefers merged streams at or below the cap, then merging the best video
+/// and audio at or below the cap, then anything.
+pub fn format_preference(max_resolution: u32, audio_only: bool) -> String {
+    if audio_only {
+        "bestaudio/best".to_string()
+    } else if max_resolution == 0 {
+        "best".to_string()
+    } else {
+        format!(
+            "best[height<=?{max}]/bestvideo[height<=?{max}]+bestaudio/best",
+            max = max_resolution
+        )
+    }
+}
+
+/// Open a YouTube video in the player.
 ///
 /// # Arguments
 /// * `video_url` - YouTube video URL (e.g., https://www.youtube.com/watch?v=VIDEO_ID)
+/// * `options` - Player command and arguments, audio-only mode and resolution cap
 ///
 /// # Returns
 /// * `Result<()>` - Success or error
@@ -17,8 +61,83 @@ use std::process::Command;
 /// Uses MPV directly with the YouTube URL. MPV has built-in support for YouTube URLs
 /// when yt-dlp is installed, and handles the yt-dlp integration automatically.
 /// This ensures both video and audio work correctly.
-/// Falls back to Haruna if MPV is not available.
-pub fn open_in_mpv(video_url: &str) -> Result<()> {
+/// MPV is started with an IPC socket (see `mpv_ipc::socket_path`) so the
+/// playing video can be controlled from the TUI and shown as "Now playing".
+/// Other commands get only the configured arguments and the URL (see
+/// `spawn_player`).
+pub fn open_in_player(video_url: &str, options: PlayOptions) -> Result<()> {
+    spawn_player(&[video_url.to_string()], options)
+}
+
+/// Play several YouTube videos in sequence in one player instance.
+///
+/// # Arguments
+/// * `urls` - Video URLs in playback order
+/// * `options` - Player command and arguments, audio-only mode and resolution cap
+///
+/// # Returns
+/// * `Result<()>` - Success, or error if the list is empty or the player can't be started
+///
+/// # Details
+/// MPV treats multiple positional URLs as a playlist.
+pub fn play_queue(urls: &[String], options: PlayOptions) -> Result<()> {
+    if urls.is_empty() {
+        return Err(anyhow::anyhow!("The queue is empty"));
+    }
+    spawn_player(urls, options)
+}
+
+/// Start the player with one or more URLs.
+///
+/// # Arguments
+/// * `urls` - Video URLs (a playlist if more than one)
+/// * `options` - Player command and arguments, audio-only mode and resolution cap
+///
+/// # Returns
+/// * `Result<()>` - Success or error if the player could not be started
+///
+/// # Details
+/// A command other than "mpv" (e.g. a wrapper script) is run as
+/// `<command> <player_args> <extra args> <urls>`, without the MPV-specific
+/// output, format and IPC options. Its input and output are detached, so it
+/// can't draw over the TUI.
+fn spawn_player(urls: &[String], options: PlayOptions) -> Result<()> {
+    if options.command == "mpv" {
+        return spawn_mpv(urls, options);
+    }
+    Command::new(options.command)
+        .args(options.player_args)
+        .args(options.extra_args)
+        .args(urls)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .with_context(|| format!("Failed to start player '{}'", options.command))?;
+    Ok(())
+}
+
+/// Start MPV with one or more URLs, trying video/audio outputs in order.
+///
+/// # Arguments
+/// * `urls` - Video URLs (a playlist if more than one)
+/// * `options` - Player and extra arguments, audio-only mode (`--no-video`) and resolution cap
+///
+/// # Returns
+/// * `Result<()>` - Success or error if no MPV invocation could be started
+fn spawn_mpv(urls: &[String], options: PlayOptions) -> Result<()> {
+    let PlayOptions {
+        command: _,
+        player_args,
+        extra_args,
+        audio_only,
+        max_resolution,
+    } = options;
+    let ipc_arg = format!(
+        "--input-ipc-server={}",
+        crate::mpv_ipc::socket_path().display()
+    );
+
     // Use mpv directly with YouTube URL
     // MPV has built-in yt-dlp support and handles YouTube URLs properly
     // We try Wayland-compatible video outputs first, then fall back to others
@@ -31,7 +150,8 @@ pub fn open_in_mpv(video_url: &str) -> Result<()> {
 
     // Format preference: prefer merged streams (best), then try merging best video+audio
     // This ensures we get both video and audio together when possible
-    let format_preference = "best[height<=?1080]/bestvideo[height<=?1080]+bestaudio/best";
+    let format_preference = format_preference(max_resolution, audio_only);
+    let video_arg = if audio_only { Some("--no-video") } else { None };
 
     // Audio output preference: try pipewire (Wayland), pulse, then auto-detect
     let audio_outputs = if is_wayland {
@@ -48,6 +168,8 @@ pub fn open_in_mpv(video_url: &str) -> Result<()> {
             for ao in &audio_outputs {
                 let mut cmd = Command::new("mpv");
                 cmd.arg("--player-operation-mode=pseudo-gui")
+                    .arg(&ipc_arg)
+                    .args(video_arg)
                     .arg(format!("--ytdl-format={}", format_preference))
                     .arg(format!("--vo={}", vo))
                     .arg(format!("--ao={}", ao));
@@ -56,7 +178,7 @@ pub fn open_in_mpv(video_url: &str) -> Result<()> {
                     cmd.arg("--hwdec=no");
                 }
 
-                cmd.arg(video_url);
+                cmd.args(player_args).args(extra_args).args(urls);
 
                 if cmd.spawn().is_ok() {
                     return Ok(());
@@ -71,6 +193,8 @@ pub fn open_in_mpv(video_url: &str) -> Result<()> {
             for ao in &audio_outputs {
                 let mut cmd = Command::new("mpv");
                 cmd.arg("--player-operation-mode=pseudo-gui")
+                    .arg(&ipc_arg)
+                    .args(video_arg)
                     .arg(format!("--ytdl-format={}", format_preference))
                     .arg(format!("--vo={}", vo))
                     .arg(format!("--ao={}", ao));
@@ -79,7 +203,7 @@ pub fn open_in_mpv(video_url: &str) -> Result<()> {
                     cmd.arg("--hwdec=no");
                 }
 
-                cmd.arg(video_url);
+                cmd.args(player_args).args(extra_args).args(urls);
 
                 if cmd.spawn().is_ok() {
                     return Ok(());
@@ -91,29 +215,228 @@ pub fn open_in_mpv(video_url: &str) -> Result<()> {
     // Final fallback: Use best format with auto-detection for both video and audio
     Command::new("mpv")
         .arg("--player-operation-mode=pseudo-gui")
-        .arg("--ytdl-format=best")
-        .arg(video_url)
+        .arg(&ipc_arg)
+        .args(video_arg)
+        .arg(if audio_only {
+            "--ytdl-format=bestaudio/best"
+        } else {
+            "--ytdl-format=best"
+        })
+        .args(player_args)
+        .args(extra_args)
+        .args(urls)
         .spawn()
         .with_context(|| {
             format!(
                 "Failed to open video with mpv. Make sure mpv and yt-dlp are installed. URL: {}",
-                video_url
+                urls.join(" ")
             )
         })?;
 
     Ok(())
 }
 
-/// Check if MPV is available in the system PATH.
+/// Split a command-line string into arguments.
+///
+/// # Arguments
+/// * `input` - Arguments as typed, e.g. `--speed=1.5 --title="My video"`
+///
+/// # Returns
+/// * `Result<Vec<String>>` - Arguments, or error for an unclosed quote
+///
+/// # Details
+/// Splits on whitespace outside quotes. Single quotes keep their content
+/// literally; inside double quotes and unquoted text a backslash escapes the
+/// next character. Quotes can join with adjacent text (`--title="a b"`).
+pub fn split_args(input: &str) -> Result<Vec<String>> {
+    let mut args = Vec::new();
+    let mut current = String::new();
+    // Whether `current` holds an argument, so `""` yields an empty argument
+    let mut in_arg = false;
+    let mut quote: Option<char> = None;
+    let mut chars = input.chars();
+
+    while let Some(ch) = chars.next() {
+        match (quote, ch) {
+            (Some('\''), '\'') | (Some('"'), '"') => quote = None,
+            (Some('\''), _) => current.push(ch),
+            (_, '\\') => {
+                if let Some(escaped) = chars.next() {
+                    current.push(escaped);
+                }
+                in_arg = true;
+            }
+            (Some(_), _) => current.push(ch),
+            (None, '\'' | '"') => {
+                quote = Some(ch);
+                in_arg = true;
+            }
+            (None, c) if c.is_whitespace() => {
+                if in_arg {
+                    args.push(std::mem::take(&mut current));
+                    in_arg = false;
+                }
+            }
+            (None, _) => {
+                current.push(ch);
+                in_arg = true;
+            }
+        }
+    }
+
+    if let Some(q) = quote {
+        return Err(anyhow::anyhow!("Unclosed {} quote", q));
+    }
+    if in_arg {
+        args.push(current);
+    }
+    Ok(args)
+}
+
+/// Open a URL in the default web browser.
+///
+/// # Arguments
+/// * `url` - Page to open
+///
+/// # Returns
+/// * `Result<()>` - Success or error if no opener could be started
+///
+/// # Details
+/// Uses `open` on macOS, the `url.dll` URL handler on Windows (`cmd /C start`
+/// would cut the URL at the first `&`) and `xdg-open` (falling back to
+/// `gio open`) elsewhere. The opener runs detached; the browser is not awaited.
+pub fn open_in_browser(url: &str) -> Result<()> {
+    let openers: &[(&str, &[&str])] = if cfg!(target_os = "macos") {
+        &[("open", &[])]
+    } else if cfg!(target_os = "windows") {
+        &[("rundll32", &["url.dll,FileProtocolHandler"])]
+    } else {
+        &[("xdg-open", &[]), ("gio", &["open"])]
+    };
+    open_with(openers, url)
+}
+
+/// Start the first opener command that can be run.
+///
+/// # Arguments
+/// * `openers` - Commands and their arguments, tried in order (the URL is appended)
+/// * `url` - Page to open
+///
+/// # Returns
+/// * `Result<()>` - Success or error naming the openers that were tried
+fn open_with(openers: &[(&str, &[&str])], url: &str) -> Result<()> {
+    for (program, args) in openers {
+        let spawned = Command::new(program)
+            .args(*args)
+            .arg(url)
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn();
+        if spawned.is_ok() {
+            return Ok(());
+        }
+    }
+
+    let names: Vec<&str> = openers.iter().map(|(program, _)| *program).collect();
+    Err(anyhow::anyhow!(
+        "No browser opener found (tried {})",
+        names.join(", ")
+    ))
+}
+
+/// Check if the player command is available in the system PATH.
+///
+/// # Arguments
+/// * `command` - Player command (`player_command`)
+///
+/// # Returns
+/// * `bool` - True if the command was found
+///
+/// # Details
+/// The command is only looked up (see `find_in_path`), never run, so a
+/// wrapper script doesn't start or block startup.
+pub fn is_player_available(command: &str) -> bool {
+    find_in_path(command, std::env::var_os("PATH").as_deref())
+}
+
+/// Check if yt-dlp is available in the system PATH.
+///
+/// # Returns
+/// * `bool` - True if `yt-dlp` was found
+///
+/// # Details
+/// MPV needs yt-dlp to resolve YouTube URLs.
+pub fn is_ytdlp_available() -> bool {
+    is_player_available("yt-dlp")
+}
+
+/// Look a command up like the shell does, without running it.
+///
+/// # Arguments
+/// * `command` - Command name, or a path if it contains a separator
+/// * `path_var` - Value of the `PATH` variable
 ///
 /// # Returns
-/// * `bool` - True if MPV command is available
+/// * `bool` - True if an executable file was found
 ///
 /// # Details
-/// Checks if `mpv --version` succeeds.
-#[allow(dead_code)] // Useful for startup validation and error messages
-pub fn is_mpv_available() -> bool {
-    Command::new("mpv").arg("--version").output().is_ok()
+/// On Windows the usual extensions (`.exe`, `.cmd`, `.bat`, `.com`) are tried too.
+fn find_in_path(command: &str, path_var: Option<&OsStr>) -> bool {
+    if command.is_empty() {
+        return false;
+    }
+    if command.contains('/') || command.contains(std::path::MAIN_SEPARATOR) {
+        return is_executable(Path::new(command));
+    }
+    let extensions: &[&str] = if cfg!(windows) {
+        &["", ".exe", ".cmd", ".bat", ".com"]
+    } else {
+        &[""]
+    };
+    path_var.is_some_and(|paths| {
+        std::env::split_paths(paths).any(|dir| {
+            extensions
+                .iter()
+                .any(|ext| is_executable(&dir.join(format!("{}{}", command, ext))))
+        })
+    })
+}
+
+/// Check if a file can be executed.
+///
+/// # Arguments
+/// * `path` - File to check
+///
+/// # Returns
+/// * `bool` - True for a file with an execute bit (any file on Windows)
+fn is_executable(path: &Path) -> bool {
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        path.metadata()
+            .is_ok_and(|meta| meta.is_file() && meta.permissions().mode() & 0o111 != 0)
+    }
+    #[cfg(not(unix))]
+    {
+        path.is_file()
+    }
+}
+
+/// Build the startup warning for missing playback tools.
+///
+/// # Arguments
+/// * `missing` - Names of the commands that could not be started
+///
+/// # Returns
+/// * `Option<String>` - Warning for the status bar, or None if nothing is missing
+pub fn missing_tools_warning(missing: &[&str]) -> Option<String> {
+    if missing.is_empty() {
+        return None;
+    }
+    Some(format!(
+        "{} not found — playback will fail",
+        missing.join(" and ")
+    ))
 }
 
 #[cfg(test)]
@@ -121,9 +444,120 @@ mod tests {
     use super::*;
 
     #[test]
-    fn test_is_mpv_available() {
+    fn test_open_with_missing_opener() {
+        let error = open_with(
+            &[
+                ("yt-tui-no-such-opener", &[]),
+                ("yt-tui-no-such-gio", &["open"]),
+            ],
+            "https://www.youtube.com/watch?v=dQw4w9WgXcQ",
+        )
+        .unwrap_err();
+        assert!(error.to_string().contains("yt-tui-no-such-opener"));
+        assert!(open_with(&[], "https://youtu.be/x").is_err());
+    }
+
+    #[test]
+    fn test_missing_tools_warning() {
+        assert_eq!(missing_tools_warning(&[]), None);
+        assert_eq!(
+            missing_tools_warning(&["mpv"]).as_deref(),
+            Some("mpv not found — playback will fail")
+        );
+        assert_eq!(
+            missing_tools_warning(&["mpv", "yt-dlp"]).as_deref(),
+            Some("mpv and yt-dlp not found — playback will fail")
+        );
+    }
+
+    #[test]
+    fn test_split_args() {
+        assert_eq!(
+            split_args("  --speed=1.5   --mute ").unwrap(),
+            ["--speed=1.5", "--mute"]
+        );
+        assert_eq!(
+            split_args(r#"--title="My video" --sub-file='/tmp/a b.srt'"#).unwrap(),
+            ["--title=My video", "--sub-file=/tmp/a b.srt"]
+        );
+        assert_eq!(
+            split_args(r#"a\ b "say \"hi\"" 'it\s' """#).unwrap(),
+            ["a b", "say \"hi\"", "it\\s", ""]
+        );
+        assert!(split_args("").unwrap().is_empty());
+        assert!(split_args("--title=\"oops").is_err());
+    }
+
+    #[test]
+    fn test_play_queue_empty() {
+        let options = PlayOptions {
+            command: "mpv",
+            player_args: &[],
+            extra_args: &[],
+            audio_only: false,
+            max_resolution: 1080,
+        };
+        assert!(play_queue(&[], options).is_err());
+    }
+
+    #[test]
+    fn test_format_preference() {
+        assert_eq!(
+            format_preference(720, false),
+            "best[height<=?720]/bestvideo[height<=?720]+bestaudio/best"
+        );
+        assert_eq!(
+            format_preference(2160, false),
+            "best[height<=?2160]/bestvideo[height<=?2160]+bestaudio/best"
+        );
+        assert_eq!(format_preference(0, false), "best");
+        assert_eq!(format_preference(1080, true), "bestaudio/best");
+    }
+
+    #[test]
+    fn test_is_player_available() {
         // This test just checks that the function doesn't panic
         // Actual result depends on system configuration
-        let _ = is_mpv_available();
+        let _ = is_player_available("mpv");
+        assert!(!is_player_available("yt-tui-no-such-player"));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_find_in_path() {
+        use std::os::unix::fs::PermissionsExt;
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let script = temp_dir.path().join("yt-tui-player");
+        std::fs::write(&script, "#!/bin/sh\nexit 1\n").unwrap();
+        let path_var = std::env::join_paths(["/nonexistent".as_ref(), temp_dir.path()]).unwrap();
+
+        // Not executable yet
+        assert!(!find_in_path("yt-tui-player", Some(&path_var)));
+        std::fs::set_permissions(&script, std::fs::Permissions::from_mode(0o755)).unwrap();
+        assert!(find_in_path("yt-tui-player", Some(&path_var)));
+        assert!(find_in_path(script.to_str().unwrap(), None));
+        assert!(!find_in_path("yt-tui-player", None));
+        assert!(!find_in_path("", Some(&path_var)));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_custom_player_command() {
+        let args = vec!["--flag".to_string()];
+        let options = PlayOptions {
+            command: "true",
+            player_args: &args,
+            extra_args: &[],
+            audio_only: false,
+            max_resolution: 1080,
+        };
+        assert!(open_in_player("https://www.youtube.com/watch?v=x", options).is_ok());
+
+        let options = PlayOptions {
+            command: "yt-tui-no-such-player",
+            ..options
+        };
+        let err = open_in_player("https://www.youtube.com/watch?v=x", options).unwrap_err();
+        assert!(err.to_string().contains("yt-tui-no-such-player"));
     }
 }
@@ -0,0 +1,319 @@
+//! Local filter query parsing.
+//!
+//! Splits a filter query into free text and `key:value` operators such as
+//! `channel:jon before:2024 longer:10m`.
+
+use crate::app::parse_human_duration;
+use crate::youtube::Video;
+use chrono::{DateTime, NaiveDate, Utc};
+use fuzzy_matcher::FuzzyMatcher;
+use fuzzy_matcher::skim::SkimMatcherV2;
+use std::sync::LazyLock;
+
+/// Shared fuzzy matcher for the free text (case-insensitive).
+static MATCHER: LazyLock<SkimMatcherV2> = LazyLock::new(|| SkimMatcherV2::default().ignore_case());
+
+/// Filter query split into free text and structured operators.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ParsedQuery {
+    /// Remaining free text (lowercased), fuzzy matched against title and channel,
+    /// or found in the description
+    pub text: String,
+    /// `channel:` - channel name must contain this (lowercased)
+    pub channel: Option<String>,
+    /// `before:` - published strictly before this date
+    pub before: Option<DateTime<Utc>>,
+    /// `after:` - published on or after this date
+    pub after: Option<DateTime<Utc>>,
+    /// `longer:` - duration of at least this many seconds
+    pub longer: Option<u64>,
+    /// `shorter:` - duration of at most this many seconds
+    pub shorter: Option<u64>,
+}
+
+impl ParsedQuery {
+    /// Parse a filter query.
+    ///
+    /// # Arguments
+    /// * `query` - Raw query, e.g. `rust channel:jon before:2024 longer:10m`
+    ///
+    /// # Returns
+    /// * `ParsedQuery` - Parsed query
+    ///
+    /// # Details
+    /// Supported operators:
+    /// - `channel:<name>` - partial, case-insensitive channel match
+    /// - `before:<date>` / `after:<date>` - `YYYY`, `YYYY-MM` or `YYYY-MM-DD`
+    /// - `longer:<duration>` / `shorter:<duration>` - e.g. `90s`, `10m`, `1h30m`
+    ///
+    /// Unknown operators and values that don't parse are kept as free text.
+    pub fn parse(query: &str) -> Self {
+        let mut parsed = ParsedQuery::default();
+        let mut words = Vec::new();
+
+        for token in query.split_whitespace() {
+            let Some((key, value)) = token.split_once(':') else {
+                words.push(token);
+                continue;
+            };
+            let recognized = match key.to_lowercase().as_str() {
+                "channel" if !value.is_empty() => {
+                    parsed.channel = Some(value.to_lowercase());
+                    true
+                }
+                "before" => parse_date(value).map(|d| parsed.before = Some(d)).is_some(),
+                "after" => parse_date(value).map(|d| parsed.after = Some(d)).is_some(),
+                "longer" => parse_human_duration(value)
+                    .map(|d| parsed.longer = Some(d))
+                    .is_some(),
+                "shorter" => parse_human_duration(value)
+                    .map(|d| parsed.shorter = Some(d))
+                    .is_some(),
+                _ => false,
+            };
+            if !recognized {
+                words.push(token);
+            }
+        }
+
+        parsed.text = words.join(" ").to_lowercase();
+        parsed
+    }
+
+    /// Check if any operator was given.
+    ///
+    /// # Returns
+    /// * `bool` - True if at least one operator was recognized
+    pub fn has_operators(&self) -> bool {
+        self.channel.is_some()
+            || self.before.is_some()
+            || self.after.is_some()
+            || self.longer.is_some()
+            || self.shorter.is_some()
+    }
+
+    /// Check if a video matches the query.
+    ///
+    /// # Arguments
+    /// * `video` - Video to test
+    ///
+    /// # Returns
+    /// * `bool` - True if the free text and all operators match
+    pub fn matches(&self, video: &Video) -> bool {
+        if self.text_score(video).is_none() {
+            return false;
+        }
+        if let Some(ref channel) = self.channel
+            && !video.channel.to_lowercase().contains(channel)
+        {
+            return false;
+        }
+        if self
+            .before
+            .is_some_and(|before| video.published_at >= before)
+        {
+            return false;
+        }
+        if self.after.is_some_and(|after| video.published_at < after) {
+            return false;
+        }
+        if self.longer.is_some_and(|longer| video.duration < longer) {
+            return false;
+        }
+        if self.shorter.is_some_and(|shorter| video.duration > shorter) {
+            return false;
+        }
+        true
+    }
+
+    /// Score how well the free text matches a video.
+    ///
+    /// # Arguments
+    /// * `video` - Video to score
+    ///
+    /// # Returns
+    /// * `Option<i64>` - Match score (higher is better), or None if it doesn't match
+    ///
+    /// # Details
+    /// The text is a fuzzy subsequence match on the title and channel, so
+    /// "rsttut" finds "Rust Tutorial"; matches at word starts score higher.
+    /// A plain substring of the description matches with score 0 (fuzzy
+    /// matching long descriptions would match almost anything). Empty text
+    /// matches everything with score 0.
+    pub fn text_score(&self, video: &Video) -> Option<i64> {
+        if self.text.is_empty() {
+            return Some(0);
+        }
+        [&video.title, &video.channel]
+            .into_iter()
+            .filter_map(|field| MATCHER.fuzzy_match(field, &self.text))
+            .max()
+            .or_else(|| {
+                video
+                    .description
+                    .to_lowercase()
+                    .contains(&self.text)
+                    .then_some(0)
+            })
+    }
+
+    /// Find the title characters matched by the free text.
+    ///
+    /// # Arguments
+    /// * `title` - Video title
+    ///
+    /// # Returns
+    /// * `Vec<usize>` - Ascending char indices of the matched characters
+    ///   (empty without free text or when only the channel or description matched)
+    ///
+    /// # Details
+    /// Uses the same case-insensitive fuzzy matcher as `text_score`, so the
+    /// highlighted characters are the ones that made the title match.
+    pub fn title_match_indices(&self, title: &str) -> Vec<usize> {
+        if self.text.is_empty() {
+            return Vec::new();
+        }
+        MATCHER
+            .fuzzy_indices(title, &self.text)
+            .map(|(_, indices)| indices)
+            .unwrap_or_default()
+    }
+}
+
+/// Parse a date operator value to the start of that period (UTC).
+///
+/// # Arguments
+/// * `value` - `YYYY`, `YYYY-MM` or `YYYY-MM-DD`
+///
+/// # Returns
+/// * `Option<DateTime<Utc>>` - Midnight at the start of the period
+fn parse_date(value: &str) -> Option<DateTime<Utc>> {
+    let parts: Vec<&str> = value.split('-').collect();
+    let year: i32 = parts.first()?.parse().ok()?;
+    let month: u32 = match parts.get(1) {
+        Some(m) => m.parse().ok()?,
+        None => 1,
+    };
+    let day: u32 = match parts.get(2) {
+        Some(d) => d.parse().ok()?,
+        None => 1,
+    };
+    if parts.len() > 3 {
+        return None;
+    }
+    let date = NaiveDate::from_ymd_opt(year, month, day)?;
+    Some(date.and_hms_opt(0, 0, 0)?.and_utc())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn video(title: &str, channel: &str, duration: u64, year: i32) -> Video {
+        Video::new(
+            "id".to_string(),
+            title.to_string(),
+            channel.to_string(),
+            "channel_id".to_string(),
+            "Description".to_string(),
+            duration,
+            Utc.with_ymd_and_hms(year, 6, 1, 0, 0, 0).unwrap(),
+            "thumb".to_string(),
+            1000,
+        )
+    }
+
+    #[test]
+    fn test_parse_plain_text() {
+        let parsed = ParsedQuery::parse("Rust  Tutorial");
+        assert_eq!(parsed.text, "rust tutorial");
+        assert_eq!(parsed.channel, None);
+        assert!(!parsed.has_operators());
+    }
+
+    #[test]
+    fn test_fuzzy_text() {
+        let parsed = ParsedQuery::parse("rsttut");
+        assert!(parsed.matches(&video("Rust Tutorial", "C", 60, 2024)));
+        assert!(!parsed.matches(&video("Python Basics", "C", 60, 2024)));
+        // Description text only matches as a substring
+        assert!(ParsedQuery::parse("descr").matches(&video("T", "C", 60, 2024)));
+        assert!(!ParsedQuery::parse("dsc").matches(&video("T", "C", 60, 2024)));
+    }
+
+    #[test]
+    fn test_title_match_indices() {
+        let parsed = ParsedQuery::parse("RUST");
+        assert_eq!(parsed.title_match_indices("Learn Rust today"), [6, 7, 8, 9]);
+        assert_eq!(
+            ParsedQuery::parse("rsttut").title_match_indices("Rust Tutorial"),
+            [0, 2, 3, 5, 6, 7]
+        );
+        assert!(parsed.title_match_indices("Python").is_empty());
+        assert!(
+            ParsedQuery::parse("")
+                .title_match_indices("Rust")
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_fuzzy_score_prefers_title_start() {
+        let parsed = ParsedQuery::parse("rust");
+        let start = parsed.text_score(&video("Rust in production", "C", 60, 2024));
+        let mid = parsed.text_score(&video("Distrust everything", "C", 60, 2024));
+        assert!(start.unwrap() > mid.unwrap());
+    }
+
+    #[test]
+    fn test_parse_channel() {
+        let parsed = ParsedQuery::parse("channel:Jon");
+        assert_eq!(parsed.channel.as_deref(), Some("jon"));
+        assert!(parsed.matches(&video("Anything", "Jon Gjengset", 60, 2024)));
+        assert!(!parsed.matches(&video("Anything", "Other", 60, 2024)));
+    }
+
+    #[test]
+    fn test_parse_dates() {
+        let parsed = ParsedQuery::parse("before:2024 after:2022-03");
+        assert_eq!(
+            parsed.before,
+            Some(Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap())
+        );
+        assert_eq!(
+            parsed.after,
+            Some(Utc.with_ymd_and_hms(2022, 3, 1, 0, 0, 0).unwrap())
+        );
+        assert!(parsed.matches(&video("T", "C", 60, 2023)));
+        assert!(!parsed.matches(&video("T", "C", 60, 2024)));
+        assert!(!parsed.matches(&video("T", "C", 60, 2021)));
+        assert!(ParsedQuery::parse("before:2024-02-30").before.is_none());
+    }
+
+    #[test]
+    fn test_parse_durations() {
+        let parsed = ParsedQuery::parse("longer:10m shorter:1h");
+        assert_eq!(parsed.longer, Some(600));
+        assert_eq!(parsed.shorter, Some(3600));
+        assert!(parsed.matches(&video("T", "C", 1200, 2024)));
+        assert!(!parsed.matches(&video("T", "C", 300, 2024)));
+        assert!(!parsed.matches(&video("T", "C", 7200, 2024)));
+    }
+
+    #[test]
+    fn test_parse_combined() {
+        let parsed = ParsedQuery::parse("rust channel:jon before:2024 longer:10m");
+        assert_eq!(parsed.text, "rust");
+        assert!(parsed.matches(&video("Crust of Rust", "Jon Gjengset", 3600, 2023)));
+        assert!(!parsed.matches(&video("Crust of Rust", "Jon Gjengset", 3600, 2024)));
+        assert!(!parsed.matches(&video("Python", "Jon Gjengset", 3600, 2023)));
+    }
+
+    #[test]
+    fn test_parse_unknown_operator_is_text() {
+        let parsed = ParsedQuery::parse("http://example longer:soon");
+        assert_eq!(parsed.text, "http://example longer:soon");
+        assert_eq!(parsed.longer, None);
+    }
+}
@@ -0,0 +1,173 @@
+//! "Watch later" list of saved videos.
+//!
+//! Persists bookmarked video IDs to a JSON file, separate from the watch history.
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+
+/// Videos saved for later.
+///
+/// Keeps video IDs in the order they were saved (most recent first).
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct SavedList {
+    /// Saved video IDs (most recent first)
+    #[serde(default)]
+    video_ids: Vec<String>,
+    /// Changed since the last save
+    #[serde(skip)]
+    dirty: bool,
+}
+
+impl SavedList {
+    /// Load the saved list from file.
+    ///
+    /// # Arguments
+    /// * `path` - Path to the saved list JSON file
+    ///
+    /// # Returns
+    /// * `Result<SavedList>` - Loaded list or error
+    ///
+    /// # Details
+    /// If the file doesn't exist, returns an empty list.
+    pub fn load(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        let content = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read saved list: {}", path.display()))?;
+        serde_json::from_str(&content).with_context(|| "Failed to parse saved list")
+    }
+
+    /// Save the list to file.
+    ///
+    /// # Arguments
+    /// * `path` - Path to the saved list JSON file
+    ///
+    /// # Returns
+    /// * `Result<()>` - Success or error
+    ///
+    /// # Details
+    /// Creates parent directory if it doesn't exist. Clears the dirty flag on success.
+    pub fn save(&mut self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).with_context(|| {
+                format!(
+                    "Failed to create saved list directory: {}",
+                    parent.display()
+                )
+            })?;
+        }
+
+        let json = serde_json::to_string_pretty(self).context("Failed to serialize saved list")?;
+        fs::write(path, json)
+            .with_context(|| format!("Failed to write saved list: {}", path.display()))?;
+
+        self.dirty = false;
+        Ok(())
+    }
+
+    /// Save the list if it changed since the last save.
+    ///
+    /// # Arguments
+    /// * `path` - Path to the saved list JSON file
+    ///
+    /// # Returns
+    /// * `Result<bool>` - Whether the file was written
+    pub fn flush(&mut self, path: &Path) -> Result<bool> {
+        if !self.dirty {
+            return Ok(false);
+        }
+        self.save(path)?;
+        Ok(true)
+    }
+
+    /// Save a video for later.
+    ///
+    /// # Arguments
+    /// * `video_id` - YouTube video ID
+    ///
+    /// # Returns
+    /// * `bool` - False if the video was already saved
+    pub fn add(&mut self, video_id: &str) -> bool {
+        if self.contains(video_id) {
+            return false;
+        }
+        self.video_ids.insert(0, video_id.to_string());
+        self.dirty = true;
+        true
+    }
+
+    /// Remove a video from the list.
+    ///
+    /// # Arguments
+    /// * `video_id` - YouTube video ID
+    ///
+    /// # Returns
+    /// * `bool` - False if the video wasn't saved
+    pub fn remove(&mut self, video_id: &str) -> bool {
+        let before = self.video_ids.len();
+        self.video_ids.retain(|id| id != video_id);
+        let removed = self.video_ids.len() != before;
+        self.dirty |= removed;
+        removed
+    }
+
+    /// Check if a video is saved.
+    ///
+    /// # Arguments
+    /// * `video_id` - YouTube video ID
+    ///
+    /// # Returns
+    /// * `bool` - True if the video is on the list
+    pub fn contains(&self, video_id: &str) -> bool {
+        self.video_ids.iter().any(|id| id == video_id)
+    }
+
+    /// Get the saved video IDs.
+    ///
+    /// # Returns
+    /// * `&[String]` - IDs, most recently saved first
+    pub fn ids(&self) -> &[String] {
+        &self.video_ids
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_add_remove_contains() {
+        let mut saved = SavedList::default();
+        assert!(saved.add("a"));
+        assert!(saved.add("b"));
+        assert!(!saved.add("a"));
+        assert_eq!(saved.ids(), ["b", "a"]);
+        assert!(saved.contains("a"));
+
+        assert!(saved.remove("a"));
+        assert!(!saved.remove("a"));
+        assert!(!saved.contains("a"));
+    }
+
+    #[test]
+    fn test_save_and_load() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("saved.json");
+        assert!(SavedList::load(&path).unwrap().ids().is_empty());
+
+        let mut saved = SavedList::default();
+        assert!(!saved.flush(&path).unwrap());
+        saved.add("a");
+        saved.add("b");
+        assert!(saved.flush(&path).unwrap());
+        assert!(!saved.flush(&path).unwrap());
+
+        let loaded = SavedList::load(&path).unwrap();
+        assert_eq!(loaded.ids(), ["b", "a"]);
+    }
+}
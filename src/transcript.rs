@@ -0,0 +1,206 @@
+//! Video transcript fetching.
+//!
+//! Downloads captions with yt-dlp and converts WebVTT to plain text.
+
+use anyhow::{Context, Result};
+use std::fs;
+use std::process::Command;
+
+/// Fetch the transcript of a video as plain text.
+///
+/// # Arguments
+/// * `video_id` - YouTube video ID
+///
+/// # Returns
+/// * `Result<String>` - Transcript text or error
+///
+/// # Details
+/// Runs `yt-dlp --skip-download` to write manual or auto-generated English
+/// subtitles in VTT format to a temporary directory, then parses the file.
+/// Blocks until yt-dlp exits, so callers should run it off the UI thread.
+pub fn fetch_transcript(video_id: &str) -> Result<String> {
+    let temp_dir = std::env::temp_dir().join(format!(
+        "yt-tui-transcript-{}-{}",
+        std::process::id(),
+        video_id
+    ));
+    fs::create_dir_all(&temp_dir).with_context(|| {
+        format!(
+            "Failed to create transcript directory: {}",
+            temp_dir.display()
+        )
+    })?;
+
+    let result = download_and_parse(video_id, &temp_dir);
+    let _ = fs::remove_dir_all(&temp_dir);
+    result
+}
+
+/// Download subtitles into a directory and parse the first VTT file found.
+///
+/// # Arguments
+/// * `video_id` - YouTube video ID
+/// * `dir` - Directory to write subtitle files into
+///
+/// # Returns
+/// * `Result<String>` - Transcript text or error
+fn download_and_parse(video_id: &str, dir: &std::path::Path) -> Result<String> {
+    let output = Command::new("yt-dlp")
+        .arg("--skip-download")
+        .arg("--write-subs")
+        .arg("--write-auto-subs")
+        .arg("--sub-langs")
+        .arg("en.*,en")
+        .arg("--sub-format")
+        .arg("vtt")
+        .arg("-o")
+        .arg(dir.join("%(id)s").to_string_lossy().to_string())
+        .arg(format!("https://www.youtube.com/watch?v={}", video_id))
+        .output()
+        .context("Failed to run yt-dlp. Make sure yt-dlp is installed")?;
+
+    if !output.status.success() {
+        return Err(anyhow::anyhow!(
+            "yt-dlp failed: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+
+    let vtt_path = fs::read_dir(dir)
+        .with_context(|| format!("Failed to read directory: {}", dir.display()))?
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .find(|path| path.extension().is_some_and(|ext| ext == "vtt"))
+        .ok_or_else(|| anyhow::anyhow!("No captions available for this video"))?;
+
+    let content = fs::read_to_string(&vtt_path)
+        .with_context(|| format!("Failed to read captions: {}", vtt_path.display()))?;
+
+    let text = parse_vtt(&content);
+    if text.is_empty() {
+        return Err(anyhow::anyhow!("Captions for this video are empty"));
+    }
+    Ok(text)
+}
+
+/// Convert WebVTT captions to plain text.
+///
+/// # Arguments
+/// * `vtt` - WebVTT file content
+///
+/// # Returns
+/// * `String` - Caption text, one line per caption line
+///
+/// # Details
+/// Drops the header, NOTE/STYLE blocks, cue identifiers (numbers right
+/// before a timing line; other numbers are caption text) and timing lines,
+/// strips inline tags (`<c>`, `<00:00:01.000>`), decodes common entities and
+/// removes consecutive duplicate lines produced by rolling auto-captions.
+pub fn parse_vtt(vtt: &str) -> String {
+    let mut lines: Vec<String> = Vec::new();
+    let mut in_header = true;
+    let mut skip_block = false;
+
+    let mut raw_lines = vtt.lines().peekable();
+    while let Some(raw_line) = raw_lines.next() {
+        let line = raw_line.trim();
+
+        if line.is_empty() {
+            in_header = false;
+            skip_block = false;
+            continue;
+        }
+        if in_header || skip_block {
+            continue;
+        }
+        if line.starts_with("NOTE") || line == "STYLE" || line == "REGION" {
+            skip_block = true;
+            continue;
+        }
+        let is_cue_number = line.chars().all(|c| c.is_ascii_digit())
+            && raw_lines.peek().is_some_and(|next| next.contains("-->"));
+        if line.contains("-->") || is_cue_number {
+            continue;
+        }
+
+        let text = decode_entities(&strip_tags(line));
+        let text = text.trim();
+        if text.is_empty() || lines.last().is_some_and(|last| last == text) {
+            continue;
+        }
+        lines.push(text.to_string());
+    }
+
+    lines.join("\n")
+}
+
+/// Remove `<...>` tags from a caption line.
+///
+/// # Arguments
+/// * `line` - Caption line
+///
+/// # Returns
+/// * `String` - Line without tags
+fn strip_tags(line: &str) -> String {
+    let mut result = String::with_capacity(line.len());
+    let mut in_tag = false;
+    for ch in line.chars() {
+        match ch {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            _ if !in_tag => result.push(ch),
+            _ => {}
+        }
+    }
+    result
+}
+
+/// Decode the HTML entities used in WebVTT.
+///
+/// # Arguments
+/// * `text` - Text containing entities
+///
+/// # Returns
+/// * `String` - Decoded text
+fn decode_entities(text: &str) -> String {
+    text.replace("&nbsp;", " ")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_vtt() {
+        let vtt = "WEBVTT\nKind: captions\nLanguage: en\n\n\
+            NOTE generated by YouTube\n\n\
+            1\n00:00:00.000 --> 00:00:02.000 align:start position:0%\n\
+            hello<00:00:00.500><c> world</c>\n\n\
+            2\n00:00:02.000 --> 00:00:04.000\n\
+            hello world\nrock &amp; roll\n\n\
+            00:00:04.000 --> 00:00:06.000\n\
+            rock &amp; roll\n";
+
+        assert_eq!(parse_vtt(vtt), "hello world\nrock & roll");
+    }
+
+    #[test]
+    fn test_parse_vtt_keeps_numbers_in_text() {
+        let vtt = "WEBVTT\n\n\
+            7\n00:00:00.000 --> 00:00:02.000\n\
+            the year was\n2024\n\n\
+            00:00:02.000 --> 00:00:04.000\n\
+            42\n";
+
+        assert_eq!(parse_vtt(vtt), "the year was\n2024\n42");
+    }
+
+    #[test]
+    fn test_parse_vtt_empty() {
+        assert_eq!(parse_vtt("WEBVTT\n\n"), "");
+    }
+}
@@ -0,0 +1,58 @@
+//! Chapter list popup rendering.
+//!
+//! Displays the chapters of the video playing in MPV.
+
+use crate::app::App;
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Widget},
+};
+
+/// Render the chapter list popup.
+///
+/// # Arguments
+/// * `app` - Application state
+/// * `area` - Area to render in (drawn over the list)
+/// * `buf` - Buffer to render to
+///
+/// # Details
+/// Shows each chapter's start time and title, highlighting the selected one.
+/// Scrolls so the selection stays visible.
+pub fn render_chapters(app: &App, area: Rect, buf: &mut Buffer) {
+    let inner_height = area.height.saturating_sub(2) as usize;
+    let offset = (app.chapter_index + 1).saturating_sub(inner_height);
+
+    let lines: Vec<Line> = app
+        .chapters
+        .iter()
+        .enumerate()
+        .skip(offset)
+        .map(|(index, chapter)| {
+            let style = if index == app.chapter_index {
+                Style::default()
+                    .fg(Color::Black)
+                    .bg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(Color::White)
+            };
+            Line::from(vec![
+                Span::styled(format!("{:>8}  ", chapter.display_time()), style),
+                Span::styled(chapter.title.clone(), style),
+            ])
+        })
+        .collect();
+
+    let paragraph = Paragraph::new(lines).block(
+        Block::default()
+            .title("Chapters (Enter to jump, 'Esc' or 'P' to close)")
+            .borders(Borders::ALL)
+            .style(Style::default().fg(Color::Yellow)),
+    );
+
+    Widget::render(Clear, area, buf);
+    Widget::render(paragraph, area, buf);
+}
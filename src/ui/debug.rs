@@ -0,0 +1,68 @@
+//! Debug overlay rendering.
+//!
+//! Displays raw API values of the selected video next to the parsed ones.
+
+use crate::app::App;
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Widget, Wrap},
+};
+
+/// Render the debug overlay.
+///
+/// # Arguments
+/// * `app` - Application state
+/// * `area` - Area to render in (drawn over the list)
+/// * `buf` - Buffer to render to
+///
+/// # Details
+/// Shows the raw duration and published strings kept in `--debug` mode, the
+/// values parsed from them and the other computed fields of the selected video.
+pub fn render_debug(app: &App, area: Rect, buf: &mut Buffer) {
+    let field = |label: &'static str, value: String| {
+        Line::from(vec![
+            Span::styled(label, Style::default().fg(Color::Cyan)),
+            Span::styled(value, Style::default().fg(Color::White)),
+        ])
+    };
+    let raw = |value: &Option<String>| {
+        value
+            .as_deref()
+            .map_or("(not kept)".to_string(), |v| format!("{:?}", v))
+    };
+
+    let lines = match app.selected_video_from_tab() {
+        Some(video) => vec![
+            field("ID: ", video.id.clone()),
+            field("Raw duration: ", raw(&video.raw_duration)),
+            field(
+                "Parsed duration: ",
+                format!("{} s ({})", video.duration, video.format_duration()),
+            ),
+            field("Raw published: ", raw(&video.raw_published_at)),
+            field("Parsed published: ", video.published_at.to_rfc3339()),
+            field("Views: ", video.view_count.to_string()),
+            field("Channel ID: ", video.channel_id.clone()),
+            field("Thumbnail: ", video.thumbnail_url.clone()),
+            field("URL: ", video.url.clone()),
+            field("Details missing: ", video.details_missing.to_string()),
+        ],
+        None => vec![Line::styled(
+            "No video selected",
+            Style::default().fg(Color::Gray),
+        )],
+    };
+
+    let paragraph = Paragraph::new(lines).wrap(Wrap { trim: false }).block(
+        Block::default()
+            .title("Debug (press 'Esc' to close)")
+            .borders(Borders::ALL)
+            .style(Style::default().fg(Color::Yellow)),
+    );
+
+    Widget::render(Clear, area, buf);
+    Widget::render(paragraph, area, buf);
+}
@@ -0,0 +1,163 @@
+//! Video detail popup rendering.
+//!
+//! Displays the full description of the selected video with its metadata.
+
+use crate::app::App;
+use crate::ui::list::wrap_text;
+use crate::ui::status::truncate_to_width;
+use crate::youtube::Video;
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Widget, Wrap},
+};
+
+/// Lines above the description: title, channel, published, URL and a blank line.
+const HEADER_LINES: u16 = 5;
+
+/// Render the detail popup.
+///
+/// # Arguments
+/// * `app` - Application state
+/// * `area` - Area to render in (drawn over the list)
+/// * `buf` - Buffer to render to
+///
+/// # Details
+/// Shows the title, channel, publish date and URL of the selected video, with
+/// its description wrapped below. A long title is cut to one line so the
+/// header keeps its `HEADER_LINES`. Only the description scrolls
+/// (`detail_scroll`, clamped to `max_detail_scroll`).
+pub fn render_detail(app: &App, area: Rect, buf: &mut Buffer) {
+    let block = Block::default()
+        .title("Details (j/k to scroll, Esc to close)")
+        .borders(Borders::ALL)
+        .style(Style::default().fg(Color::Yellow));
+    let inner = block.inner(area);
+    Widget::render(Clear, area, buf);
+    Widget::render(block, area, buf);
+
+    let Some(video) = app.selected_video_from_tab() else {
+        Widget::render(
+            Paragraph::new(Line::styled(
+                "No video selected",
+                Style::default().fg(Color::Gray),
+            )),
+            inner,
+            buf,
+        );
+        return;
+    };
+
+    let field = |label: &'static str, value: String| {
+        Line::from(vec![
+            Span::styled(label, Style::default().fg(Color::Cyan)),
+            Span::styled(value, Style::default().fg(Color::White)),
+        ])
+    };
+    let header = vec![
+        Line::styled(
+            truncate_to_width(&video.title, inner.width as usize),
+            Style::default()
+                .fg(Color::White)
+                .add_modifier(Modifier::BOLD),
+        ),
+        field("Channel: ", video.channel.clone()),
+        field(
+            "Published: ",
+            video.published_at.format("%Y-%m-%d %H:%M UTC").to_string(),
+        ),
+        field("URL: ", app.video_url(video)),
+    ];
+    let header_area = Rect {
+        height: HEADER_LINES.min(inner.height),
+        ..inner
+    };
+    Widget::render(
+        Paragraph::new(header).wrap(Wrap { trim: false }),
+        header_area,
+        buf,
+    );
+
+    let body_area = Rect {
+        y: inner.y + header_area.height,
+        height: inner.height - header_area.height,
+        ..inner
+    };
+    let scroll = app.detail_scroll.min(max_detail_scroll(video, area));
+    let lines: Vec<Line> = description_lines(video, body_area.width)
+        .into_iter()
+        .skip(scroll as usize)
+        .take(body_area.height as usize)
+        .map(|line| Line::styled(line, Style::default().fg(Color::Gray)))
+        .collect();
+    Widget::render(Paragraph::new(lines), body_area, buf);
+}
+
+/// Get the largest useful description scroll offset.
+///
+/// # Arguments
+/// * `video` - Video shown in the popup
+/// * `area` - Area the popup is drawn in
+///
+/// # Returns
+/// * `u16` - Offset at which the end of the description reaches the bottom
+pub fn max_detail_scroll(video: &Video, area: Rect) -> u16 {
+    let inner_width = area.width.saturating_sub(2);
+    let body_height = area.height.saturating_sub(2 + HEADER_LINES);
+    let total = description_lines(video, inner_width).len();
+    total.saturating_sub(body_height as usize) as u16
+}
+
+/// Wrap the description to the popup width.
+///
+/// # Arguments
+/// * `video` - Video whose description is shown
+/// * `width` - Available width in columns
+///
+/// # Returns
+/// * `Vec<String>` - Wrapped lines; blank lines between paragraphs are kept
+fn description_lines(video: &Video, width: u16) -> Vec<String> {
+    if video.description.trim().is_empty() {
+        return vec!["(no description)".to_string()];
+    }
+    video
+        .description
+        .lines()
+        .flat_map(|line| wrap_text(line, width as usize))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_max_detail_scroll() {
+        let mut video = Video::id_only("vid".to_string(), chrono::Utc::now());
+        video.description = "one two three four\n\nfive".to_string();
+        // 10 wide inside the borders: "one two", "three four", "", "five"
+        assert_eq!(description_lines(&video, 10).len(), 4);
+        // Room for 2 description lines (2 borders + 5 header lines)
+        assert_eq!(max_detail_scroll(&video, Rect::new(0, 0, 12, 9)), 2);
+        // Everything fits
+        assert_eq!(max_detail_scroll(&video, Rect::new(0, 0, 12, 20)), 0);
+    }
+
+    #[test]
+    fn test_long_title_keeps_header() {
+        let mut app = App::new(crate::history::History::default(), false);
+        let mut video = crate::youtube::models::test_video("vid");
+        video.title = "A very long title that needs more than one line".to_string();
+        video.channel = "Channel".to_string();
+        app.set_videos(vec![video]);
+
+        let area = Rect::new(0, 0, 30, 12);
+        let mut buf = Buffer::empty(area);
+        render_detail(&app, area, &mut buf);
+        let row = |y: u16| -> String { (1..29).map(|x| buf[(x, y)].symbol()).collect() };
+        assert!(row(1).starts_with("A very long title that need…"));
+        assert!(row(2).starts_with("Channel: Channel"));
+    }
+}
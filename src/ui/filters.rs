@@ -2,13 +2,14 @@
 //!
 //! Displays filter controls and current filter settings.
 
-use crate::app::App;
+use crate::app::{App, FilterField, format_human_duration};
+use crate::youtube::format_count;
 use ratatui::{
     buffer::Buffer,
     layout::Rect,
-    style::{Color, Style},
+    style::{Color, Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, Paragraph, Widget},
+    widgets::{Block, Borders, Clear, Paragraph, Widget},
 };
 
 /// Render the filters widget.
@@ -22,8 +23,9 @@ use ratatui::{
 /// Displays current filter settings including:
 /// - Channel filter
 /// - Duration filters
+/// - View count filters
 /// - Date filter
-/// - Hide watched toggle
+/// - Hide watched and hide Shorts toggles
 pub fn render_filters(app: &App, area: Rect, buf: &mut Buffer) {
     let is_active = app.mode == crate::app::UiMode::Filters;
     let mut lines = vec![];
@@ -41,12 +43,12 @@ pub fn render_filters(app: &App, area: Rect, buf: &mut Buffer) {
         let min = app
             .filters
             .min_duration
-            .map(|d| format!("{}s", d))
+            .map(format_human_duration)
             .unwrap_or_else(|| "0s".to_string());
         let max = app
             .filters
             .max_duration
-            .map(|d| format!("{}s", d))
+            .map(format_human_duration)
             .unwrap_or_else(|| "∞".to_string());
         lines.push(Line::from(vec![
             Span::styled("Duration: ", Style::default().fg(Color::Cyan)),
@@ -57,6 +59,20 @@ pub fn render_filters(app: &App, area: Rect, buf: &mut Buffer) {
         ]));
     }
 
+    // View count filters
+    if app.filters.min_views.is_some() || app.filters.max_views.is_some() {
+        let count = |views| format_count(views, app.number_style, &app.thousands_separator);
+        let min = app.filters.min_views.map_or_else(|| "0".to_string(), count);
+        let max = app.filters.max_views.map_or_else(|| "∞".to_string(), count);
+        lines.push(Line::from(vec![
+            Span::styled("Views: ", Style::default().fg(Color::Cyan)),
+            Span::styled(
+                format!("{} - {}", min, max),
+                Style::default().fg(Color::White),
+            ),
+        ]));
+    }
+
     // Date filter
     if let Some(ref date) = app.filters.after_date {
         lines.push(Line::from(vec![
@@ -65,17 +81,18 @@ pub fn render_filters(app: &App, area: Rect, buf: &mut Buffer) {
         ]));
     }
 
-    // Hide watched
+    // Hide watched and Shorts
+    let toggle = |enabled: bool| {
+        Span::styled(
+            if enabled { "Yes" } else { "No" },
+            Style::default().fg(if enabled { Color::Green } else { Color::Gray }),
+        )
+    };
     lines.push(Line::from(vec![
         Span::styled("Hide Watched: ", Style::default().fg(Color::Cyan)),
-        Span::styled(
-            if app.hide_watched { "Yes" } else { "No" },
-            Style::default().fg(if app.hide_watched {
-                Color::Green
-            } else {
-                Color::Gray
-            }),
-        ),
+        toggle(app.hide_watched),
+        Span::styled("  Hide Shorts: ", Style::default().fg(Color::Cyan)),
+        toggle(app.hide_shorts),
     ]));
 
     // Sort mode
@@ -94,7 +111,7 @@ pub fn render_filters(app: &App, area: Rect, buf: &mut Buffer) {
     // Add instruction line when active
     if is_active {
         lines.push(Line::from(Span::styled(
-            "Press 'h' to toggle hide watched, 's' to change sort, 'Esc' or 'f' to exit",
+            "Press '↑'/'↓' and 'Enter' to edit a filter, 'h'/'H' to toggle hide watched/Shorts, 's' to change sort, 'Esc' or 'f' to exit",
             Style::default().fg(Color::Yellow),
         )));
     }
@@ -116,3 +133,141 @@ pub fn render_filters(app: &App, area: Rect, buf: &mut Buffer) {
 
     Widget::render(paragraph, area, buf);
 }
+
+/// Render the filter editor popup (Filters mode).
+///
+/// # Arguments
+/// * `app` - Application state
+/// * `area` - List area; the popup covers its top so filtered results stay visible
+/// * `buf` - Buffer to render to
+///
+/// # Details
+/// Lists every editable filter with its current value and highlights the
+/// selected one. While a field is being edited, its value is replaced by the
+/// input with a cursor, followed by an input hint or the last parse error.
+pub fn render_filter_editor(app: &App, area: Rect, buf: &mut Buffer) {
+    let label_width = FilterField::ALL
+        .iter()
+        .map(|field| field.label().len())
+        .max()
+        .unwrap_or(0);
+    let selected = app.selected_filter_field();
+
+    let mut lines: Vec<Line> = FilterField::ALL
+        .iter()
+        .map(|&field| {
+            let is_selected = field == selected;
+            let label_style = if is_selected {
+                Style::default()
+                    .fg(Color::Yellow)
+                    .bg(Color::Blue)
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(Color::Cyan)
+            };
+            let mut spans = vec![Span::styled(
+                format!("{:<width$}  ", field.label(), width = label_width),
+                label_style,
+            )];
+
+            match app
+                .filter_input
+                .as_ref()
+                .filter(|input| input.field == field)
+            {
+                Some(input) => {
+                    spans.push(Span::styled(
+                        input.buffer.clone(),
+                        Style::default().fg(Color::White),
+                    ));
+                    spans.push(Span::styled("_", Style::default().fg(Color::Yellow)));
+                    let (note, color) = match input.error {
+                        Some(ref error) => (error.clone(), Color::Red),
+                        None => (field.hint().to_string(), Color::DarkGray),
+                    };
+                    spans.push(Span::styled(
+                        format!("  {}", note),
+                        Style::default().fg(color),
+                    ));
+                }
+                None => spans.push(match app.filter_value_text(field) {
+                    Some(value) => Span::styled(value, Style::default().fg(Color::White)),
+                    None => Span::styled("-", Style::default().fg(Color::DarkGray)),
+                }),
+            }
+            Line::from(spans)
+        })
+        .collect();
+
+    lines.push(Line::default());
+    lines.push(Line::from(Span::styled(
+        if app.filter_input.is_some() {
+            "'Enter' to apply (empty clears), 'Esc' to cancel"
+        } else {
+            "'↑'/'↓' to select, 'Enter' to edit"
+        },
+        Style::default().fg(Color::Gray),
+    )));
+
+    // Fields, a blank line, the key hints and the borders
+    let area = Rect {
+        height: area.height.min(lines.len() as u16 + 2),
+        ..area
+    };
+    let paragraph = Paragraph::new(lines).block(
+        Block::default()
+            .title("Edit filters")
+            .borders(Borders::ALL)
+            .style(Style::default().fg(Color::Yellow)),
+    );
+
+    Widget::render(Clear, area, buf);
+    Widget::render(paragraph, area, buf);
+}
+
+/// Render the active filters as a single summary line (focus mode).
+///
+/// # Arguments
+/// * `app` - Application state
+/// * `area` - One-row area to render in
+/// * `buf` - Buffer to render to
+pub fn render_filters_summary(app: &App, area: Rect, buf: &mut Buffer) {
+    let mut parts = Vec::new();
+    if let Some(ref channel) = app.filters.channel {
+        parts.push(format!("channel {}", channel));
+    }
+    if let Some(min) = app.filters.min_duration {
+        parts.push(format!(">= {}", format_human_duration(min)));
+    }
+    if let Some(max) = app.filters.max_duration {
+        parts.push(format!("<= {}", format_human_duration(max)));
+    }
+    if let Some(min) = app.filters.min_views {
+        parts.push(format!(
+            ">= {} views",
+            format_count(min, app.number_style, &app.thousands_separator)
+        ));
+    }
+    if let Some(max) = app.filters.max_views {
+        parts.push(format!(
+            "<= {} views",
+            format_count(max, app.number_style, &app.thousands_separator)
+        ));
+    }
+    if let Some(ref date) = app.filters.after_date {
+        parts.push(format!("after {}", date));
+    }
+    if app.hide_watched {
+        parts.push("hide watched".to_string());
+    }
+    if app.hide_shorts {
+        parts.push("hide Shorts".to_string());
+    }
+    parts.push(format!("sort {}", app.sort_mode_name()));
+
+    let line = Line::from(vec![
+        Span::styled("Filters: ", Style::default().fg(Color::Cyan)),
+        Span::styled(parts.join(", "), Style::default().fg(Color::Gray)),
+    ]);
+    Widget::render(Paragraph::new(line), area, buf);
+}
@@ -2,7 +2,9 @@
 //!
 //! Displays a scrollable list of videos with selection highlighting.
 
-use crate::app::App;
+use crate::app::{App, ItemRow, ScrollMode};
+use crate::ui::status::truncate_to_width;
+use crate::youtube::format_count;
 use ratatui::{
     buffer::Buffer,
     layout::Rect,
@@ -10,6 +12,617 @@ use ratatui::{
     text::{Line, Span},
     widgets::{Block, Borders, List, ListItem, ListState, StatefulWidget, Widget},
 };
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+
+/// Number of rows each video occupies at full detail (1 title + 4 info lines + 1 separator).
+pub const LINES_PER_VIDEO: u16 = 6;
+
+/// Highest detail level: title, creator, duration, upload date and views.
+pub const MAX_DETAIL_LEVEL: u8 = 5;
+
+/// Number of rows a video with a one-line title occupies at a detail level.
+///
+/// # Arguments
+/// * `detail_level` - Lines shown per video, 1 (title only) to `MAX_DETAIL_LEVEL`
+///
+/// # Returns
+/// * `u16` - Shown lines plus the separator
+pub fn lines_per_video(detail_level: u8) -> u16 {
+    u16::from(detail_level.clamp(1, MAX_DETAIL_LEVEL)) + 1
+}
+
+/// Number of rows a video with a one-line title occupies in the list.
+///
+/// # Arguments
+/// * `compact` - Whether the compact list (one line per video) is on
+/// * `detail_level` - Lines shown per video otherwise (see `lines_per_video`)
+///
+/// # Returns
+/// * `u16` - 1 in the compact list, `lines_per_video(detail_level)` otherwise
+pub fn list_item_height(compact: bool, detail_level: u8) -> u16 {
+    if compact {
+        1
+    } else {
+        lines_per_video(detail_level)
+    }
+}
+
+/// Colors used to tint channel names, all readable on dark and blue backgrounds.
+const CHANNEL_PALETTE: [Color; 10] = [
+    Color::Cyan,
+    Color::LightCyan,
+    Color::Green,
+    Color::LightGreen,
+    Color::Yellow,
+    Color::LightYellow,
+    Color::Magenta,
+    Color::LightMagenta,
+    Color::LightRed,
+    Color::LightBlue,
+];
+
+/// Map a channel ID to a stable color.
+///
+/// # Arguments
+/// * `channel_id` - YouTube channel ID
+///
+/// # Returns
+/// * `Color` - Palette color for the channel
+///
+/// # Details
+/// Uses FNV-1a so the mapping is identical across runs and Rust versions.
+pub fn channel_color(channel_id: &str) -> Color {
+    let hash = channel_id
+        .bytes()
+        .fold(0xcbf2_9ce4_8422_2325u64, |hash, byte| {
+            (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+        });
+    CHANNEL_PALETTE[(hash % CHANNEL_PALETTE.len() as u64) as usize]
+}
+
+/// Count the videos that fit in the list at once.
+///
+/// # Arguments
+/// * `available_height` - Rows available inside the list borders
+/// * `item_height` - Rows per video (see `lines_per_video`)
+///
+/// # Returns
+/// * `usize` - Whole videos that fit (at least 1)
+pub fn visible_video_count(available_height: u16, item_height: u16) -> usize {
+    (available_height / item_height.max(1)).max(1) as usize
+}
+
+/// Get the number of videos one page of the list holds.
+///
+/// # Arguments
+/// * `area_height` - Height of the list widget including its borders, less
+///   the rows under the preview pane (see `preview::covered_rows`)
+/// * `heights` - Row height of every entry (see `list_heights`)
+/// * `selected_index` - Index of the selected entry
+///
+/// # Returns
+/// * `usize` - Page size for PageUp/PageDown (at least 1)
+///
+/// # Details
+/// Counts the entries shown around the selection (see
+/// `visible_range_for_heights`), so taller items, e.g. videos with a reason
+/// row, make the page smaller.
+pub fn page_size(area_height: u16, heights: &[u16], selected_index: usize) -> usize {
+    let (start, end) =
+        visible_range_for_heights(selected_index, heights, area_height.saturating_sub(2));
+    (end - start).max(1)
+}
+
+/// Get the row height of every entry in the list.
+///
+/// # Arguments
+/// * `app` - Application state
+/// * `inner_width` - Columns available inside the list borders
+///
+/// # Returns
+/// * `Vec<u16>` - Heights of the videos of the active tab (see `video_height`),
+///   or one row per recent search while those are shown
+pub fn list_heights(app: &App, inner_width: usize) -> Vec<u16> {
+    if app.showing_recent_searches() {
+        return vec![1; app.history.recent_searches().len()];
+    }
+    let current_list = app.get_current_video_list();
+    if app.compact_list {
+        vec![1; current_list.len()]
+    } else {
+        current_list
+            .iter()
+            .map(|video| video_height(video, inner_width, app.title_wrap, app.detail_level))
+            .collect()
+    }
+}
+
+/// Compute the range of videos to render.
+///
+/// # Arguments
+/// * `selected_index` - Index of the selected video
+/// * `len` - Number of videos in the list
+/// * `available_height` - Rows available inside the list borders
+/// * `item_height` - Rows per video (see `lines_per_video`)
+///
+/// # Returns
+/// * `(usize, usize)` - Start (inclusive) and end (exclusive) indices
+///
+/// # Details
+/// Only whole items are counted as visible, so leftover rows that can't hold
+/// a full item never clip the last video. The selection is kept centered
+/// until the end of the list is reached; from then on the final page is
+/// anchored so the last video sits fully visible at the bottom.
+pub fn visible_range(
+    selected_index: usize,
+    len: usize,
+    available_height: u16,
+    item_height: u16,
+) -> (usize, usize) {
+    let visible_videos = visible_video_count(available_height, item_height);
+    if len <= visible_videos {
+        return (0, len);
+    }
+
+    let selected_index = selected_index.min(len - 1);
+    let center_offset = visible_videos / 2;
+    let max_scroll = len - visible_videos;
+    let scroll_offset = selected_index.saturating_sub(center_offset).min(max_scroll);
+    (scroll_offset, scroll_offset + visible_videos)
+}
+
+/// Compute the range of videos to render when items have different heights.
+///
+/// # Arguments
+/// * `selected_index` - Index of the selected video
+/// * `heights` - Row height of every video in the list
+/// * `available_height` - Rows available inside the list borders
+///
+/// # Returns
+/// * `(usize, usize)` - Start (inclusive) and end (exclusive) indices
+///
+/// # Details
+/// Variable-height counterpart of `visible_range`: items above the selection
+/// take up to half of the spare rows, items below fill the rest, and any rows
+/// still free at the end of the list are given back to items above. Only whole
+/// items are counted; the selected item is always included.
+pub fn visible_range_for_heights(
+    selected_index: usize,
+    heights: &[u16],
+    available_height: u16,
+) -> (usize, usize) {
+    if heights.is_empty() {
+        return (0, 0);
+    }
+
+    let selected_index = selected_index.min(heights.len() - 1);
+    let (mut start, mut end) = (selected_index, selected_index + 1);
+    let mut used = heights[selected_index];
+
+    let half = available_height.saturating_sub(used) / 2;
+    let mut above = 0;
+    while start > 0 && above + heights[start - 1] <= half {
+        start -= 1;
+        above += heights[start];
+    }
+    used += above;
+
+    while end < heights.len() && used + heights[end] <= available_height {
+        used += heights[end];
+        end += 1;
+    }
+    while start > 0 && used + heights[start - 1] <= available_height {
+        start -= 1;
+        used += heights[start];
+    }
+    (start, end)
+}
+
+/// Compute the range of videos to render, scrolling only when needed.
+///
+/// # Arguments
+/// * `selected_index` - Index of the selected video
+/// * `heights` - Row height of every video in the list
+/// * `available_height` - Rows available inside the list borders
+/// * `previous_start` - First visible index of the previous frame
+///
+/// # Returns
+/// * `(usize, usize)` - Start (inclusive) and end (exclusive) indices
+///
+/// # Details
+/// Keeps the previous scroll position while the selection stays fully
+/// visible; otherwise scrolls just far enough to bring it into view. Rows left
+/// free at the end of the list are given back to items above, and only whole
+/// items are counted; the selected item is always included.
+pub fn anchored_range(
+    selected_index: usize,
+    heights: &[u16],
+    available_height: u16,
+    previous_start: usize,
+) -> (usize, usize) {
+    if heights.is_empty() {
+        return (0, 0);
+    }
+
+    let selected_index = selected_index.min(heights.len() - 1);
+    let fill_from = |start: usize| {
+        let mut end = start;
+        let mut used = 0;
+        while end < heights.len() && (end == start || used + heights[end] <= available_height) {
+            used += heights[end];
+            end += 1;
+        }
+        (end, used)
+    };
+
+    let mut start = previous_start.min(selected_index);
+    let (mut end, mut used) = fill_from(start);
+    while end <= selected_index {
+        start += 1;
+        (end, used) = fill_from(start);
+    }
+    while start > 0 && used + heights[start - 1] <= available_height {
+        start -= 1;
+        used += heights[start];
+    }
+    (start, end)
+}
+
+/// Lay out a title for the list.
+///
+/// # Arguments
+/// * `title` - Video title
+/// * `width` - Available display width in columns
+/// * `wrap` - Wrap onto several lines instead of truncating
+///
+/// # Returns
+/// * `Vec<String>` - Title lines (exactly one when truncating)
+pub fn title_lines(title: &str, width: usize, wrap: bool) -> Vec<String> {
+    if wrap {
+        wrap_text(title, width)
+    } else {
+        vec![truncate_to_width(title, width)]
+    }
+}
+
+/// Number of rows a video occupies in the list.
+///
+/// # Arguments
+/// * `title` - Video title
+/// * `width` - Available display width in columns
+/// * `wrap` - Whether titles wrap
+/// * `detail_level` - Lines shown per video (see `lines_per_video`)
+///
+/// # Returns
+/// * `u16` - Title lines plus the info lines and separator
+pub fn item_height(title: &str, width: usize, wrap: bool, detail_level: u8) -> u16 {
+    title_lines(title, width, wrap).len() as u16 + lines_per_video(detail_level) - 1
+}
+
+/// Number of rows a video occupies in the list, including its recommendation reason.
+///
+/// # Arguments
+/// * `video` - Video to measure
+/// * `width` - Available display width in columns
+/// * `wrap` - Whether titles wrap
+/// * `detail_level` - Lines shown per video (see `lines_per_video`)
+///
+/// # Returns
+/// * `u16` - `item_height` plus one line for the reason when it is shown
+pub fn video_height(
+    video: &crate::youtube::Video,
+    width: usize,
+    wrap: bool,
+    detail_level: u8,
+) -> u16 {
+    let reason_line = video.reason.is_some() && detail_level > 1;
+    item_height(&video.title, width, wrap, detail_level) + u16::from(reason_line)
+}
+
+/// Right-align values to the widest one.
+///
+/// # Arguments
+/// * `values` - Formatted values (e.g. view counts of the visible videos)
+///
+/// # Returns
+/// * `Vec<String>` - Values left-padded with spaces to a common display width
+pub fn align_right(values: &[String]) -> Vec<String> {
+    let width = values.iter().map(|v| v.width()).max().unwrap_or(0);
+    values
+        .iter()
+        .map(|v| format!("{}{}", " ".repeat(width - v.width()), v))
+        .collect()
+}
+
+/// Greedy word wrap by display width.
+///
+/// # Arguments
+/// * `text` - Text to wrap
+/// * `width` - Maximum line width in columns
+///
+/// # Returns
+/// * `Vec<String>` - At least one line; words wider than a line are split
+pub(crate) fn wrap_text(text: &str, width: usize) -> Vec<String> {
+    let width = width.max(1);
+    let mut lines = Vec::new();
+    let mut line = String::new();
+    let mut line_width = 0;
+
+    for word in text.split_whitespace() {
+        let word_width = word.width();
+        if line_width > 0 && line_width + 1 + word_width <= width {
+            line.push(' ');
+            line.push_str(word);
+            line_width += 1 + word_width;
+            continue;
+        }
+        if line_width > 0 {
+            lines.push(std::mem::take(&mut line));
+            line_width = 0;
+        }
+        for ch in word.chars() {
+            let ch_width = ch.width().unwrap_or(0);
+            if line_width > 0 && line_width + ch_width > width {
+                lines.push(std::mem::take(&mut line));
+                line_width = 0;
+            }
+            line.push(ch);
+            line_width += ch_width;
+        }
+    }
+
+    if !line.is_empty() || lines.is_empty() {
+        lines.push(line);
+    }
+    lines
+}
+
+/// Render the recent search queries as a selectable list.
+///
+/// # Arguments
+/// * `app` - Application state
+/// * `area` - Area to render in
+/// * `buf` - Buffer to render to
+///
+/// # Details
+/// Shown on the Search tab while there are no results and no query;
+/// `Enter` runs the selected query.
+fn render_recent_searches(app: &App, area: Rect, buf: &mut Buffer) {
+    let items: Vec<ListItem> = app
+        .history
+        .recent_searches()
+        .iter()
+        .map(|query| ListItem::new(Line::from(Span::raw(query.as_str()))))
+        .collect();
+
+    let mut list_state = ListState::default();
+    list_state.select(Some(app.selected_index.min(items.len().saturating_sub(1))));
+
+    let list = List::new(items)
+        .block(
+            Block::default()
+                .title("Recent searches (press 'Enter' to search again)")
+                .borders(Borders::ALL),
+        )
+        .highlight_style(
+            Style::default()
+                .bg(Color::Blue)
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        );
+    StatefulWidget::render(list, area, buf, &mut list_state);
+}
+
+/// Build the list item for a video whose details could not be fetched.
+///
+/// # Arguments
+/// * `video` - Placeholder video (see `Video::id_only`)
+/// * `url` - URL to show (see `App::video_url`)
+/// * `title_style` - Style of the first line
+/// * `is_watched` - Whether to show the watched tag
+/// * `separator` - Separator line
+///
+/// # Returns
+/// * `ListItem` - Item with the ID, watch time and URL, as tall as a regular item
+fn id_only_item<'a>(
+    video: &'a crate::youtube::Video,
+    url: String,
+    title_style: Style,
+    is_watched: bool,
+    detail_level: u8,
+    separator: Line<'a>,
+) -> ListItem<'a> {
+    let mut line1 = vec![Span::styled(format!("Video {}", video.id), title_style)];
+    if is_watched {
+        line1.push(Span::styled(
+            " [WATCHED]",
+            Style::default()
+                .fg(Color::Green)
+                .add_modifier(Modifier::BOLD),
+        ));
+    }
+    let mut lines = vec![
+        Line::from(line1),
+        Line::from(Span::styled(
+            "Details unavailable (offline?)",
+            Style::default().fg(Color::DarkGray),
+        )),
+        Line::from(Span::styled(
+            format!("Watched: {}", video.format_date()),
+            Style::default().fg(Color::Yellow),
+        )),
+        Line::from(Span::styled(url, Style::default().fg(Color::Gray))),
+        Line::default(),
+    ];
+    lines.truncate(lines_per_video(detail_level) as usize - 1);
+    lines.push(separator);
+    ListItem::new(lines)
+}
+
+/// Split text into spans, highlighting the given characters.
+///
+/// # Arguments
+/// * `text` - Text to split (e.g. one line of a title)
+/// * `matched` - Ascending char indices into `text` to highlight
+/// * `style` - Style of the other characters
+/// * `highlight` - Style of the highlighted characters
+///
+/// # Returns
+/// * `Vec<Span>` - Alternating runs of plain and highlighted text (a single
+///   span when nothing is highlighted)
+pub fn highlight_spans(
+    text: &str,
+    matched: &[usize],
+    style: Style,
+    highlight: Style,
+) -> Vec<Span<'static>> {
+    if matched.is_empty() {
+        return vec![Span::styled(text.to_string(), style)];
+    }
+    let mut spans = Vec::new();
+    let mut run = String::new();
+    let mut run_highlighted = false;
+    let mut next = matched.iter().peekable();
+    for (index, ch) in text.chars().enumerate() {
+        let highlighted = next.next_if(|&&i| i == index).is_some();
+        if highlighted != run_highlighted && !run.is_empty() {
+            let run_style = if run_highlighted { highlight } else { style };
+            spans.push(Span::styled(std::mem::take(&mut run), run_style));
+        }
+        run_highlighted = highlighted;
+        run.push(ch);
+    }
+    if !run.is_empty() {
+        spans.push(Span::styled(
+            run,
+            if run_highlighted { highlight } else { style },
+        ));
+    }
+    spans
+}
+
+/// Highlight the matched characters in the displayed lines of a title.
+///
+/// # Arguments
+/// * `lines` - Title lines as displayed (see `title_lines`)
+/// * `title` - Full title the lines were made from
+/// * `matched` - Ascending char indices into `title` (see `ParsedQuery::title_match_indices`)
+/// * `style` - Style of the title
+///
+/// # Returns
+/// * `Vec<Line>` - One line per title line
+///
+/// # Details
+/// Wrapping drops spaces at line breaks and truncation adds an ellipsis, so
+/// each line is located in the title to map the indices onto it.
+fn highlight_title_lines(
+    lines: Vec<String>,
+    title: &str,
+    matched: &[usize],
+    style: Style,
+) -> Vec<Line<'static>> {
+    let highlight = style.fg(Color::Black).bg(Color::Yellow);
+    let mut byte_offset = 0;
+    lines
+        .into_iter()
+        .map(|line| {
+            let body = line.strip_suffix('…').unwrap_or(&line);
+            let line_matched: Vec<usize> = match title[byte_offset..].find(body) {
+                Some(found) if !matched.is_empty() => {
+                    let start = byte_offset + found;
+                    byte_offset = start + body.len();
+                    let first = title[..start].chars().count();
+                    let len = body.chars().count();
+                    matched
+                        .iter()
+                        .filter(|&&i| i >= first && i < first + len)
+                        .map(|&i| i - first)
+                        .collect()
+                }
+                _ => Vec::new(),
+            };
+            Line::from(highlight_spans(&line, &line_matched, style, highlight))
+        })
+        .collect()
+}
+
+/// Build the one-line list item of a video for the compact list.
+///
+/// # Arguments
+/// * `app` - Application state (tags and channel colors)
+/// * `video` - Video to show
+/// * `duration` - Duration, right-aligned with the other visible videos
+/// * `views` - View count, right-aligned with the other visible videos
+/// * `width` - Width inside the list borders
+/// * `title_style` - Style of the title
+/// * `matched` - Title characters to highlight (see `highlight_title_lines`)
+///
+/// # Returns
+/// * `ListItem` - "Duration | Title — Channel (views)" with the title
+///   truncated so the channel and views stay visible
+fn compact_item<'a>(
+    app: &App,
+    video: &'a crate::youtube::Video,
+    duration: &str,
+    views: &str,
+    width: usize,
+    title_style: Style,
+    matched: &[usize],
+) -> ListItem<'a> {
+    let prefix = Span::styled(
+        format!("{} | ", duration),
+        Style::default().fg(Color::Magenta),
+    );
+    if video.details_missing {
+        return ListItem::new(Line::from(vec![
+            prefix,
+            Span::styled(format!("Video {}", video.id), title_style),
+            Span::styled(
+                " — details unavailable (offline?)",
+                Style::default().fg(Color::DarkGray),
+            ),
+        ]));
+    }
+
+    let creator_style = if app.no_color {
+        Style::default()
+    } else {
+        Style::default().fg(channel_color(&video.channel_id))
+    };
+    let mut suffix = vec![
+        Span::raw(" — "),
+        Span::styled(video.channel.clone(), creator_style),
+        Span::styled(
+            format!(" ({})", views.trim()),
+            Style::default().fg(Color::Gray),
+        ),
+    ];
+    if app.history.is_watched(&video.id) {
+        suffix.push(Span::styled(
+            " [WATCHED]",
+            Style::default()
+                .fg(Color::Green)
+                .add_modifier(Modifier::BOLD),
+        ));
+    }
+    if app.is_marked(&video.id) {
+        suffix.push(Span::styled(
+            " [MARKED]",
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+        ));
+    }
+
+    let fixed_width = prefix.width() + suffix.iter().map(|span| span.width()).sum::<usize>();
+    let title = truncate_to_width(&video.title, width.saturating_sub(fixed_width));
+    let mut spans = vec![prefix];
+    for line in highlight_title_lines(vec![title], &video.title, matched, title_style) {
+        spans.extend(line.spans);
+    }
+    spans.extend(suffix);
+    ListItem::new(Line::from(spans))
+}
 
 /// Render the video list widget.
 ///
@@ -20,29 +633,46 @@ use ratatui::{
 ///
 /// # Details
 /// Displays a scrollable list of videos with each video on multiple lines:
-/// - Line 1: Video title and watched indicator (bold)
+/// - Line 1: Video title, watched and continue-watching indicators (bold);
+///   truncated with an ellipsis, or wrapped over several lines when `title_wrap` is on.
+///   The characters matched by the filter text (`/`) are highlighted
 /// - Line 2: Creator/channel name
 /// - Line 3: Video duration
 /// - Line 4: Upload date
-/// - Line 5: View count
+/// - Line 5: View count (and video/channel IDs when `show_ids` is on)
+/// - Recommendation reason, for personalized recommendations (hidden at detail level 1)
 ///
-/// Highlights the selected video.
-pub fn render_list(app: &App, area: Rect, buf: &mut Buffer) {
+/// With `compact_list` on, each video takes a single line instead (see
+/// `compact_item`).
+///
+/// Videos whose details could not be fetched show their ID, watch time and
+/// URL instead. Highlights the selected video.
+///
+/// # Returns
+/// * `Vec<ItemRow>` - Screen rows of the rendered items, for mouse hit-testing
+pub fn render_list(app: &App, area: Rect, buf: &mut Buffer) -> Vec<ItemRow> {
     // Get the current video list based on active tab
     let current_list = app.get_current_video_list();
     let total_count = match app.active_tab() {
         crate::app::Tab::CurrentView => app.all_videos.len(),
         crate::app::Tab::Search => current_list.len(),
         crate::app::Tab::History => current_list.len(),
+        crate::app::Tab::Saved => current_list.len(),
     };
 
+    // Empty Search tab: offer recent searches instead
+    if app.showing_recent_searches() {
+        render_recent_searches(app, area, buf);
+        return Vec::new();
+    }
+
     // Handle empty list
     if current_list.is_empty() {
         let title = format!("Videos ({}/{})", current_list.len(), total_count);
         let list = List::new(vec![ListItem::new("No videos to display")])
             .block(Block::default().title(title).borders(Borders::ALL));
         Widget::render(list, area, buf);
-        return;
+        return Vec::new();
     }
 
     // Ensure selected_index is valid
@@ -52,27 +682,50 @@ pub fn render_list(app: &App, area: Rect, buf: &mut Buffer) {
     let separator_width = area.width.saturating_sub(2).max(10) as usize;
     let separator_line = "─".repeat(separator_width);
 
-    // Calculate scroll offset to keep selection centered
-    // Each video takes 6 lines (1 for title + 4 for info + 1 separator)
-    let lines_per_video = 6;
-    let available_height = area.height.saturating_sub(2); // Account for borders
-    let visible_videos = (available_height / lines_per_video).max(1) as usize;
-    let center_offset = (visible_videos / 2).max(0);
-
-    // Calculate scroll offset to center the selected item
-    let scroll_offset = if selected_index >= center_offset {
-        selected_index.saturating_sub(center_offset)
-    } else {
-        0
+    // Calculate which videos are visible, keeping the selection centered
+    // (or, when anchored, scrolling from the previous frame's position);
+    // rows under the preview pane don't count
+    let available_height = area
+        .height
+        .saturating_sub(2) // Account for borders
+        .saturating_sub(crate::ui::preview::covered_rows(app, area.height));
+    let inner_width = area.width.saturating_sub(2) as usize;
+    let heights = list_heights(app, inner_width);
+    let has_reasons = !app.compact_list && current_list.iter().any(|video| video.reason.is_some());
+    let (start_idx, end_idx) = match app.scroll_mode {
+        ScrollMode::Anchored => {
+            let previous_start = app.item_rows.first().map_or(0, |row| row.index);
+            anchored_range(selected_index, &heights, available_height, previous_start)
+        }
+        ScrollMode::Centered if (app.title_wrap && !app.compact_list) || has_reasons => {
+            visible_range_for_heights(selected_index, &heights, available_height)
+        }
+        ScrollMode::Centered => visible_range(
+            selected_index,
+            current_list.len(),
+            available_height,
+            list_item_height(app.compact_list, app.detail_level),
+        ),
     };
+    let scroll_offset = start_idx;
 
-    // Ensure we don't scroll past the end
-    let max_scroll = current_list.len().saturating_sub(visible_videos);
-    let scroll_offset = scroll_offset.min(max_scroll);
+    // Right-align numbers to the widest visible value so they line up
+    let visible = &current_list[start_idx..end_idx];
+    let durations = align_right(
+        &visible
+            .iter()
+            .map(|video| video.format_duration())
+            .collect::<Vec<_>>(),
+    );
+    let views = align_right(
+        &visible
+            .iter()
+            .map(|video| format_count(video.view_count, app.number_style, &app.thousands_separator))
+            .collect::<Vec<_>>(),
+    );
 
-    // Only render visible items based on scroll offset
-    let start_idx = scroll_offset;
-    let end_idx = (scroll_offset + visible_videos).min(current_list.len());
+    // Parsed once; only the visible titles are matched for highlighting
+    let query = crate::query::ParsedQuery::parse(app.search_query());
 
     let items: Vec<ListItem> = current_list
         .iter()
@@ -100,55 +753,157 @@ pub fn render_list(app: &App, area: Rect, buf: &mut Buffer) {
                 })
                 .add_modifier(Modifier::BOLD); // Always bold for title
 
-            // Line 1: Video title (bold, single line)
-            let mut line1_spans = vec![Span::styled(&video.title, title_style)];
+            // Last line: Separator (dashed line)
+            let separator_style = if is_selected {
+                Style::default().fg(Color::Blue)
+            } else {
+                Style::default().fg(Color::DarkGray)
+            };
+            let separator = Line::from(vec![Span::styled(separator_line.clone(), separator_style)]);
+
+            if app.compact_list {
+                return compact_item(
+                    app,
+                    video,
+                    &durations[idx - start_idx],
+                    &views[idx - start_idx],
+                    inner_width,
+                    title_style,
+                    &query.title_match_indices(&video.title),
+                )
+                .style(base_style);
+            }
+
+            if video.details_missing {
+                return id_only_item(
+                    video,
+                    app.video_url(video),
+                    title_style,
+                    is_watched,
+                    app.detail_level,
+                    separator,
+                )
+                .style(base_style);
+            }
+
+            // Line 1: Video title (bold), followed by indicator tags
+            let mut tag_spans = Vec::new();
             if is_watched {
-                line1_spans.push(Span::styled(
+                tag_spans.push(Span::styled(
                     " [WATCHED]",
                     Style::default()
                         .fg(Color::Green)
                         .add_modifier(Modifier::BOLD),
                 ));
             }
-            let line1 = Line::from(line1_spans);
+            if app.is_marked(&video.id) {
+                tag_spans.push(Span::styled(
+                    " [MARKED]",
+                    Style::default()
+                        .fg(Color::Cyan)
+                        .add_modifier(Modifier::BOLD),
+                ));
+            }
+            if let Some(progress) = app.history.progress(&video.id).filter(|p| p.is_resumable()) {
+                tag_spans.push(Span::styled(
+                    format!(" [CONTINUE {:.0}%]", progress.fraction() * 100.0),
+                    Style::default()
+                        .fg(Color::LightMagenta)
+                        .add_modifier(Modifier::BOLD),
+                ));
+            }
+
+            // Wrapped titles use the full width; truncated titles leave room for the tags
+            let title_width = if app.title_wrap {
+                inner_width
+            } else {
+                let tags_width: usize = tag_spans.iter().map(|span| span.width()).sum();
+                inner_width.saturating_sub(tags_width)
+            };
+            let mut lines: Vec<Line> = highlight_title_lines(
+                title_lines(&video.title, title_width, app.title_wrap),
+                &video.title,
+                &query.title_match_indices(&video.title),
+                title_style,
+            );
+            if let Some(last) = lines.last_mut() {
+                last.spans.extend(tag_spans);
+            }
 
-            // Line 2: Creator/channel
+            // Line 2: Creator/channel (tinted per channel unless colors are disabled)
+            let creator_style = if app.no_color {
+                Style::default()
+            } else {
+                Style::default().fg(channel_color(&video.channel_id))
+            };
             let line2 = Line::from(vec![Span::styled(
                 format!("Creator: {}", video.channel),
-                Style::default().fg(Color::Cyan),
+                creator_style,
             )]);
 
             // Line 3: Video duration
             let line3 = Line::from(vec![Span::styled(
-                format!("Duration: {}", video.format_duration()),
+                format!("Duration: {}", durations[idx - start_idx]),
                 Style::default().fg(Color::Magenta),
             )]);
 
             // Line 4: Upload date
+            let uploaded = if app.relative_dates {
+                video.format_relative_date()
+            } else {
+                video.format_date()
+            };
             let line4 = Line::from(vec![Span::styled(
-                format!("Uploaded: {}", video.format_date()),
+                format!("Uploaded: {}", uploaded),
                 Style::default().fg(Color::Yellow),
             )]);
 
-            // Line 5: Views
-            let line5 = Line::from(vec![Span::styled(
-                format!("Views: {}", video.format_views()),
+            // Line 5: Views (plus raw IDs when enabled)
+            let mut line5_spans = vec![Span::styled(
+                format!("Views: {}", views[idx - start_idx]),
                 Style::default().fg(Color::Gray),
-            )]);
+            )];
+            if app.show_ids {
+                line5_spans.push(Span::styled(
+                    format!("  ID: {}  Channel ID: {}", video.id, video.channel_id),
+                    Style::default().fg(Color::DarkGray),
+                ));
+            }
+            let line5 = Line::from(line5_spans);
 
-            // Line 6: Separator (dashed line)
-            let separator_style = if is_selected {
-                Style::default().fg(Color::Blue)
-            } else {
-                Style::default().fg(Color::DarkGray)
-            };
-            let separator = Line::from(vec![Span::styled(separator_line.clone(), separator_style)]);
+            // Create ListItem with the title lines, the info lines of the detail level and 1 separator
+            let info_lines = usize::from(app.detail_level.clamp(1, MAX_DETAIL_LEVEL)) - 1;
+            lines.extend([line2, line3, line4, line5].into_iter().take(info_lines));
 
-            // Create ListItem with 6 lines (5 content + 1 separator: title takes 1 line)
-            ListItem::new(vec![line1, line2, line3, line4, line5, separator]).style(base_style)
+            // Extra line: why YouTube recommended the video
+            if let Some(reason) = &video.reason
+                && info_lines > 0
+            {
+                lines.push(Line::from(Span::styled(
+                    truncate_to_width(reason, inner_width),
+                    Style::default()
+                        .fg(Color::LightBlue)
+                        .add_modifier(Modifier::ITALIC),
+                )));
+            }
+            lines.push(separator);
+            ListItem::new(lines).style(base_style)
         })
         .collect();
 
+    // Record where each item is drawn (inside the top border)
+    let mut item_rows = Vec::with_capacity(items.len());
+    let mut row = area.y + 1;
+    for (offset, item) in items.iter().enumerate() {
+        let height = item.height() as u16;
+        item_rows.push(ItemRow {
+            index: start_idx + offset,
+            start_row: row,
+            height,
+        });
+        row += height;
+    }
+
     let title = format!("Videos ({}/{})", current_list.len(), total_count);
 
     // Calculate relative selected index for visible items
@@ -173,4 +928,471 @@ pub fn render_list(app: &App, area: Rect, buf: &mut Buffer) {
         );
 
     StatefulWidget::render(list, area, buf, &mut list_state);
+    item_rows
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_page_size() {
+        let uniform = |compact, detail_level| vec![list_item_height(compact, detail_level); 50];
+        // 30 rows minus borders hold 4 whole items of 6 rows
+        assert_eq!(page_size(30, &uniform(false, MAX_DETAIL_LEVEL), 20), 4);
+        assert_eq!(page_size(30, &uniform(false, 1), 20), 14);
+        assert_eq!(page_size(3, &uniform(false, MAX_DETAIL_LEVEL), 20), 1);
+        // One row per video in the compact list
+        assert_eq!(page_size(30, &uniform(true, MAX_DETAIL_LEVEL), 20), 28);
+        assert_eq!(page_size(30, &[], 0), 1);
+    }
+
+    #[test]
+    fn test_page_size_with_reasons() {
+        let mut app = App::new(crate::history::History::default(), false);
+        app.all_videos = (0..20)
+            .map(|i| {
+                let mut video = crate::youtube::models::test_video(&format!("id{}", i));
+                video.reason = Some("Because you watched Rust".to_string());
+                video
+            })
+            .collect();
+        app.apply_filters();
+        let heights = list_heights(&app, 80);
+        let item_height = list_item_height(false, app.detail_level);
+        assert!(heights.iter().all(|&height| height == item_height + 1));
+        // 42 rows hold fewer videos once each has a reason row
+        let page = page_size(44, &heights, 10);
+        assert_eq!(page, 42 / (item_height as usize + 1));
+        assert!(page < page_size(44, &[item_height; 20], 10));
+    }
+
+    #[test]
+    fn test_visible_range_final_page() {
+        // 18 usable rows hold 3 whole items, leaving 0 spare; 22 rows leave 4 spare
+        for available_height in [18u16, 22] {
+            let visible = (available_height / LINES_PER_VIDEO) as usize;
+            for len in 1..=11 {
+                for selected in 0..len {
+                    let (start, end) =
+                        visible_range(selected, len, available_height, LINES_PER_VIDEO);
+                    assert!(start <= selected && selected < end);
+                    assert!(end <= len);
+                    assert_eq!(end - start, len.min(visible));
+                }
+                // Selecting the last item shows the whole final page
+                let (start, end) = visible_range(len - 1, len, available_height, LINES_PER_VIDEO);
+                assert_eq!(end, len);
+                assert_eq!(start, len.saturating_sub(visible));
+            }
+        }
+    }
+
+    #[test]
+    fn test_render_last_item_fully_visible() {
+        let mut app = App::new(crate::history::History::default(), false);
+        app.set_videos(
+            (0..30)
+                .map(|i| {
+                    crate::youtube::Video::new(
+                        format!("vid{}", i),
+                        format!("Video {}", i),
+                        "Channel".to_string(),
+                        "UCchan".to_string(),
+                        String::new(),
+                        60,
+                        chrono::Utc::now(),
+                        String::new(),
+                        10,
+                    )
+                })
+                .collect(),
+        );
+
+        // 20 rows leave 18 inside the borders (3 whole items); 21 leave an odd 19
+        for height in [20u16, 21] {
+            let area = Rect::new(0, 0, 60, height);
+            let inner_bottom = area.bottom() - 1;
+            for selected in 0..30 {
+                app.selected_index = selected;
+                let mut buf = Buffer::empty(area);
+                let rows = render_list(&app, area, &mut buf);
+                assert_eq!(rows.len(), 3);
+                assert!(rows.iter().any(|row| row.index == selected));
+                assert!(
+                    rows.iter()
+                        .all(|row| row.start_row + row.height <= inner_bottom)
+                );
+            }
+
+            // The last video ends with its separator on the last inner row
+            let mut buf = Buffer::empty(area);
+            let rows = render_list(&app, area, &mut buf);
+            let last = rows.last().unwrap();
+            assert_eq!((rows[0].index, last.index), (27, 29));
+            assert_eq!(buf[(1, last.start_row + last.height - 1)].symbol(), "─");
+        }
+    }
+
+    #[test]
+    fn test_selection_kept_above_preview() {
+        let mut app = App::new(crate::history::History::default(), false);
+        app.compact_list = true;
+        app.set_videos(
+            (0..30)
+                .map(|i| crate::youtube::models::test_video(&format!("vid{}", i)))
+                .collect(),
+        );
+        let area = Rect::new(0, 0, 60, 20);
+        let pane = crate::ui::preview::preview_area(area);
+        app.preview_next();
+        for selected in [10, 29] {
+            app.selected_index = selected;
+            let mut buf = Buffer::empty(area);
+            let rows = render_list(&app, area, &mut buf);
+            let row = rows.iter().find(|row| row.index == selected).unwrap();
+            assert!(row.start_row + row.height <= pane.y);
+            assert!(rows.iter().all(|row| row.start_row < pane.y));
+        }
+    }
+
+    #[test]
+    fn test_compact_list_rows() {
+        let mut app = App::new(crate::history::History::default(), false);
+        app.compact_list = true;
+        app.set_videos(
+            (0..30)
+                .map(|i| {
+                    crate::youtube::Video::new(
+                        format!("vid{}", i),
+                        format!("Video {}", i),
+                        "Channel".to_string(),
+                        "UCchan".to_string(),
+                        String::new(),
+                        65,
+                        chrono::Utc::now(),
+                        String::new(),
+                        1500,
+                    )
+                })
+                .collect(),
+        );
+        app.set_initial_sort(
+            crate::app::Tab::CurrentView,
+            crate::app::SortMode::Relevance,
+        );
+
+        // 12 rows inside the borders hold 12 videos
+        let area = Rect::new(0, 5, 60, 14);
+        let mut buf = Buffer::empty(area);
+        app.item_rows = render_list(&app, area, &mut buf);
+        assert_eq!(app.item_rows.len(), 12);
+        assert!(app.item_rows.iter().all(|row| row.height == 1));
+
+        // Clicks map one row to one video, starting below the top border
+        assert_eq!(app.index_at_row(5), None);
+        assert_eq!(app.index_at_row(6), Some(0));
+        assert_eq!(app.index_at_row(10), Some(4));
+        assert_eq!(app.index_at_row(17), Some(11));
+        assert_eq!(app.index_at_row(18), None);
+
+        let line: String = (0..60).map(|x| buf[(x, 6)].symbol()).collect();
+        assert!(line.starts_with("│01:05 | Video 0 — Channel (1.5K) "));
+
+        // Selecting further down scrolls by single rows
+        app.selected_index = 20;
+        app.item_rows = render_list(&app, area, &mut buf);
+        assert!(app.item_rows.iter().any(|row| row.index == 20));
+        let row = app.item_rows.iter().find(|row| row.index == 20).unwrap();
+        assert_eq!(app.index_at_row(row.start_row), Some(20));
+    }
+
+    #[test]
+    fn test_highlight_spans() {
+        let style = Style::default();
+        let highlight = Style::default().bg(Color::Yellow);
+        let texts = |spans: &[Span]| {
+            spans
+                .iter()
+                .map(|span| (span.content.to_string(), span.style == highlight))
+                .collect::<Vec<_>>()
+        };
+
+        let spans = highlight_spans("Learn Rust now", &[6, 7, 8, 9], style, highlight);
+        assert_eq!(
+            texts(&spans),
+            [
+                ("Learn ".to_string(), false),
+                ("Rust".to_string(), true),
+                (" now".to_string(), false)
+            ]
+        );
+        // Scattered fuzzy matches, at the start and end, with wide characters
+        let spans = highlight_spans("日本abc", &[0, 3, 4], style, highlight);
+        assert_eq!(
+            texts(&spans),
+            [
+                ("日".to_string(), true),
+                ("本a".to_string(), false),
+                ("bc".to_string(), true)
+            ]
+        );
+        // No query: one plain span
+        assert_eq!(
+            texts(&highlight_spans("Title", &[], style, highlight)),
+            [("Title".to_string(), false)]
+        );
+    }
+
+    #[test]
+    fn test_highlight_wrapped_title_lines() {
+        let style = Style::default();
+        let title = "Rust tutorial for beginners";
+        let query = crate::query::ParsedQuery::parse("beginners");
+        let lines = highlight_title_lines(
+            title_lines(title, 14, true),
+            title,
+            &query.title_match_indices(title),
+            style,
+        );
+        let highlighted: Vec<String> = lines
+            .iter()
+            .flat_map(|line| line.spans.iter())
+            .filter(|span| span.style != style)
+            .map(|span| span.content.to_string())
+            .collect();
+        assert_eq!(highlighted, ["beginners"]);
+    }
+
+    fn rendered_text(app: &App) -> String {
+        let area = Rect::new(0, 0, 100, 10);
+        let mut buf = Buffer::empty(area);
+        render_list(app, area, &mut buf);
+        buf.content().iter().map(|cell| cell.symbol()).collect()
+    }
+
+    #[test]
+    fn test_show_ids_toggle() {
+        let mut app = App::new(crate::history::History::default(), false);
+        app.set_videos(vec![crate::youtube::Video::new(
+            "vid123".to_string(),
+            "Title".to_string(),
+            "Channel".to_string(),
+            "UCchan".to_string(),
+            "Description".to_string(),
+            60,
+            chrono::Utc::now(),
+            "thumb".to_string(),
+            10,
+        )]);
+
+        assert!(!rendered_text(&app).contains("vid123"));
+        app.show_ids = true;
+        let text = rendered_text(&app);
+        assert!(text.contains("ID: vid123"));
+        assert!(text.contains("Channel ID: UCchan"));
+    }
+
+    #[test]
+    fn test_item_height_wrap_vs_truncate() {
+        let title = "A fairly long video title that needs several lines";
+        // Truncated titles always take one line
+        assert_eq!(
+            item_height(title, 20, false, MAX_DETAIL_LEVEL),
+            LINES_PER_VIDEO
+        );
+        assert_eq!(title_lines(title, 20, false)[0].width(), 20);
+        // Wrapped: "A fairly long video" / "title that needs" / "several lines"
+        assert_eq!(title_lines(title, 20, true).len(), 3);
+        assert_eq!(
+            item_height(title, 20, true, MAX_DETAIL_LEVEL),
+            LINES_PER_VIDEO + 2
+        );
+        assert_eq!(
+            item_height("Short", 20, true, MAX_DETAIL_LEVEL),
+            LINES_PER_VIDEO
+        );
+        // Words longer than the width are split
+        assert_eq!(
+            title_lines("abcdefghij", 4, true),
+            vec!["abcd", "efgh", "ij"]
+        );
+    }
+
+    #[test]
+    fn test_visible_range_for_heights() {
+        let heights = [6, 8, 6, 10, 6, 6];
+        for selected in 0..heights.len() {
+            let (start, end) = visible_range_for_heights(selected, &heights, 20);
+            assert!(start <= selected && selected < end);
+            let used: u16 = heights[start..end].iter().sum();
+            assert!(used <= 20);
+        }
+        // The end of the list is anchored: the final items fill the space
+        assert_eq!(visible_range_for_heights(5, &heights, 26), (3, 6));
+        // A single item taller than the area is still shown
+        assert_eq!(visible_range_for_heights(0, &[30], 20), (0, 1));
+    }
+
+    #[test]
+    fn test_anchored_vs_centered_offsets() {
+        let heights = [LINES_PER_VIDEO; 20];
+        // 30 rows fit 5 videos
+        assert_eq!(visible_range(4, 20, 30, LINES_PER_VIDEO), (2, 7));
+        assert_eq!(anchored_range(4, &heights, 30, 0), (0, 5));
+        // Moving past the bottom scrolls by one
+        assert_eq!(anchored_range(5, &heights, 30, 0), (1, 6));
+        // Moving back up within the view keeps the offset
+        assert_eq!(anchored_range(3, &heights, 30, 1), (1, 6));
+        // Moving above the view scrolls up to the selection
+        assert_eq!(anchored_range(0, &heights, 30, 1), (0, 5));
+        // The final page stays full
+        assert_eq!(anchored_range(19, &heights, 30, 18), (15, 20));
+
+        let heights = [6, 8, 6, 10, 6, 6];
+        for previous in 0..heights.len() {
+            for selected in 0..heights.len() {
+                let (start, end) = anchored_range(selected, &heights, 20, previous);
+                assert!(start <= selected && selected < end);
+                let used: u16 = heights[start..end].iter().sum();
+                assert!(used <= 20);
+            }
+        }
+    }
+
+    #[test]
+    fn test_align_right_counts() {
+        let counts: Vec<String> = [5, 1_500, 2_500_000, 12_345]
+            .iter()
+            .map(|&c| format_count(c, crate::youtube::NumberStyle::Short, ""))
+            .collect();
+        assert_eq!(align_right(&counts), ["    5", " 1.5K", " 2.5M", "12.3K"]);
+
+        let counts: Vec<String> = [5, 12_345]
+            .iter()
+            .map(|&c| format_count(c, crate::youtube::NumberStyle::Full, ","))
+            .collect();
+        assert_eq!(align_right(&counts), ["     5", "12,345"]);
+        assert!(align_right(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_recent_searches_shown_when_search_empty() {
+        let mut history = crate::history::History::default();
+        history.add_recent_search("older query");
+        history.add_recent_search("rust async");
+        let mut app = App::new(history, false);
+
+        // Not on the Search tab: regular (empty) list
+        assert!(rendered_text(&app).contains("No videos to display"));
+
+        app.switch_tab(crate::app::Tab::Search);
+        let text = rendered_text(&app);
+        assert!(text.contains("Recent searches"));
+        assert!(text.contains("rust async"));
+        assert!(text.contains("older query"));
+
+        // A typed query hides the list
+        app.search_query_global = "new".to_string();
+        assert!(!rendered_text(&app).contains("Recent searches"));
+    }
+
+    #[test]
+    fn test_id_only_video_rendered() {
+        let mut app = App::new(crate::history::History::default(), false);
+        app.switch_tab(crate::app::Tab::History);
+        app.set_history_videos(vec![crate::youtube::Video::id_only(
+            "dQw4w9WgXcQ".to_string(),
+            chrono::Utc::now(),
+        )]);
+        let text = rendered_text(&app);
+        assert!(text.contains("Video dQw4w9WgXcQ"));
+        assert!(text.contains("Details unavailable"));
+    }
+
+    #[test]
+    fn test_recommendation_reason_rendered() {
+        let mut video = crate::youtube::Video::new(
+            "vid123".to_string(),
+            "Title".to_string(),
+            "Channel".to_string(),
+            "UC123".to_string(),
+            String::new(),
+            60,
+            chrono::Utc::now(),
+            String::new(),
+            1,
+        );
+        assert_eq!(
+            video_height(&video, 20, false, MAX_DETAIL_LEVEL),
+            LINES_PER_VIDEO
+        );
+        video.reason = Some("Because you watched a similar video".to_string());
+        assert_eq!(
+            video_height(&video, 20, false, MAX_DETAIL_LEVEL),
+            LINES_PER_VIDEO + 1
+        );
+        assert_eq!(video_height(&video, 20, false, 1), 2);
+
+        let mut app = App::new(crate::history::History::default(), false);
+        app.set_videos(vec![video]);
+        assert!(rendered_text(&app).contains("Because you watched a similar video"));
+
+        app.detail_level = 1;
+        assert!(!rendered_text(&app).contains("Because you watched"));
+    }
+
+    #[test]
+    fn test_channel_color_stable() {
+        let first = channel_color("UC_x5XG1OV2P6uZZ5FSM9Ttw");
+        for _ in 0..10 {
+            assert_eq!(channel_color("UC_x5XG1OV2P6uZZ5FSM9Ttw"), first);
+        }
+        assert!(CHANNEL_PALETTE.contains(&channel_color("")));
+    }
+
+    #[test]
+    fn test_visible_range_tiny_area() {
+        // Less than one item of height still shows the selected item
+        assert_eq!(visible_range(4, 10, 3, LINES_PER_VIDEO), (4, 5));
+        assert_eq!(visible_range(0, 0, 20, LINES_PER_VIDEO), (0, 0));
+    }
+
+    #[test]
+    fn test_detail_level_lines() {
+        assert_eq!(lines_per_video(MAX_DETAIL_LEVEL), LINES_PER_VIDEO);
+        assert_eq!(lines_per_video(1), 2);
+        // Out-of-range levels are clamped
+        assert_eq!(lines_per_video(0), 2);
+        assert_eq!(lines_per_video(9), LINES_PER_VIDEO);
+        assert_eq!(item_height("Title", 20, false, 2), 3);
+
+        let mut app = App::new(crate::history::History::default(), false);
+        app.set_videos(vec![crate::youtube::Video::new(
+            "vid123".to_string(),
+            "Title".to_string(),
+            "Channel".to_string(),
+            "UCchan".to_string(),
+            String::new(),
+            60,
+            chrono::Utc::now(),
+            String::new(),
+            10,
+        )]);
+
+        let text = rendered_text(&app);
+        assert!(text.contains("Creator: Channel") && text.contains("Views:"));
+
+        app.detail_level = 3;
+        let text = rendered_text(&app);
+        assert!(text.contains("Duration:") && !text.contains("Uploaded:"));
+
+        app.detail_level = 1;
+        let area = Rect::new(0, 0, 100, 10);
+        let mut buf = Buffer::empty(area);
+        let rows = render_list(&app, area, &mut buf);
+        assert_eq!(rows[0].height, 2);
+        let text: String = buf.content().iter().map(|cell| cell.symbol()).collect();
+        assert!(text.contains("Title") && !text.contains("Creator:"));
+    }
 }
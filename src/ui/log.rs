@@ -0,0 +1,47 @@
+//! Message log popup rendering.
+//!
+//! Displays the full text of recent status messages.
+
+use crate::app::App;
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Style},
+    text::Line,
+    widgets::{Block, Borders, Clear, Paragraph, Widget, Wrap},
+};
+
+/// Render the message log popup.
+///
+/// # Arguments
+/// * `app` - Application state
+/// * `area` - Area to render in (drawn over the list)
+/// * `buf` - Buffer to render to
+///
+/// # Details
+/// Shows recent status messages newest first, wrapped to the popup width,
+/// so messages truncated in the status bar can be read in full.
+pub fn render_log(app: &App, area: Rect, buf: &mut Buffer) {
+    let lines: Vec<Line> = if app.status_log.is_empty() {
+        vec![Line::styled(
+            "No messages yet",
+            Style::default().fg(Color::Gray),
+        )]
+    } else {
+        app.status_log
+            .iter()
+            .rev()
+            .map(|message| Line::from(format!("• {}", message)))
+            .collect()
+    };
+
+    let paragraph = Paragraph::new(lines).wrap(Wrap { trim: false }).block(
+        Block::default()
+            .title("Message Log (press 'Esc' or 'L' to close)")
+            .borders(Borders::ALL)
+            .style(Style::default().fg(Color::Yellow)),
+    );
+
+    Widget::render(Clear, area, buf);
+    Widget::render(paragraph, area, buf);
+}
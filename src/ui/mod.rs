@@ -2,12 +2,26 @@
 //!
 //! Contains ratatui widgets for displaying the application interface.
 
+pub mod chapters;
+pub mod debug;
+pub mod detail;
 pub mod filters;
 pub mod list;
+pub mod log;
+pub mod preview;
 pub mod search;
+pub mod stats;
+pub mod status;
 pub mod tabs;
 
+pub use chapters::render_chapters;
+pub use debug::render_debug;
+pub use detail::render_detail;
 pub use filters::render_filters;
 pub use list::render_list;
+pub use log::render_log;
+pub use preview::render_preview;
 pub use search::render_search;
+pub use stats::render_stats;
+pub use status::render_status;
 pub use tabs::render_tabs;
@@ -0,0 +1,119 @@
+//! Video preview pane rendering.
+//!
+//! Shows details of the previewed video without moving the list selection.
+
+use crate::app::App;
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Widget, Wrap},
+};
+
+/// Height of the preview pane in rows (including borders).
+pub const PREVIEW_HEIGHT: u16 = 9;
+
+/// Area of the preview pane at the bottom of the list.
+///
+/// # Arguments
+/// * `list_area` - Area of the video list
+///
+/// # Returns
+/// * `Rect` - Bottom `PREVIEW_HEIGHT` rows of the list area (or all of it if smaller)
+pub fn preview_area(list_area: Rect) -> Rect {
+    let height = PREVIEW_HEIGHT.min(list_area.height);
+    Rect {
+        y: list_area.y + list_area.height - height,
+        height,
+        ..list_area
+    }
+}
+
+/// Count the list rows the preview pane hides.
+///
+/// # Arguments
+/// * `app` - Application state
+/// * `list_height` - Height of the list widget including its borders
+///
+/// # Returns
+/// * `u16` - Rows inside the list borders covered by the pane (0 when it is
+///   closed); the pane's bottom border overlaps the list's
+pub fn covered_rows(app: &App, list_height: u16) -> u16 {
+    if !app.preview_shown() {
+        return 0;
+    }
+    PREVIEW_HEIGHT.min(list_height).saturating_sub(1)
+}
+
+/// Render the preview pane.
+///
+/// # Arguments
+/// * `app` - Application state
+/// * `area` - Area to render in (drawn over the list)
+/// * `buf` - Buffer to render to
+///
+/// # Details
+/// Shows title, channel, duration, upload date, views and the start of the
+/// description of the video at `preview_index`, plus the other tabs and the
+/// queue that also list it (see `App::video_locations`). Renders nothing if
+/// no video is being previewed.
+pub fn render_preview(app: &App, area: Rect, buf: &mut Buffer) {
+    let Some(video) = app.preview_video() else {
+        return;
+    };
+    let position = app.preview_index.unwrap_or(0) + 1;
+    let total = app.get_current_video_list().len();
+
+    let mut lines = vec![
+        Line::from(Span::styled(
+            video.title.as_str(),
+            Style::default()
+                .fg(Color::White)
+                .add_modifier(Modifier::BOLD),
+        )),
+        Line::from(vec![
+            Span::styled(video.channel.as_str(), Style::default().fg(Color::Cyan)),
+            Span::raw("  "),
+            Span::styled(video.format_duration(), Style::default().fg(Color::Magenta)),
+            Span::raw("  "),
+            Span::styled(video.format_date(), Style::default().fg(Color::Yellow)),
+            Span::raw("  "),
+            Span::styled(
+                format!(
+                    "{} views",
+                    video.format_views(app.number_style, &app.thousands_separator)
+                ),
+                Style::default().fg(Color::Gray),
+            ),
+        ]),
+    ];
+    let elsewhere: Vec<&str> = app
+        .video_locations(&video.id)
+        .into_iter()
+        .filter(|location| *location != app.active_tab().name())
+        .collect();
+    if !elsewhere.is_empty() {
+        lines.push(Line::from(Span::styled(
+            format!("Also in {}", elsewhere.join(", ")),
+            Style::default().fg(Color::Green),
+        )));
+    }
+    lines.push(Line::from(Span::styled(
+        video.description.as_str(),
+        Style::default().fg(Color::DarkGray),
+    )));
+
+    let paragraph = Paragraph::new(lines).wrap(Wrap { trim: true }).block(
+        Block::default()
+            .title(format!(
+                "Preview {}/{} ('['/']' to cycle, 'c' to select, 'Esc' to close)",
+                position, total
+            ))
+            .borders(Borders::ALL)
+            .style(Style::default().fg(Color::Yellow)),
+    );
+
+    Widget::render(Clear, area, buf);
+    Widget::render(paragraph, area, buf);
+}
@@ -20,22 +20,27 @@ use ratatui::{
 ///
 /// # Details
 /// Displays a search input bar with the current query.
-/// Highlights when in search mode or on Search tab.
-/// Shows search_query_global when on Search tab, otherwise shows search_query.
+/// Highlights when in search mode or on Search tab; the cursor is only shown
+/// in search mode, since queries are typed after `/`.
+/// In search mode shows the query of the search target (with a mode indicator
+/// in the title); otherwise shows search_query_global on the Search tab and
+/// the active tab's filter query elsewhere.
 pub fn render_search(app: &App, area: Rect, buf: &mut Buffer) {
     let is_active = app.mode == crate::app::UiMode::Search;
     let is_search_tab = app.active_tab() == crate::app::Tab::Search;
-    let prompt = if is_active || is_search_tab {
+    let prompt = if is_active {
         "Search: "
     } else {
         "Search (press '/'): "
     };
 
-    // Show search_query_global when on Search tab, otherwise show search_query
-    let query = if is_search_tab {
+    // Show search_query_global when on Search tab, otherwise the tab's filter query
+    let query = if is_active {
+        app.search_input()
+    } else if is_search_tab {
         &app.search_query_global
     } else {
-        &app.search_query
+        app.search_query()
     };
 
     let line = Line::from(vec![
@@ -49,14 +54,18 @@ pub fn render_search(app: &App, area: Rect, buf: &mut Buffer) {
             }),
         ),
         Span::styled(
-            if is_active || is_search_tab { "_" } else { "" },
+            if is_active { "_" } else { "" },
             Style::default().fg(Color::Yellow),
         ),
     ]);
 
     let paragraph = Paragraph::new(line).block(
         Block::default()
-            .title("Search")
+            .title(if is_active {
+                format!("Search [{}] (Tab to switch)", app.search_target.label())
+            } else {
+                "Search".to_string()
+            })
             .borders(Borders::ALL)
             .style(if is_active || is_search_tab {
                 Style::default().fg(Color::Yellow)
@@ -67,3 +76,25 @@ pub fn render_search(app: &App, area: Rect, buf: &mut Buffer) {
 
     Widget::render(paragraph, area, buf);
 }
+
+/// Render the search query as a single summary line (focus mode).
+///
+/// # Arguments
+/// * `app` - Application state
+/// * `area` - One-row area to render in
+/// * `buf` - Buffer to render to
+pub fn render_search_summary(app: &App, area: Rect, buf: &mut Buffer) {
+    let query = if app.active_tab() == crate::app::Tab::Search {
+        &app.search_query_global
+    } else {
+        app.search_query()
+    };
+    let line = Line::from(vec![
+        Span::styled("Search: ", Style::default().fg(Color::Yellow)),
+        Span::styled(
+            if query.is_empty() { "-" } else { query },
+            Style::default().fg(Color::Gray),
+        ),
+    ]);
+    Widget::render(Paragraph::new(line), area, buf);
+}
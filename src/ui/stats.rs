@@ -0,0 +1,50 @@
+//! Watch statistics popup rendering.
+//!
+//! Displays totals and the daily watch streak derived from history.
+
+use crate::app::App;
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Widget},
+};
+
+/// Render the statistics popup.
+///
+/// # Arguments
+/// * `app` - Application state
+/// * `area` - Area to render in (drawn over the list)
+/// * `buf` - Buffer to render to
+///
+/// # Details
+/// Shows the number of watched videos, days active and the current streak.
+pub fn render_stats(app: &App, area: Rect, buf: &mut Buffer) {
+    let streak = app.history.current_streak();
+    let stat_line = |label: &'static str, value: String| {
+        Line::from(vec![
+            Span::styled(label, Style::default().fg(Color::Cyan)),
+            Span::styled(value, Style::default().fg(Color::White)),
+        ])
+    };
+
+    let lines = vec![
+        stat_line("Watched videos: ", app.history.watched_count().to_string()),
+        stat_line("Days active: ", app.history.days_active().to_string()),
+        stat_line(
+            "Current streak: ",
+            format!("{} day{}", streak, if streak == 1 { "" } else { "s" }),
+        ),
+    ];
+
+    let paragraph = Paragraph::new(lines).block(
+        Block::default()
+            .title("Stats (press 'Esc' or 'S' to close)")
+            .borders(Borders::ALL)
+            .style(Style::default().fg(Color::Yellow)),
+    );
+
+    Widget::render(Clear, area, buf);
+    Widget::render(paragraph, area, buf);
+}
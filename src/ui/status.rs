@@ -0,0 +1,191 @@
+//! Status bar rendering.
+//!
+//! Displays the current status message on a single line.
+
+use crate::app::{App, UiMode};
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    text::Line,
+    widgets::{Paragraph, Widget},
+};
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+
+/// Help text shown when no status message is set.
+const DEFAULT_HELP: &str = "Press 'q' to quit, '/' to search, 'f' for filters, 'h' to toggle hide watched, 's' to change sort, '1-4' or Tab to switch tabs, 'L' for message log";
+
+/// Render the status bar widget.
+///
+/// # Arguments
+/// * `app` - Application state
+/// * `area` - Area to render in
+/// * `buf` - Buffer to render to
+///
+/// # Details
+/// Shows, in order of precedence:
+/// - the y/n question while playing or opening the marked videos waits
+/// - the prompt while a list position (`:`) or mpv arguments are typed
+/// - the current status message, or help text if none is set
+///
+/// The status message is preceded by the queue length while videos are
+/// queued, and followed by a spinner while a search, refresh or transcript is
+/// loading. Text wider than the area is truncated with an ellipsis; the full
+/// message remains available in the message log (`L`).
+pub fn render_status(app: &App, area: Rect, buf: &mut Buffer) {
+    if app.mode == UiMode::ConfirmPlay
+        && let Some(video) = app.selected_video_from_tab()
+    {
+        let question = format!("Play '{}'? (y/n)", video.title);
+        let text = truncate_to_width(&question, area.width as usize);
+        Widget::render(Paragraph::new(Line::from(text)), area, buf);
+        return;
+    }
+    if app.mode == UiMode::ConfirmOpenMarked {
+        let question = format!("Open {} videos? (y/n)", app.marked.len());
+        let text = truncate_to_width(&question, area.width as usize);
+        Widget::render(Paragraph::new(Line::from(text)), area, buf);
+        return;
+    }
+    if app.mode == UiMode::Goto {
+        let prompt = format!("Go to: {}_ (Enter to jump, Esc to cancel)", app.goto_input);
+        let text = truncate_to_width(&prompt, area.width as usize);
+        Widget::render(Paragraph::new(Line::from(text)), area, buf);
+        return;
+    }
+    if let Some(input) = &app.mpv_args_input {
+        let prompt = format!("mpv args (Enter to apply, Esc to cancel): {}_", input);
+        // Keep the end of long input (where the cursor is) visible
+        let text = truncate_start_to_width(&prompt, area.width as usize);
+        Widget::render(Paragraph::new(Line::from(text)), area, buf);
+        return;
+    }
+    let status_text = app.status_with_spinner();
+    let status_text = status_text.as_deref().unwrap_or(DEFAULT_HELP);
+    let status_text = if app.queue.is_empty() {
+        status_text.to_string()
+    } else {
+        format!("[Queue: {}] {}", app.queue.len(), status_text)
+    };
+    let text = truncate_to_width(&status_text, area.width as usize);
+    Widget::render(Paragraph::new(Line::from(text)), area, buf);
+}
+
+/// Truncate text to fit a display width, appending an ellipsis when cut.
+///
+/// # Arguments
+/// * `text` - Text to truncate
+/// * `width` - Maximum display width in terminal columns
+///
+/// # Returns
+/// * `String` - Text that fits within `width` columns
+///
+/// # Details
+/// Uses Unicode display width so wide characters (CJK, emoji) are measured correctly.
+pub fn truncate_to_width(text: &str, width: usize) -> String {
+    if text.width() <= width {
+        return text.to_string();
+    }
+    if width == 0 {
+        return String::new();
+    }
+
+    let ellipsis = '…';
+    let budget = width - ellipsis.width().unwrap_or(1);
+    let mut result = String::new();
+    let mut used = 0;
+    for ch in text.chars() {
+        let ch_width = ch.width().unwrap_or(0);
+        if used + ch_width > budget {
+            break;
+        }
+        used += ch_width;
+        result.push(ch);
+    }
+    result.push(ellipsis);
+    result
+}
+
+/// Truncate text to fit a display width from the start, keeping its end.
+///
+/// # Arguments
+/// * `text` - Text to truncate
+/// * `width` - Maximum display width in terminal columns
+///
+/// # Returns
+/// * `String` - Text that fits within `width` columns, starting with an
+///   ellipsis when cut
+///
+/// # Details
+/// Counterpart of `truncate_to_width` for input prompts, where the end
+/// (and the cursor) should stay visible.
+pub fn truncate_start_to_width(text: &str, width: usize) -> String {
+    if text.width() <= width {
+        return text.to_string();
+    }
+    if width == 0 {
+        return String::new();
+    }
+
+    let ellipsis = '…';
+    let budget = width - ellipsis.width().unwrap_or(1);
+    let mut used = 0;
+    let mut start = text.len();
+    for (index, ch) in text.char_indices().rev() {
+        let ch_width = ch.width().unwrap_or(0);
+        if used + ch_width > budget {
+            break;
+        }
+        used += ch_width;
+        start = index;
+    }
+    format!("{}{}", ellipsis, &text[start..])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_truncate_to_width() {
+        assert_eq!(truncate_to_width("short", 10), "short");
+        assert_eq!(truncate_to_width("exactly10!", 10), "exactly10!");
+        assert_eq!(truncate_to_width("this is too long", 10), "this is t…");
+        assert_eq!(truncate_to_width("anything", 0), "");
+        // Wide characters take two columns each
+        assert_eq!(truncate_to_width("日本語のテキスト", 7), "日本語…");
+    }
+
+    #[test]
+    fn test_truncate_start_to_width() {
+        assert_eq!(truncate_start_to_width("short", 10), "short");
+        assert_eq!(
+            truncate_start_to_width("this is too long", 10),
+            "… too long"
+        );
+        assert_eq!(truncate_start_to_width("anything", 0), "");
+        assert_eq!(truncate_start_to_width("日本語のテキスト_", 8), "…キスト_");
+    }
+
+    #[test]
+    fn test_confirm_play_prompt() {
+        let mut app = App::new(crate::history::History::default(), false);
+        app.set_videos(vec![crate::youtube::Video::new(
+            "vid".to_string(),
+            "My video".to_string(),
+            "Channel".to_string(),
+            "UCchan".to_string(),
+            String::new(),
+            60,
+            chrono::Utc::now(),
+            String::new(),
+            10,
+        )]);
+        app.mode = UiMode::ConfirmPlay;
+
+        let area = Rect::new(0, 0, 40, 1);
+        let mut buf = Buffer::empty(area);
+        render_status(&app, area, &mut buf);
+        let text: String = buf.content().iter().map(|cell| cell.symbol()).collect();
+        assert_eq!(text.trim_end(), "Play 'My video'? (y/n)");
+    }
+}
@@ -19,26 +19,22 @@ use ratatui::{
 /// * `buf` - Buffer to render to
 ///
 /// # Details
-/// Displays three tabs horizontally:
+/// Displays four tabs horizontally:
 /// - Current View
 /// - Search
 /// - History
+/// - Saved
 ///
-/// Highlights the active tab with different styling.
+/// Highlights the active tab with different styling. While MPV plays a
+/// video, its title and position are shown at the right of the top border.
 pub fn render_tabs(app: &App, area: Rect, buf: &mut Buffer) {
     let active_tab = app.active_tab();
 
-    // Create tab labels
-    let tabs = [
-        ("Current View", Tab::CurrentView),
-        ("Search", Tab::Search),
-        ("History", Tab::History),
-    ];
-
     // Build tab line with separators
     let mut spans = Vec::new();
-    for (i, (label, tab)) in tabs.iter().enumerate() {
-        let is_active = *tab == active_tab;
+    for (i, tab) in Tab::ALL.into_iter().enumerate() {
+        let label = tab.name();
+        let is_active = tab == active_tab;
         let style = if is_active {
             Style::default()
                 .fg(Color::Yellow)
@@ -64,8 +60,18 @@ pub fn render_tabs(app: &App, area: Rect, buf: &mut Buffer) {
 
     let line = Line::from(spans);
 
+    let mut block = Block::default().title("Tabs").borders(Borders::ALL);
+    if let Some(status) = &app.now_playing {
+        // Leave room for the "Tabs" title and the corners
+        let width = (area.width as usize).saturating_sub(10);
+        let text = crate::ui::status::truncate_to_width(&status.display(), width);
+        block = block.title_top(
+            Line::from(Span::styled(text, Style::default().fg(Color::Green))).right_aligned(),
+        );
+    }
+
     let paragraph = Paragraph::new(line)
-        .block(Block::default().title("Tabs").borders(Borders::ALL))
+        .block(block)
         .alignment(ratatui::layout::Alignment::Center);
 
     Widget::render(paragraph, area, buf);
@@ -0,0 +1,145 @@
+//! On-disk cache of video details.
+//!
+//! Keeps the details returned by videos.list in a JSON file so relaunches
+//! don't spend API quota on videos that were fetched recently.
+
+use crate::youtube::Video;
+use anyhow::{Context, Result};
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+
+/// A cached video and when it was fetched.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CacheEntry {
+    /// Time the details were fetched from the API
+    fetched_at: DateTime<Utc>,
+    /// Video details
+    video: Video,
+}
+
+/// Video details cache backed by a JSON file.
+#[derive(Debug, Clone)]
+pub struct VideoCache {
+    /// Cache file path
+    path: PathBuf,
+    /// Age after which entries are fetched again
+    ttl: Duration,
+}
+
+impl VideoCache {
+    /// Create a cache for a file.
+    ///
+    /// # Arguments
+    /// * `path` - Cache file path (created on the first store)
+    /// * `ttl_hours` - Hours entries stay valid
+    ///
+    /// # Returns
+    /// * `VideoCache` - Cache handle (the file is read on every lookup)
+    pub fn new(path: PathBuf, ttl_hours: u64) -> Self {
+        Self {
+            path,
+            ttl: Duration::hours(ttl_hours.min(i64::MAX as u64 / 3600) as i64),
+        }
+    }
+
+    /// Look up cached details.
+    ///
+    /// # Arguments
+    /// * `ids` - Video IDs to look up
+    ///
+    /// # Returns
+    /// * `HashMap<String, Video>` - Fresh cached videos by ID (missing and
+    ///   expired IDs are left out)
+    ///
+    /// # Details
+    /// A missing or unreadable cache file is treated as empty.
+    pub fn load_cached(&self, ids: &[String]) -> HashMap<String, Video> {
+        let now = Utc::now();
+        let mut entries = self.read_entries();
+        ids.iter()
+            .filter_map(|id| {
+                let entry = entries.remove(id)?;
+                (now - entry.fetched_at < self.ttl).then(|| (id.clone(), entry.video))
+            })
+            .collect()
+    }
+
+    /// Add freshly fetched videos to the cache.
+    ///
+    /// # Arguments
+    /// * `videos` - Videos returned by the API
+    ///
+    /// # Returns
+    /// * `Result<()>` - Success or error if the file can't be written
+    ///
+    /// # Details
+    /// Expired entries are dropped while the file is rewritten. Videos whose
+    /// details are missing are not cached.
+    pub fn store_cached(&self, videos: &[Video]) -> Result<()> {
+        let now = Utc::now();
+        let mut entries = self.read_entries();
+        entries.retain(|_, entry| now - entry.fetched_at < self.ttl);
+        for video in videos.iter().filter(|video| !video.details_missing) {
+            entries.insert(
+                video.id.clone(),
+                CacheEntry {
+                    fetched_at: now,
+                    video: video.clone(),
+                },
+            );
+        }
+
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent).with_context(|| {
+                format!("Failed to create cache directory: {}", parent.display())
+            })?;
+        }
+        let json = serde_json::to_string(&entries).context("Failed to serialize video cache")?;
+        fs::write(&self.path, json)
+            .with_context(|| format!("Failed to write video cache: {}", self.path.display()))
+    }
+
+    /// Read all entries from the cache file.
+    ///
+    /// # Returns
+    /// * `HashMap<String, CacheEntry>` - Entries by video ID (empty if the file
+    ///   is missing or invalid)
+    fn read_entries(&self) -> HashMap<String, CacheEntry> {
+        fs::read_to_string(&self.path)
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::youtube::models::test_video as video;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_store_and_load_cached() {
+        let temp_dir = TempDir::new().unwrap();
+        let cache = VideoCache::new(temp_dir.path().join("cache.json"), 24);
+        assert!(cache.load_cached(&["a".to_string()]).is_empty());
+
+        cache
+            .store_cached(&[video("a"), Video::id_only("b".to_string(), Utc::now())])
+            .unwrap();
+        let cached = cache.load_cached(&["a".to_string(), "b".to_string(), "c".to_string()]);
+        assert_eq!(cached.len(), 1);
+        assert_eq!(cached["a"].title, "Video a");
+    }
+
+    #[test]
+    fn test_expired_entries_ignored() {
+        let temp_dir = TempDir::new().unwrap();
+        let cache = VideoCache::new(temp_dir.path().join("cache.json"), 0);
+        cache.store_cached(&[video("a")]).unwrap();
+        assert!(cache.load_cached(&["a".to_string()]).is_empty());
+    }
+}
@@ -2,12 +2,123 @@
 //!
 //! Handles authentication and fetching of recommended videos.
 
-use crate::config::Config;
-use crate::youtube::models::{ApiActivityItem, ApiResponse, ApiVideoItem, Video};
+use crate::config::{Config, StoredTokens};
+use crate::oauth::{TOKEN_URL, parse_token_response};
+use crate::youtube::cache::VideoCache;
+use crate::youtube::models::{ApiActivityItem, ApiErrorResponse, ApiResponse, ApiVideoItem, Video};
+use crate::youtube::quota::QuotaTracker;
 use anyhow::{Context, Result};
-use reqwest::Client;
+use futures_util::{StreamExt, stream};
+use reqwest::{Client, RequestBuilder, Response, StatusCode};
+use std::collections::{HashMap, HashSet};
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
 use std::time::Duration;
 
+/// Quota cost of a search.list request.
+const SEARCH_QUOTA_COST: u64 = 100;
+/// Maximum number of IDs per videos.list request.
+const DETAILS_CHUNK_SIZE: usize = 50;
+/// Number of videos.list requests in flight at once.
+const DETAILS_CONCURRENCY: usize = 4;
+/// Quota cost of a videos.list or activities.list request.
+const LIST_QUOTA_COST: u64 = 1;
+/// Most results the API returns for one request (`maxResults`).
+pub const MAX_RESULTS_PER_REQUEST: u32 = 50;
+/// Wait before the first retry of a failed request; doubles with each retry.
+const RETRY_BASE_DELAY: Duration = Duration::from_millis(250);
+
+/// Result of validating the configured API key.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum KeyStatus {
+    /// Key works and quota is available
+    Valid,
+    /// Key was rejected by the API
+    KeyInvalid,
+    /// Key is valid but the daily quota is used up
+    QuotaExceeded,
+    /// Any other API error (HTTP status and message)
+    Other(u16, String),
+}
+
+impl KeyStatus {
+    /// Classify an API response from a key check.
+    ///
+    /// # Arguments
+    /// * `status` - HTTP status code
+    /// * `body` - Response body text
+    ///
+    /// # Returns
+    /// * `KeyStatus` - Classified outcome
+    ///
+    /// # Details
+    /// Looks at the `error.errors[].reason` entries of the error envelope.
+    /// Newer API versions report invalid keys as `badRequest` with an
+    /// "API key not valid" message, so the message is checked as well.
+    pub fn from_response(status: u16, body: &str) -> Self {
+        if (200..300).contains(&status) {
+            return KeyStatus::Valid;
+        }
+
+        let Ok(error) = serde_json::from_str::<ApiErrorResponse>(body) else {
+            return KeyStatus::Other(status, body.to_string());
+        };
+
+        if error.has_reason("keyInvalid") || error.error.message.contains("API key not valid") {
+            KeyStatus::KeyInvalid
+        } else if error.has_reason("quotaExceeded") || error.has_reason("dailyLimitExceeded") {
+            KeyStatus::QuotaExceeded
+        } else {
+            KeyStatus::Other(status, error.error.message)
+        }
+    }
+
+    /// Get a user-facing description of the outcome.
+    ///
+    /// # Returns
+    /// * `String` - Description suitable for the status bar or stderr
+    pub fn message(&self) -> String {
+        match self {
+            KeyStatus::Valid => "API key is valid".to_string(),
+            KeyStatus::KeyInvalid => {
+                "Invalid API key. Check api_key in config.jsonc and that YouTube Data API v3 is enabled".to_string()
+            }
+            KeyStatus::QuotaExceeded => {
+                "API quota exceeded. Try again tomorrow or add an OAuth client".to_string()
+            }
+            KeyStatus::Other(status, message) => {
+                format!("YouTube API error ({}): {}", status, message)
+            }
+        }
+    }
+}
+
+/// Failed YouTube API request, classified from the error body.
+///
+/// Displays the friendly `KeyStatus::message` (e.g. for an exceeded quota)
+/// instead of the raw JSON body.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("{}", .status.message())]
+pub struct ApiRequestError {
+    /// Classified outcome of the request
+    pub status: KeyStatus,
+}
+
+/// Build the error for an unsuccessful API response.
+///
+/// # Arguments
+/// * `status` - HTTP status of the response
+/// * `body` - Response body (the API's JSON error envelope)
+///
+/// # Returns
+/// * `anyhow::Error` - `ApiRequestError` with the classified outcome
+fn api_error(status: StatusCode, body: &str) -> anyhow::Error {
+    ApiRequestError {
+        status: KeyStatus::from_response(status.as_u16(), body),
+    }
+    .into()
+}
+
 /// YouTube Data API v3 client.
 ///
 /// Handles API requests for fetching recommended videos.
@@ -17,10 +128,37 @@ pub struct YouTubeClient {
     client: Client,
     /// API key for authentication
     api_key: String,
-    /// OAuth2 access token (if available)
-    access_token: Option<String>,
+    /// OAuth2 access and refresh token (shared between clones, so a refresh
+    /// in a background task is seen by all of them)
+    tokens: Arc<Mutex<StoredTokens>>,
+    /// OAuth2 client ID (for refreshing the access token)
+    oauth_client_id: Option<String>,
+    /// OAuth2 client secret (for refreshing the access token)
+    oauth_client_secret: Option<String>,
+    /// OAuth2 token endpoint
+    token_url: String,
+    /// Token file refreshed tokens are saved to
+    tokens_path: Option<PathBuf>,
     /// Base URL for YouTube Data API
     base_url: String,
+    /// Pad sparse personalized recommendations with trending videos
+    merge_trending_fallback: bool,
+    /// Thumbnail tiers in order of preference
+    thumbnail_order: Vec<String>,
+    /// Estimated quota units used today (shared between clones and runs)
+    quota: QuotaTracker,
+    /// Daily quota budget in units
+    daily_quota_budget: u64,
+    /// Keep raw API strings on videos (`--debug`)
+    debug: bool,
+    /// Disk cache of video details (None when disabled)
+    cache: Option<VideoCache>,
+    /// Retries of requests that failed to connect, timed out or got a 5xx
+    max_retries: u32,
+    /// Videos requested per fetch or search page
+    default_results: u32,
+    /// Problems that didn't fail a request, for the status bar (shared between clones)
+    warnings: Arc<Mutex<Vec<String>>>,
 }
 
 impl YouTubeClient {
@@ -34,7 +172,9 @@ impl YouTubeClient {
     ///
     /// # Details
     /// Requires at least an API key. OAuth2 tokens are optional but needed
-    /// for personalized recommendations.
+    /// for personalized recommendations. The details cache is off with
+    /// `cache_ttl_hours` set to 0 and in debug mode (cached videos carry no
+    /// raw API values).
     pub fn new(config: &Config) -> Result<Self> {
         if config.api_key.is_empty() {
             return Err(anyhow::anyhow!(
@@ -50,11 +190,346 @@ impl YouTubeClient {
         Ok(Self {
             client,
             api_key: config.api_key.clone(),
-            access_token: config.oauth_access_token.clone(),
+            tokens: Arc::new(Mutex::new(StoredTokens {
+                access_token: config.oauth_access_token.clone(),
+                refresh_token: config.oauth_refresh_token.clone(),
+            })),
+            oauth_client_id: config.oauth_client_id.clone(),
+            oauth_client_secret: config.oauth_client_secret.clone(),
+            token_url: TOKEN_URL.to_string(),
+            tokens_path: config.tokens_file_path().ok(),
             base_url: "https://www.googleapis.com/youtube/v3".to_string(),
+            merge_trending_fallback: config.merge_trending_fallback,
+            thumbnail_order: config.thumbnail_order.clone(),
+            quota: QuotaTracker::new(config.quota_file_path().ok()),
+            daily_quota_budget: config.daily_quota_budget,
+            debug: config.debug,
+            cache: (config.cache_ttl_hours > 0 && !config.debug)
+                .then(|| config.cache_file_path().ok())
+                .flatten()
+                .map(|path| VideoCache::new(path, config.cache_ttl_hours)),
+            max_retries: config.max_retries,
+            default_results: config.default_results.clamp(1, MAX_RESULTS_PER_REQUEST),
+            warnings: Arc::default(),
         })
     }
 
+    /// Record a problem that didn't fail the request.
+    ///
+    /// # Arguments
+    /// * `message` - Message for the status bar
+    fn warn(&self, message: String) {
+        self.warnings
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .push(message);
+    }
+
+    /// Report items of a response that couldn't be parsed.
+    ///
+    /// # Arguments
+    /// * `skipped` - Number of skipped items (nothing is reported for 0)
+    /// * `what` - Kind of item, e.g. "videos"
+    fn warn_skipped(&self, skipped: usize, what: &str) {
+        if skipped > 0 {
+            self.warn(format!(
+                "Skipped {} malformed {} in the YouTube API response",
+                skipped, what
+            ));
+        }
+    }
+
+    /// Convert the items of a videos.list response.
+    ///
+    /// # Arguments
+    /// * `response` - Parsed response
+    ///
+    /// # Returns
+    /// * `Vec<Video>` - Videos in response order
+    ///
+    /// # Details
+    /// Items that can't be converted are left out and reported together with
+    /// those `ApiResponse::parse` already skipped (see `warn_skipped`).
+    fn videos_from_response(&self, response: ApiResponse<ApiVideoItem>) -> Vec<Video> {
+        let mut skipped = response.skipped;
+        let videos = response
+            .items
+            .into_iter()
+            .filter_map(|item| {
+                Video::from_api_item(item, &self.thumbnail_order, self.debug)
+                    .map_err(|_| skipped += 1)
+                    .ok()
+            })
+            .collect();
+        self.warn_skipped(skipped, "videos");
+        videos
+    }
+
+    /// Take the problems recorded since the last call.
+    ///
+    /// # Returns
+    /// * `Vec<String>` - Messages in the order they happened (printing to
+    ///   stderr would draw over the TUI, so the caller shows them)
+    pub fn take_warnings(&self) -> Vec<String> {
+        std::mem::take(
+            &mut *self
+                .warnings
+                .lock()
+                .unwrap_or_else(|poisoned| poisoned.into_inner()),
+        )
+    }
+
+    /// Lock the OAuth tokens.
+    ///
+    /// # Returns
+    /// * `MutexGuard<StoredTokens>` - Tokens shared by all clones
+    fn tokens(&self) -> std::sync::MutexGuard<'_, StoredTokens> {
+        self.tokens
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// Get the current OAuth access token.
+    ///
+    /// # Returns
+    /// * `Option<String>` - Access token (updated by `refresh_access_token`)
+    pub fn access_token(&self) -> Option<String> {
+        self.tokens().access_token.clone()
+    }
+
+    /// Get a new access token with the refresh token and save it.
+    ///
+    /// # Returns
+    /// * `Result<()>` - Success, or error if credentials are missing, the token
+    ///   endpoint refuses, or the token file can't be written
+    ///
+    /// # Details
+    /// Posts a `refresh_token` grant to Google's token endpoint with
+    /// `oauth_client_id` and `oauth_client_secret`. The new token is used right
+    /// away by this client and all its clones, and written to the token file
+    /// (see `Config::tokens_file_path`), leaving the config file untouched. A
+    /// rotated refresh token is stored as well.
+    pub async fn refresh_access_token(&self) -> Result<()> {
+        let refresh_token = self.tokens().refresh_token.clone();
+        let (Some(client_id), Some(client_secret), Some(refresh_token)) = (
+            self.oauth_client_id.as_deref(),
+            self.oauth_client_secret.as_deref(),
+            refresh_token.as_deref(),
+        ) else {
+            return Err(anyhow::anyhow!(
+                "oauth_client_id, oauth_client_secret and oauth_refresh_token are required to refresh the access token"
+            ));
+        };
+
+        let response = self
+            .client
+            .post(&self.token_url)
+            .form(&[
+                ("client_id", client_id),
+                ("client_secret", client_secret),
+                ("refresh_token", refresh_token),
+                ("grant_type", "refresh_token"),
+            ])
+            .send()
+            .await
+            .context("Failed to reach the OAuth token endpoint")?;
+        let status = response.status().as_u16();
+        let body = response
+            .text()
+            .await
+            .context("Failed to read token response")?;
+        let tokens = parse_token_response(status, &body)?;
+
+        let stored = {
+            let mut stored = self.tokens();
+            stored.access_token = Some(tokens.access_token);
+            if tokens.refresh_token.is_some() {
+                stored.refresh_token = tokens.refresh_token;
+            }
+            stored.clone()
+        };
+        if let Some(path) = &self.tokens_path {
+            stored.save(path)?;
+        }
+        Ok(())
+    }
+
+    /// Build a GET request authorized as the user or with the API key.
+    ///
+    /// # Arguments
+    /// * `url` - Endpoint URL
+    /// * `params` - Query parameters (without `key`)
+    /// * `as_user` - Send the OAuth access token (if any) instead of the API key
+    ///
+    /// # Returns
+    /// * `RequestBuilder` - Request ready to send
+    fn authorized_request(
+        &self,
+        url: &str,
+        params: &[(&str, &str)],
+        as_user: bool,
+    ) -> RequestBuilder {
+        let request = self.client.get(url).query(params);
+        match self.access_token() {
+            Some(token) if as_user => request.bearer_auth(token),
+            _ => request.query(&[("key", &self.api_key)]),
+        }
+    }
+
+    /// Send a request, retrying transient failures with exponential backoff.
+    ///
+    /// # Arguments
+    /// * `request` - Request to send
+    ///
+    /// # Returns
+    /// * `reqwest::Result<Response>` - Response or transport error of the last attempt
+    ///
+    /// # Details
+    /// Connection errors, timeouts and 5xx responses are retried up to
+    /// `max_retries` times, waiting 250ms, 500ms, 1s, ... in between. Other
+    /// errors and 4xx responses are returned right away.
+    async fn send_with_retry(&self, request: RequestBuilder) -> reqwest::Result<Response> {
+        let mut attempt = 0;
+        loop {
+            let retry = if attempt < self.max_retries {
+                request.try_clone()
+            } else {
+                None
+            };
+            let Some(retry) = retry else {
+                return request.send().await;
+            };
+            match retry.send().await {
+                Ok(response) if !response.status().is_server_error() => return Ok(response),
+                Err(e) if !e.is_connect() && !e.is_timeout() => return Err(e),
+                _ => {}
+            }
+            tokio::time::sleep(retry_delay(attempt)).await;
+            attempt += 1;
+        }
+    }
+
+    /// Send a GET request as the user when signed in, falling back to the API key.
+    ///
+    /// # Arguments
+    /// * `url` - Endpoint URL
+    /// * `params` - Query parameters (without `key`)
+    ///
+    /// # Returns
+    /// * `reqwest::Result<Response>` - Response or transport error
+    ///
+    /// # Details
+    /// With an OAuth access token, the user's own private and unlisted videos
+    /// resolve. If the token is rejected (401, e.g. expired), the request is
+    /// repeated with the API key so public videos still load.
+    async fn send_as_user(&self, url: &str, params: &[(&str, &str)]) -> reqwest::Result<Response> {
+        if self.access_token().is_some() {
+            let response = self
+                .send_with_retry(self.authorized_request(url, params, true))
+                .await?;
+            if response.status() != StatusCode::UNAUTHORIZED {
+                return Ok(response);
+            }
+            self.record_quota(LIST_QUOTA_COST);
+        }
+        self.send_with_retry(self.authorized_request(url, params, false))
+            .await
+    }
+
+    /// Add the cost of a request to the estimated quota usage.
+    ///
+    /// # Arguments
+    /// * `units` - Quota cost of the request
+    ///
+    /// # Details
+    /// A usage file that can't be written only loses the estimate for later runs.
+    fn record_quota(&self, units: u64) {
+        let _ = self.quota.record(units);
+    }
+
+    /// Get the estimated quota units used today.
+    ///
+    /// # Returns
+    /// * `u64` - Sum of the documented costs of all requests sent since the
+    ///   last quota reset, by this and earlier runs
+    pub fn quota_used(&self) -> u64 {
+        self.quota.used()
+    }
+
+    /// Get the daily quota budget.
+    ///
+    /// # Returns
+    /// * `u64` - Budget in quota units (`daily_quota_budget` config option)
+    pub fn quota_budget(&self) -> u64 {
+        self.daily_quota_budget
+    }
+
+    /// Get the number of videos requested per fetch or search page.
+    ///
+    /// # Returns
+    /// * `u32` - `default_results` config option, clamped to
+    ///   `1..=MAX_RESULTS_PER_REQUEST`
+    ///
+    /// # Details
+    /// Trending (`chart=mostPopular`) and search return at most 50 videos
+    /// per request, so larger values are clamped rather than paginated;
+    /// more search results can still be loaded page by page.
+    pub fn default_results(&self) -> u32 {
+        self.default_results
+    }
+
+    /// Check whether the quota budget leaves room for a search.
+    ///
+    /// # Returns
+    /// * `bool` - Whether a search fits the estimated remaining quota (see
+    ///   `search_within_budget`)
+    pub fn can_search(&self) -> bool {
+        search_within_budget(self.quota_used(), self.daily_quota_budget)
+    }
+
+    /// Get the number of results to request for a search page.
+    ///
+    /// # Arguments
+    /// * `requested` - Results the caller would like (usually `default_results`)
+    ///
+    /// # Returns
+    /// * `u32` - `requested`, lowered as the estimated remaining quota shrinks
+    ///   (see `budget_max_results`)
+    pub fn effective_max_results(&self, requested: u32) -> u32 {
+        budget_max_results(requested, self.quota_used(), self.daily_quota_budget)
+    }
+
+    /// Validate the API key with a minimal request.
+    ///
+    /// # Returns
+    /// * `Result<KeyStatus>` - Key status, or error if the API could not be reached
+    ///
+    /// # Details
+    /// Requests a single trending video ID (`part=id`, `maxResults=1`), which costs
+    /// one quota unit and gives fast feedback before the full fetch.
+    pub async fn validate_key(&self) -> Result<KeyStatus> {
+        let url = format!("{}/videos", self.base_url);
+        let params = [
+            ("part", "id"),
+            ("chart", "mostPopular"),
+            ("maxResults", "1"),
+            ("key", &self.api_key),
+        ];
+
+        self.record_quota(LIST_QUOTA_COST);
+        let response = self
+            .client
+            .get(&url)
+            .query(&params)
+            .send()
+            .await
+            .context("Failed to reach YouTube API")?;
+
+        let status = response.status().as_u16();
+        let body = response.text().await.unwrap_or_default();
+        Ok(KeyStatus::from_response(status, &body))
+    }
+
     /// Fetch recommended videos from YouTube.
     ///
     /// # Arguments
@@ -67,11 +542,20 @@ impl YouTubeClient {
     /// Uses the activities.list endpoint with home channel to get recommendations.
     /// If OAuth2 is available, uses authenticated requests for personalized recommendations.
     /// Otherwise, falls back to search.list with trending videos.
+    /// With `merge_trending_fallback`, fewer than `max_results` personalized videos
+    /// are padded with trending ones.
     pub async fn fetch_recommended_videos(&self, max_results: u32) -> Result<Vec<Video>> {
         // Try to get personalized recommendations if OAuth is available
-        if self.access_token.is_some()
+        if self.access_token().is_some()
             && let Ok(videos) = self.fetch_personalized_recommendations(max_results).await
         {
+            if self.merge_trending_fallback && videos.len() < max_results as usize {
+                return match self.fetch_trending_videos(max_results).await {
+                    Ok(trending) => Ok(pad_with_fallback(videos, trending, max_results as usize)),
+                    // Padding is best effort; keep what we have
+                    Err(_) => Ok(videos),
+                };
+            }
             return Ok(videos);
         }
 
@@ -89,16 +573,20 @@ impl YouTubeClient {
     ///
     /// # Details
     /// Requires OAuth2 authentication. Fetches activities from "home" channel.
+    /// When the access token is rejected (401, e.g. expired), it is refreshed
+    /// once with `refresh_access_token` (unless another task already did) and
+    /// the request is repeated.
     async fn fetch_personalized_recommendations(&self, max_results: u32) -> Result<Vec<Video>> {
-        let access_token = self.access_token.as_ref().ok_or_else(|| {
-            anyhow::anyhow!("OAuth access token required for personalized recommendations")
-        })?;
-
         let url = format!("{}/activities", self.base_url);
         let mut videos = Vec::new();
         let mut page_token: Option<String> = None;
+        let mut refreshed = false;
 
         loop {
+            let access_token = self.access_token().ok_or_else(|| {
+                anyhow::anyhow!("OAuth access token required for personalized recommendations")
+            })?;
+
             let mut params = vec![
                 ("part", "snippet,contentDetails"),
                 ("home", "true"),
@@ -109,54 +597,72 @@ impl YouTubeClient {
                 params.push(("pageToken", token));
             }
 
+            self.record_quota(LIST_QUOTA_COST);
             let response = self
-                .client
-                .get(&url)
-                .bearer_auth(access_token)
-                .query(&params)
-                .send()
+                .send_with_retry(
+                    self.client
+                        .get(&url)
+                        .bearer_auth(&access_token)
+                        .query(&params),
+                )
                 .await
                 .context("Failed to fetch activities from YouTube API")?;
 
             let status = response.status();
+            if status == StatusCode::UNAUTHORIZED
+                && !refreshed
+                && self.tokens().refresh_token.is_some()
+            {
+                refreshed = true;
+                if self.access_token().as_deref() == Some(access_token.as_str()) {
+                    self.refresh_access_token()
+                        .await
+                        .context("Failed to refresh the OAuth access token")?;
+                }
+                continue;
+            }
             if !status.is_success() {
                 let error_text = response.text().await.unwrap_or_default();
-                return Err(anyhow::anyhow!(
-                    "YouTube API error ({}): {}",
-                    status,
-                    error_text
-                ));
+                return Err(api_error(status, &error_text));
             }
 
-            let api_response: ApiResponse<ApiActivityItem> = response
-                .json()
+            let body = response
+                .text()
                 .await
-                .context("Failed to parse activities response")?;
+                .context("Failed to read activities response")?;
+            let api_response: ApiResponse<ApiActivityItem> =
+                ApiResponse::parse(&body).context("Failed to parse activities response")?;
+            self.warn_skipped(api_response.skipped, "activities");
 
-            // Extract video IDs from recommendations
-            let video_ids: Vec<String> = api_response
+            // Extract video IDs and reasons from recommendations
+            let recommendations: Vec<(String, Option<String>)> = api_response
                 .items
                 .iter()
                 .filter_map(|activity| {
-                    activity
+                    let recommendation = activity
                         .snippet
                         .content_details
                         .as_ref()?
                         .recommendation
-                        .as_ref()?
-                        .resource_id
-                        .video_id
-                        .clone()
-                        .into()
+                        .as_ref()?;
+                    Some((
+                        recommendation.resource_id.video_id.clone(),
+                        recommendation.reason_text(),
+                    ))
                 })
                 .collect();
+            let video_ids: Vec<String> = recommendations.iter().map(|(id, _)| id.clone()).collect();
+            let reasons: HashMap<String, Option<String>> = recommendations.into_iter().collect();
 
             if video_ids.is_empty() {
                 break;
             }
 
-            // Fetch video details
-            let video_details = self.fetch_video_details(&video_ids).await?;
+            // Fetch video details and carry the reasons over
+            let mut video_details = self.fetch_video_details(&video_ids).await?;
+            for video in &mut video_details {
+                video.reason = reasons.get(&video.id).cloned().flatten();
+            }
             videos.extend(video_details);
 
             if videos.len() >= max_results as usize {
@@ -194,44 +700,67 @@ impl YouTubeClient {
             ("key", &self.api_key),
         ];
 
+        self.record_quota(LIST_QUOTA_COST);
         let response = self
-            .client
-            .get(&url)
-            .query(&params)
-            .send()
+            .send_with_retry(self.client.get(&url).query(&params))
             .await
             .context("Failed to fetch trending videos from YouTube API")?;
 
         let status = response.status();
         if !status.is_success() {
             let error_text = response.text().await.unwrap_or_default();
-            return Err(anyhow::anyhow!(
-                "YouTube API error ({}): {}",
-                status,
-                error_text
-            ));
+            return Err(api_error(status, &error_text));
         }
 
-        let api_response: ApiResponse<ApiVideoItem> = response
-            .json()
+        let body = response
+            .text()
             .await
-            .context("Failed to parse trending videos response")?;
+            .context("Failed to read trending videos response")?;
+        let api_response: ApiResponse<ApiVideoItem> =
+            ApiResponse::parse(&body).context("Failed to parse trending videos response")?;
 
-        let mut videos = Vec::new();
-        for item in api_response.items {
-            match Video::try_from(item) {
-                Ok(video) => videos.push(video),
-                Err(e) => {
-                    eprintln!("Failed to parse video: {}", e);
-                    // Continue with other videos
-                }
+        Ok(self.videos_from_response(api_response))
+    }
+
+    /// Fetch detailed information for a list of video IDs.
+    ///
+    /// # Arguments
+    /// * `video_ids` - List of YouTube video IDs
+    ///
+    /// # Returns
+    /// * `Result<Vec<Video>>` - List of video details or error
+    ///
+    /// # Details
+    /// Videos fetched within `cache_ttl_hours` come from the disk cache; only
+    /// the other IDs are requested, and the results are added to the cache (a
+    /// failed write is reported with `take_warnings`). Videos are returned in
+    /// the order of `video_ids`.
+    async fn fetch_video_details(&self, video_ids: &[String]) -> Result<Vec<Video>> {
+        let Some(cache) = &self.cache else {
+            return self.fetch_video_details_uncached(video_ids).await;
+        };
+
+        let mut videos = cache.load_cached(video_ids);
+        let missing: Vec<String> = video_ids
+            .iter()
+            .filter(|id| !videos.contains_key(*id))
+            .cloned()
+            .collect();
+        if !missing.is_empty() {
+            let fetched = self.fetch_video_details_uncached(&missing).await?;
+            if let Err(e) = cache.store_cached(&fetched) {
+                self.warn(format!("Failed to update video cache: {:#}", e));
             }
+            videos.extend(fetched.into_iter().map(|video| (video.id.clone(), video)));
         }
 
-        Ok(videos)
+        Ok(video_ids
+            .iter()
+            .filter_map(|id| videos.remove(id))
+            .collect())
     }
 
-    /// Fetch detailed information for a list of video IDs.
+    /// Fetch detailed information for a list of video IDs from the API.
     ///
     /// # Arguments
     /// * `video_ids` - List of YouTube video IDs
@@ -241,95 +770,103 @@ impl YouTubeClient {
     ///
     /// # Details
     /// Uses videos.list endpoint to get full video details including duration and statistics.
-    async fn fetch_video_details(&self, video_ids: &[String]) -> Result<Vec<Video>> {
-        // YouTube API limits to 50 IDs per request
-        let chunk_size = 50;
-        let mut all_videos = Vec::new();
-
-        for chunk in video_ids.chunks(chunk_size) {
-            let ids = chunk.join(",");
-            let url = format!("{}/videos", self.base_url);
-            let params = [
-                ("part", "snippet,contentDetails,statistics"),
-                ("id", &ids),
-                ("key", &self.api_key),
-            ];
+    /// Authorizes as the user when signed in (see `send_as_user`). Requests for
+    /// chunks of 50 IDs run concurrently (see `fetch_in_chunks`); when only
+    /// some of them fail, the number of videos left out is reported with
+    /// `take_warnings`.
+    async fn fetch_video_details_uncached(&self, video_ids: &[String]) -> Result<Vec<Video>> {
+        let (videos, failed) = fetch_in_chunks(video_ids, |ids| async move {
+            self.fetch_details_chunk(&ids).await
+        })
+        .await?;
+        if let Some((missing, e)) = failed {
+            self.warn(format!(
+                "Failed to load details of {} of {} videos: {:#}",
+                missing,
+                video_ids.len(),
+                e
+            ));
+        }
+        Ok(videos)
+    }
 
-            let response = self
-                .client
-                .get(&url)
-                .query(&params)
-                .send()
-                .await
-                .context("Failed to fetch video details from YouTube API")?;
+    /// Fetch details for up to 50 videos with one videos.list request.
+    ///
+    /// # Arguments
+    /// * `video_ids` - IDs to fetch (at most `DETAILS_CHUNK_SIZE`)
+    ///
+    /// # Returns
+    /// * `Result<Vec<Video>>` - Videos that could be parsed, in response order
+    async fn fetch_details_chunk(&self, video_ids: &[String]) -> Result<Vec<Video>> {
+        let ids = video_ids.join(",");
+        let url = format!("{}/videos", self.base_url);
+        let params = [("part", "snippet,contentDetails,statistics"), ("id", &ids)];
 
-            let status = response.status();
-            if !status.is_success() {
-                let error_text = response.text().await.unwrap_or_default();
-                return Err(anyhow::anyhow!(
-                    "YouTube API error ({}): {}",
-                    status,
-                    error_text
-                ));
-            }
+        self.record_quota(LIST_QUOTA_COST);
+        let response = self
+            .send_as_user(&url, &params)
+            .await
+            .context("Failed to fetch video details from YouTube API")?;
 
-            let api_response: ApiResponse<ApiVideoItem> = response
-                .json()
-                .await
-                .context("Failed to parse video details response")?;
-
-            for item in api_response.items {
-                match Video::try_from(item) {
-                    Ok(video) => all_videos.push(video),
-                    Err(e) => {
-                        eprintln!("Failed to parse video: {}", e);
-                        // Continue with other videos
-                    }
-                }
-            }
+        let status = response.status();
+        if !status.is_success() {
+            let error_text = response.text().await.unwrap_or_default();
+            return Err(api_error(status, &error_text));
         }
 
-        Ok(all_videos)
+        let body = response
+            .text()
+            .await
+            .context("Failed to read video details response")?;
+        let api_response: ApiResponse<ApiVideoItem> =
+            ApiResponse::parse(&body).context("Failed to parse video details response")?;
+
+        Ok(self.videos_from_response(api_response))
     }
 
-    /// Search for videos on YouTube platform.
+    /// Search for one page of videos on YouTube platform.
     ///
     /// # Arguments
     /// * `query` - Search query string
     /// * `max_results` - Maximum number of videos to return
+    /// * `page_token` - `nextPageToken` of the previous page (None for the first page)
     ///
     /// # Returns
-    /// * `Result<Vec<Video>>` - List of videos matching the search query
+    /// * `Result<(Vec<Video>, Option<String>)>` - Videos matching the search query
+    ///   and the token of the next page (None on the last page)
     ///
     /// # Details
     /// Uses the search.list endpoint to search YouTube for videos.
     /// Fetches full video details including duration and statistics.
-    pub async fn search_videos(&self, query: &str, max_results: u32) -> Result<Vec<Video>> {
+    pub async fn search_videos_page(
+        &self,
+        query: &str,
+        max_results: u32,
+        page_token: Option<&str>,
+    ) -> Result<(Vec<Video>, Option<String>)> {
         let url = format!("{}/search", self.base_url);
-        let params = [
+        let max_results = max_results.to_string();
+        let mut params = vec![
             ("part", "snippet"),
             ("type", "video"),
             ("q", query),
-            ("maxResults", &max_results.to_string()),
-            ("key", &self.api_key),
+            ("maxResults", max_results.as_str()),
+            ("key", self.api_key.as_str()),
         ];
+        if let Some(token) = page_token {
+            params.push(("pageToken", token));
+        }
 
+        self.record_quota(SEARCH_QUOTA_COST);
         let response = self
-            .client
-            .get(&url)
-            .query(&params)
-            .send()
+            .send_with_retry(self.client.get(&url).query(&params))
             .await
             .context("Failed to search videos from YouTube API")?;
 
         let status = response.status();
         if !status.is_success() {
             let error_text = response.text().await.unwrap_or_default();
-            return Err(anyhow::anyhow!(
-                "YouTube API error ({}): {}",
-                status,
-                error_text
-            ));
+            return Err(api_error(status, &error_text));
         }
 
         #[derive(Debug, serde::Deserialize)]
@@ -345,10 +882,13 @@ impl YouTubeClient {
             video_id: String,
         }
 
-        let api_response: ApiResponse<ApiSearchItem> = response
-            .json()
+        let body = response
+            .text()
             .await
-            .context("Failed to parse search response")?;
+            .context("Failed to read search response")?;
+        let api_response: ApiResponse<ApiSearchItem> =
+            ApiResponse::parse(&body).context("Failed to parse search response")?;
+        self.warn_skipped(api_response.skipped, "search results");
 
         // Extract video IDs
         let video_ids: Vec<String> = api_response
@@ -356,13 +896,15 @@ impl YouTubeClient {
             .iter()
             .map(|item| item.id.video_id.clone())
             .collect();
+        let next_page_token = api_response.next_page_token;
 
         if video_ids.is_empty() {
-            return Ok(Vec::new());
+            return Ok((Vec::new(), next_page_token));
         }
 
         // Fetch full video details
-        self.fetch_video_details(&video_ids).await
+        let videos = self.fetch_video_details(&video_ids).await?;
+        Ok((videos, next_page_token))
     }
 
     /// Fetch video details for history videos.
@@ -374,7 +916,8 @@ impl YouTubeClient {
     /// * `Result<Vec<Video>>` - List of video details
     ///
     /// # Details
-    /// Reuses fetch_video_details to get full video information.
+    /// Reuses fetch_video_details to get full video information, so with OAuth
+    /// the user's own private and unlisted videos resolve too.
     pub async fn fetch_history_videos(&self, video_ids: &[String]) -> Result<Vec<Video>> {
         if video_ids.is_empty() {
             return Ok(Vec::new());
@@ -383,10 +926,156 @@ impl YouTubeClient {
     }
 }
 
+/// Fetch videos in chunks of `DETAILS_CHUNK_SIZE` IDs, several at a time.
+///
+/// # Arguments
+/// * `video_ids` - IDs to fetch
+/// * `fetch` - Fetches one chunk of IDs (owned, which keeps the futures `Send`)
+///
+/// # Returns
+/// * `Result<(Vec<Video>, Option<(usize, anyhow::Error)>)>` - Videos of all
+///   chunks in the order of `video_ids`, and for failed chunks the number of
+///   IDs they held and the first error
+///
+/// # Details
+/// Runs up to `DETAILS_CONCURRENCY` chunk requests concurrently. Failed
+/// chunks are skipped so the others still show up; the first error is only
+/// returned as an error when every chunk failed.
+async fn fetch_in_chunks<F, Fut>(
+    video_ids: &[String],
+    fetch: F,
+) -> Result<(Vec<Video>, Option<(usize, anyhow::Error)>)>
+where
+    F: Fn(Vec<String>) -> Fut,
+    Fut: Future<Output = Result<Vec<Video>>>,
+{
+    // Futures are lazy: building them all up front doesn't start any request
+    let chunks: Vec<&[String]> = video_ids.chunks(DETAILS_CHUNK_SIZE).collect();
+    let requests: Vec<Fut> = chunks.iter().map(|chunk| fetch(chunk.to_vec())).collect();
+    let results: Vec<Result<Vec<Video>>> = stream::iter(requests)
+        .buffered(DETAILS_CONCURRENCY)
+        .collect()
+        .await;
+
+    let mut videos = Vec::new();
+    let mut first_error = None;
+    let mut missing = 0;
+    let mut any_ok = false;
+    for (chunk, result) in chunks.iter().zip(results) {
+        match result {
+            Ok(fetched) => {
+                any_ok = true;
+                videos.extend(fetched);
+            }
+            Err(e) => {
+                missing += chunk.len();
+                first_error.get_or_insert(e);
+            }
+        }
+    }
+    match first_error {
+        Some(e) if !any_ok => Err(e),
+        failed => Ok((videos, failed.map(|e| (missing, e)))),
+    }
+}
+
+/// Get the wait before a retry.
+///
+/// # Arguments
+/// * `attempt` - Number of attempts that already failed minus one (0 for the first retry)
+///
+/// # Returns
+/// * `Duration` - `RETRY_BASE_DELAY` doubled per earlier retry (250ms, 500ms, 1s, ...)
+fn retry_delay(attempt: u32) -> Duration {
+    RETRY_BASE_DELAY * 2u32.saturating_pow(attempt.min(10))
+}
+
+/// Check whether a search fits the remaining quota budget.
+///
+/// # Arguments
+/// * `used` - Estimated quota units used today
+/// * `budget` - Daily quota budget (0 disables the check)
+///
+/// # Returns
+/// * `bool` - Whether a search leaves at least a tenth of the budget
+///
+/// # Details
+/// search.list costs the same for any `maxResults`, so searches are skipped
+/// rather than shrunk; the reserve keeps cheap list requests (playlists,
+/// details, refreshes) working for the rest of the day.
+fn search_within_budget(used: u64, budget: u64) -> bool {
+    budget == 0 || used.saturating_add(SEARCH_QUOTA_COST) <= budget - budget / 10
+}
+
+/// Lower a search result count as the quota budget runs out.
+///
+/// # Arguments
+/// * `requested` - Results the caller would like
+/// * `used` - Estimated quota units used today
+/// * `budget` - Daily quota budget (0 disables the reduction)
+///
+/// # Returns
+/// * `u32` - `requested` while at least half the budget is left, half of it
+///   down to a quarter left, a quarter down to a tenth, and a tenth below
+///   that (never less than 1)
+///
+/// # Details
+/// Fewer results mean fewer video detail requests per search and show that
+/// the budget is running out well before searches are skipped (see
+/// `search_within_budget`).
+fn budget_max_results(requested: u32, used: u64, budget: u64) -> u32 {
+    if budget == 0 {
+        return requested;
+    }
+    let remaining_percent = budget.saturating_sub(used) * 100 / budget;
+    let reduced = match remaining_percent {
+        50.. => requested,
+        25..=49 => requested / 2,
+        10..=24 => requested / 4,
+        _ => requested / 10,
+    };
+    reduced.max(1)
+}
+
+/// Pad a list of videos with fallback videos up to a target count.
+///
+/// # Arguments
+/// * `primary` - Videos to keep, in order
+/// * `fallback` - Videos to append from, in order
+/// * `target` - Desired number of videos
+///
+/// # Returns
+/// * `Vec<Video>` - `primary` followed by fallback videos not already present, at most `target` long
+///
+/// # Details
+/// Primary videos are never dropped, even if there are more than `target`.
+fn pad_with_fallback(mut primary: Vec<Video>, fallback: Vec<Video>, target: usize) -> Vec<Video> {
+    let mut seen: HashSet<String> = primary.iter().map(|v| v.id.clone()).collect();
+    for video in fallback {
+        if primary.len() >= target {
+            break;
+        }
+        if seen.insert(video.id.clone()) {
+            primary.push(video);
+        }
+    }
+    primary
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::config::Config;
+    use crate::youtube::models::test_video as video;
+
+    /// Build a client that touches no files in the user's config directory.
+    fn test_client(config: Config) -> YouTubeClient {
+        let mut client = YouTubeClient::new(&config).unwrap();
+        client.quota = QuotaTracker::new(None);
+        client.tokens_path = None;
+        client.cache = None;
+        client
+    }
 
     #[test]
     fn test_client_new_requires_api_key() {
@@ -402,4 +1091,456 @@ mod tests {
         };
         assert!(YouTubeClient::new(&config).is_ok());
     }
+
+    #[test]
+    fn test_default_results_clamped() {
+        let results = |default_results| {
+            test_client(Config {
+                api_key: "test_key".to_string(),
+                default_results,
+                ..Config::default()
+            })
+            .default_results()
+        };
+        assert_eq!(results(20), 20);
+        assert_eq!(results(50), 50);
+        assert_eq!(results(200), MAX_RESULTS_PER_REQUEST);
+        assert_eq!(results(0), 1);
+    }
+
+    #[test]
+    fn test_authorized_request_prefers_bearer() {
+        let config = Config {
+            api_key: "test_key".to_string(),
+            oauth_access_token: Some("token".to_string()),
+            ..Config::default()
+        };
+        let client = test_client(config.clone());
+        let params = [("id", "abc")];
+
+        let request = client
+            .authorized_request("https://example.com/videos", &params, true)
+            .build()
+            .unwrap();
+        assert_eq!(
+            request.headers()[reqwest::header::AUTHORIZATION],
+            "Bearer token"
+        );
+        assert_eq!(request.url().query(), Some("id=abc"));
+
+        // API key fallback
+        let request = client
+            .authorized_request("https://example.com/videos", &params, false)
+            .build()
+            .unwrap();
+        assert!(
+            request
+                .headers()
+                .get(reqwest::header::AUTHORIZATION)
+                .is_none()
+        );
+        assert_eq!(request.url().query(), Some("id=abc&key=test_key"));
+
+        // Without a token the API key is used even as the user
+        let client = test_client(Config {
+            oauth_access_token: None,
+            ..config
+        });
+        let request = client
+            .authorized_request("https://example.com/videos", &params, true)
+            .build()
+            .unwrap();
+        assert_eq!(request.url().query(), Some("id=abc&key=test_key"));
+    }
+
+    #[tokio::test]
+    async fn test_refresh_access_token() {
+        use std::io::{Read, Write};
+
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let server = std::thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut request = vec![0; 4096];
+            let len = stream.read(&mut request).unwrap();
+            let body = r#"{"access_token": "new_token", "expires_in": 3599}"#;
+            write!(
+                stream,
+                "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                body.len(),
+                body
+            )
+            .unwrap();
+            String::from_utf8_lossy(&request[..len]).into_owned()
+        });
+
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let config_path = temp_dir.path().join("config.jsonc");
+        let config_content = "{\n    // My key\n    \"api_key\": \"test_key\",\n}\n";
+        std::fs::write(&config_path, config_content).unwrap();
+        let config = Config {
+            oauth_client_id: Some("id".to_string()),
+            oauth_client_secret: Some("secret".to_string()),
+            oauth_access_token: Some("old_token".to_string()),
+            oauth_refresh_token: Some("refresh".to_string()),
+            ..Config::load(Some(&config_path)).unwrap()
+        };
+
+        let mut client = test_client(config);
+        client.token_url = format!("http://127.0.0.1:{}/token", port);
+        client.tokens_path = Some(temp_dir.path().join("oauth_tokens.json"));
+        // A clone (e.g. a background search task) does the refresh
+        client.clone().refresh_access_token().await.unwrap();
+
+        let request = server.join().unwrap();
+        assert!(request.contains("grant_type=refresh_token"));
+        assert!(request.contains("refresh_token=refresh"));
+        assert_eq!(client.access_token().as_deref(), Some("new_token"));
+        let saved = Config::load(Some(&config_path)).unwrap();
+        assert_eq!(saved.oauth_access_token.as_deref(), Some("new_token"));
+        // The refresh token is kept when the endpoint doesn't rotate it
+        assert_eq!(saved.oauth_refresh_token.as_deref(), Some("refresh"));
+        // The config file keeps its comments
+        assert_eq!(
+            std::fs::read_to_string(&config_path).unwrap(),
+            config_content
+        );
+
+        // Without a refresh token there's nothing to refresh with
+        client.tokens().refresh_token = None;
+        assert!(client.refresh_access_token().await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_fetch_video_details_uses_cache() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let cache = VideoCache::new(temp_dir.path().join("video_cache.json"), 6);
+        cache.store_cached(&[video("cached")]).unwrap();
+
+        let mut client = test_client(Config {
+            api_key: "test_key".to_string(),
+            ..Config::default()
+        });
+        // Nothing listens here, so any HTTP request fails
+        client.base_url = "http://127.0.0.1:9".to_string();
+        client.cache = Some(cache);
+
+        let videos = client
+            .fetch_video_details(&["cached".to_string()])
+            .await
+            .unwrap();
+        assert_eq!(ids(&videos), ["cached"]);
+        assert_eq!(client.quota_used(), 0);
+        assert!(client.take_warnings().is_empty());
+
+        // IDs missing from the cache are requested
+        assert!(
+            client
+                .fetch_video_details(&["cached".to_string(), "other".to_string()])
+                .await
+                .is_err()
+        );
+    }
+
+    fn ids(videos: &[Video]) -> Vec<&str> {
+        videos.iter().map(|v| v.id.as_str()).collect()
+    }
+
+    #[test]
+    fn test_pad_with_fallback() {
+        let primary = vec![video("a"), video("b")];
+        let trending = vec![video("b"), video("c"), video("d"), video("e")];
+        let merged = pad_with_fallback(primary, trending, 4);
+        // "b" is already present, so it is skipped rather than duplicated
+        assert_eq!(ids(&merged), ["a", "b", "c", "d"]);
+
+        // Not enough fallback videos: everything unique is appended
+        let merged = pad_with_fallback(vec![video("a")], vec![video("a"), video("b")], 10);
+        assert_eq!(ids(&merged), ["a", "b"]);
+
+        // Full primary list is left untouched
+        let merged = pad_with_fallback(vec![video("a"), video("b")], vec![video("c")], 2);
+        assert_eq!(ids(&merged), ["a", "b"]);
+    }
+
+    #[test]
+    fn test_key_status_valid() {
+        assert_eq!(
+            KeyStatus::from_response(200, r#"{"items": []}"#),
+            KeyStatus::Valid
+        );
+    }
+
+    #[test]
+    fn test_key_status_invalid_key() {
+        let body = r#"{
+            "error": {
+                "code": 400,
+                "message": "API key not valid. Please pass a valid API key.",
+                "errors": [{"message": "API key not valid.", "domain": "global", "reason": "badRequest"}]
+            }
+        }"#;
+        assert_eq!(KeyStatus::from_response(400, body), KeyStatus::KeyInvalid);
+
+        let legacy = r#"{"error": {"code": 400, "message": "Bad Request", "errors": [{"reason": "keyInvalid"}]}}"#;
+        assert_eq!(KeyStatus::from_response(400, legacy), KeyStatus::KeyInvalid);
+    }
+
+    #[test]
+    fn test_key_status_quota_exceeded() {
+        let body = r#"{
+            "error": {
+                "code": 403,
+                "message": "The request cannot be completed because you have exceeded your quota.",
+                "errors": [{"domain": "youtube.quota", "reason": "quotaExceeded"}]
+            }
+        }"#;
+        assert_eq!(
+            KeyStatus::from_response(403, body),
+            KeyStatus::QuotaExceeded
+        );
+    }
+
+    #[test]
+    fn test_key_status_other() {
+        let body = r#"{"error": {"code": 500, "message": "Backend Error", "errors": [{"reason": "backendError"}]}}"#;
+        assert_eq!(
+            KeyStatus::from_response(500, body),
+            KeyStatus::Other(500, "Backend Error".to_string())
+        );
+        assert_eq!(
+            KeyStatus::from_response(502, "Bad Gateway"),
+            KeyStatus::Other(502, "Bad Gateway".to_string())
+        );
+    }
+
+    #[test]
+    fn test_api_error_messages() {
+        let body = r#"{"error": {"code": 403, "message": "The request cannot be completed because you have exceeded your quota.", "errors": [{"reason": "quotaExceeded"}]}}"#;
+        let err = api_error(StatusCode::FORBIDDEN, body);
+        assert_eq!(err.to_string(), KeyStatus::QuotaExceeded.message());
+        assert_eq!(
+            err.downcast_ref::<ApiRequestError>().unwrap().status,
+            KeyStatus::QuotaExceeded
+        );
+
+        let body = r#"{"error": {"code": 400, "message": "API key not valid. Please pass a valid API key.", "errors": [{"reason": "badRequest"}]}}"#;
+        let err = api_error(StatusCode::BAD_REQUEST, body);
+        assert!(err.to_string().starts_with("Invalid API key"));
+
+        // Unknown errors keep the API's message, not the JSON blob
+        let body = r#"{"error": {"code": 404, "message": "Video not found", "errors": [{"reason": "notFound"}]}}"#;
+        assert_eq!(
+            api_error(StatusCode::NOT_FOUND, body).to_string(),
+            "YouTube API error (404): Video not found"
+        );
+    }
+
+    /// Serve canned HTTP responses, one per connection, on a local port.
+    ///
+    /// Returns the base URL and a handle yielding how many requests were answered.
+    fn serve(responses: Vec<(u16, &'static str)>) -> (String, std::thread::JoinHandle<usize>) {
+        use std::io::{Read, Write};
+
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let handle = std::thread::spawn(move || {
+            let mut served = 0;
+            for (status, body) in responses {
+                let Ok((mut stream, _)) = listener.accept() else {
+                    break;
+                };
+                let mut request = [0; 4096];
+                let _ = stream.read(&mut request);
+                let _ = write!(
+                    stream,
+                    "HTTP/1.1 {} X\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    status,
+                    body.len(),
+                    body
+                );
+                served += 1;
+            }
+            served
+        });
+        (url, handle)
+    }
+
+    #[tokio::test]
+    async fn test_cache_write_failure_is_a_warning() {
+        let body = r#"{"items": [{"id": "fresh", "snippet": {"title": "Title", "channelTitle": "Channel", "channelId": "UC1", "description": "", "publishedAt": "2024-03-01T10:00:00Z", "thumbnails": {}}}]}"#;
+        let (url, server) = serve(vec![(200, body)]);
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        // The cache directory can't be created below a file
+        let blocker = temp_dir.path().join("file");
+        std::fs::write(&blocker, "").unwrap();
+
+        let mut client = test_client(Config {
+            api_key: "test_key".to_string(),
+            ..Config::default()
+        });
+        client.base_url = url;
+        client.cache = Some(VideoCache::new(blocker.join("video_cache.json"), 6));
+
+        let videos = client
+            .fetch_video_details(&["fresh".to_string()])
+            .await
+            .unwrap();
+        assert_eq!(ids(&videos), ["fresh"]);
+        let warnings = client.take_warnings();
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].starts_with("Failed to update video cache"));
+        assert!(client.take_warnings().is_empty());
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn test_skipped_videos_are_reported() {
+        let client = test_client(Config {
+            api_key: "test_key".to_string(),
+            ..Config::default()
+        });
+        let item = |published_at: &str| {
+            serde_json::json!({
+                "id": published_at,
+                "snippet": {
+                    "title": "Title",
+                    "channelTitle": "Channel",
+                    "channelId": "UC1",
+                    "description": "",
+                    "publishedAt": published_at,
+                    "thumbnails": {}
+                }
+            })
+        };
+        // One item the parser skips, one with a date that can't be converted
+        let body = serde_json::json!({
+            "items": [item("2024-03-01T10:00:00Z"), { "id": 5 }, item("yesterday")]
+        })
+        .to_string();
+        let response = ApiResponse::<ApiVideoItem>::parse(&body).unwrap();
+
+        let videos = client.videos_from_response(response);
+        assert_eq!(ids(&videos), ["2024-03-01T10:00:00Z"]);
+        assert_eq!(
+            client.take_warnings(),
+            ["Skipped 2 malformed videos in the YouTube API response"]
+        );
+
+        let response = ApiResponse::<ApiVideoItem>::parse(r#"{"items": []}"#).unwrap();
+        assert!(client.videos_from_response(response).is_empty());
+        assert!(client.take_warnings().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_send_with_retry_recovers_from_503() {
+        let (url, server) = serve(vec![(503, "busy"), (200, "ok")]);
+        let client = test_client(Config {
+            api_key: "test_key".to_string(),
+            ..Config::default()
+        });
+
+        let response = client
+            .send_with_retry(client.client.get(&url))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.text().await.unwrap(), "ok");
+        assert_eq!(server.join().unwrap(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_send_with_retry_skips_4xx() {
+        let (url, server) = serve(vec![(403, "quota")]);
+        let client = test_client(Config {
+            api_key: "test_key".to_string(),
+            ..Config::default()
+        });
+
+        let response = client
+            .send_with_retry(client.client.get(&url))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+        assert_eq!(server.join().unwrap(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_fetch_in_chunks_keeps_order() {
+        let ids: Vec<String> = (0..120).map(|i| format!("id{}", i)).collect();
+        // Later chunks tend to answer first; the output must not depend on it
+        let (videos, failed) = fetch_in_chunks(&ids, |chunk| async move {
+            let first: u64 = chunk[0].trim_start_matches("id").parse().unwrap();
+            tokio::time::sleep(Duration::from_millis(60 - first / 2)).await;
+            Ok(chunk.iter().map(|id| video(id)).collect())
+        })
+        .await
+        .unwrap();
+
+        let got: Vec<&str> = videos.iter().map(|v| v.id.as_str()).collect();
+        assert_eq!(got, ids);
+        assert!(failed.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_fetch_in_chunks_partial_failure() {
+        let ids: Vec<String> = (0..120).map(|i| format!("id{}", i)).collect();
+        let (videos, failed) = fetch_in_chunks(&ids, |chunk| async move {
+            if chunk[0] == "id50" {
+                anyhow::bail!("quota");
+            }
+            Ok(chunk.iter().map(|id| video(id)).collect())
+        })
+        .await
+        .unwrap();
+        assert_eq!(videos.len(), 70);
+        assert_eq!(videos[50].id, "id100");
+        let (missing, error) = failed.unwrap();
+        assert_eq!(missing, 50);
+        assert_eq!(error.to_string(), "quota");
+
+        let error = fetch_in_chunks(&ids, |_| async { anyhow::bail!("offline") })
+            .await
+            .unwrap_err();
+        assert_eq!(error.to_string(), "offline");
+        assert!(
+            fetch_in_chunks(&[], |_| async { anyhow::bail!("unused") })
+                .await
+                .unwrap()
+                .0
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_retry_delay() {
+        assert_eq!(retry_delay(0), Duration::from_millis(250));
+        assert_eq!(retry_delay(1), Duration::from_millis(500));
+        assert_eq!(retry_delay(2), Duration::from_secs(1));
+    }
+
+    #[test]
+    fn test_search_within_budget() {
+        assert!(search_within_budget(0, 10_000));
+        assert!(search_within_budget(8_900, 10_000));
+        // A tenth of the budget stays in reserve
+        assert!(!search_within_budget(8_901, 10_000));
+        assert!(!search_within_budget(20_000, 10_000));
+        assert!(!search_within_budget(0, 50));
+        assert!(search_within_budget(20_000, 0));
+    }
+
+    #[test]
+    fn test_budget_max_results() {
+        assert_eq!(budget_max_results(50, 0, 10_000), 50);
+        assert_eq!(budget_max_results(50, 5_000, 10_000), 50);
+        assert_eq!(budget_max_results(50, 5_100, 10_000), 25);
+        assert_eq!(budget_max_results(50, 7_500, 10_000), 25);
+        assert_eq!(budget_max_results(50, 8_000, 10_000), 12);
+        assert_eq!(budget_max_results(50, 9_500, 10_000), 5);
+        // Never below one result
+        assert_eq!(budget_max_results(5, 20_000, 10_000), 1);
+        // No budget, no reduction
+        assert_eq!(budget_max_results(50, 20_000, 0), 50);
+    }
 }
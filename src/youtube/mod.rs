@@ -2,8 +2,10 @@
 //!
 //! Provides client for fetching recommended videos and other YouTube data.
 
+pub mod cache;
 pub mod client;
 pub mod models;
+pub mod quota;
 
-pub use client::YouTubeClient;
-pub use models::Video;
+pub use client::{KeyStatus, YouTubeClient};
+pub use models::{NumberStyle, Video, format_count};
@@ -2,7 +2,9 @@
 //!
 //! Contains structures for representing videos, channels, and API responses.
 
+use anyhow::{Context, Result};
 use chrono::{DateTime, Utc};
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 
 /// Represents a YouTube video.
@@ -30,6 +32,18 @@ pub struct Video {
     pub view_count: u64,
     /// YouTube video URL
     pub url: String,
+    /// Only the ID is known (details could not be fetched)
+    #[serde(default)]
+    pub details_missing: bool,
+    /// Duration string as returned by the API (kept with `--debug`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub raw_duration: Option<String>,
+    /// Published date string as returned by the API (kept with `--debug`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub raw_published_at: Option<String>,
+    /// Why YouTube recommended the video (personalized recommendations only)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
 }
 
 impl Video {
@@ -75,9 +89,37 @@ impl Video {
             thumbnail_url,
             view_count,
             url,
+            details_missing: false,
+            raw_duration: None,
+            raw_published_at: None,
+            reason: None,
         }
     }
 
+    /// Create a placeholder for a video whose details could not be fetched.
+    ///
+    /// # Arguments
+    /// * `id` - YouTube video ID
+    /// * `watched_at` - When the video was watched, stored in `published_at`
+    ///
+    /// # Returns
+    /// * `Video` - Video with only the ID, URL and watch time set
+    pub fn id_only(id: String, watched_at: DateTime<Utc>) -> Self {
+        let mut video = Self::new(
+            id,
+            String::new(),
+            String::new(),
+            String::new(),
+            String::new(),
+            0,
+            watched_at,
+            String::new(),
+            0,
+        );
+        video.details_missing = true;
+        video
+    }
+
     /// Format duration as HH:MM:SS or MM:SS.
     ///
     /// # Returns
@@ -94,18 +136,24 @@ impl Video {
         }
     }
 
-    /// Format view count with K/M suffixes.
+    /// Format the view count.
+    ///
+    /// # Arguments
+    /// * `style` - Number style (`number_style`)
+    /// * `separator` - Thousands separator of the full style
     ///
     /// # Returns
-    /// * `String` - Formatted view count
-    pub fn format_views(&self) -> String {
-        if self.view_count >= 1_000_000 {
-            format!("{:.1}M", self.view_count as f64 / 1_000_000.0)
-        } else if self.view_count >= 1_000 {
-            format!("{:.1}K", self.view_count as f64 / 1_000.0)
-        } else {
-            self.view_count.to_string()
-        }
+    /// * `String` - Formatted view count (see `format_count`)
+    pub fn format_views(&self, style: NumberStyle, separator: &str) -> String {
+        format_count(self.view_count, style, separator)
+    }
+
+    /// Get the short youtu.be link to the video.
+    ///
+    /// # Returns
+    /// * `String` - URL such as `https://youtu.be/VIDEO_ID`
+    pub fn short_url(&self) -> String {
+        format!("https://youtu.be/{}", self.id)
     }
 
     /// Format published date as a readable string.
@@ -115,16 +163,208 @@ impl Video {
     pub fn format_date(&self) -> String {
         self.published_at.format("%a. %d.%m.%Y").to_string()
     }
+
+    /// Format the published date relative to now.
+    ///
+    /// # Returns
+    /// * `String` - Relative time (e.g., "3 days ago", "1 month ago")
+    pub fn format_relative_date(&self) -> String {
+        self.format_relative_date_at(Utc::now())
+    }
+
+    /// Format the published date relative to a given time.
+    ///
+    /// # Arguments
+    /// * `now` - Reference time
+    ///
+    /// # Returns
+    /// * `String` - Relative time in the largest whole unit
+    ///
+    /// # Details
+    /// Less than a minute, and dates in the future (clock skew), read "just now".
+    /// Months count as 30 days and years as 365.
+    pub fn format_relative_date_at(&self, now: DateTime<Utc>) -> String {
+        let seconds = (now - self.published_at).num_seconds();
+        if seconds < 60 {
+            return "just now".to_string();
+        }
+        let (count, unit) = match seconds / 60 {
+            minutes if minutes < 60 => (minutes, "minute"),
+            minutes if minutes < 60 * 24 => (minutes / 60, "hour"),
+            minutes => match minutes / (60 * 24) {
+                days if days < 7 => (days, "day"),
+                days if days < 30 => (days / 7, "week"),
+                days if days < 365 => (days / 30, "month"),
+                days => (days / 365, "year"),
+            },
+        };
+        format!(
+            "{} {}{} ago",
+            count,
+            unit,
+            if count == 1 { "" } else { "s" }
+        )
+    }
+}
+
+/// How large counts (views) are written.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NumberStyle {
+    /// Abbreviated with K/M suffixes (e.g. "1.5K")
+    #[default]
+    Short,
+    /// Full number with grouped thousands (e.g. "1,534")
+    Full,
+}
+
+impl std::str::FromStr for NumberStyle {
+    type Err = anyhow::Error;
+
+    /// Parse a number style from its config name.
+    ///
+    /// # Arguments
+    /// * `s` - "short" or "full"
+    ///
+    /// # Returns
+    /// * `Result<NumberStyle>` - Parsed style or error for unknown names
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "short" => Ok(NumberStyle::Short),
+            "full" => Ok(NumberStyle::Full),
+            other => Err(anyhow::anyhow!("Unknown number style: {}", other)),
+        }
+    }
+}
+
+/// Format a count in the given style.
+///
+/// # Arguments
+/// * `count` - Number to format
+/// * `style` - Short (K/M suffixes) or full
+/// * `separator` - Thousands separator for the full style (e.g. ",", ".", " " or "")
+///
+/// # Returns
+/// * `String` - Formatted count
+pub fn format_count(count: u64, style: NumberStyle, separator: &str) -> String {
+    match style {
+        NumberStyle::Short if count >= 1_000_000 => {
+            format!("{:.1}M", count as f64 / 1_000_000.0)
+        }
+        NumberStyle::Short if count >= 1_000 => format!("{:.1}K", count as f64 / 1_000.0),
+        NumberStyle::Short => count.to_string(),
+        NumberStyle::Full => {
+            let digits = count.to_string();
+            let mut result = String::new();
+            for (i, ch) in digits.chars().enumerate() {
+                if i > 0 && (digits.len() - i).is_multiple_of(3) {
+                    result.push_str(separator);
+                }
+                result.push(ch);
+            }
+            result
+        }
+    }
 }
 
 /// YouTube API search/list response wrapper.
 #[derive(Debug, Deserialize)]
 pub struct ApiResponse<T> {
-    /// Response items
+    /// Response items (missing in error envelopes)
+    #[serde(default = "Vec::new")]
     pub items: Vec<T>,
     /// Next page token for pagination
-    #[serde(rename = "nextPageToken")]
+    #[serde(rename = "nextPageToken", default)]
     pub next_page_token: Option<String>,
+    /// Error object, sent by some proxies with HTTP 200
+    #[serde(default)]
+    pub error: Option<ApiError>,
+    /// Number of items left out because they couldn't be parsed (see `parse`)
+    #[serde(skip)]
+    pub skipped: usize,
+}
+
+impl<T: DeserializeOwned> ApiResponse<T> {
+    /// Parse a list response body.
+    ///
+    /// # Arguments
+    /// * `body` - Response body
+    ///
+    /// # Returns
+    /// * `Result<ApiResponse<T>>` - Parsed response, or error if the body is not
+    ///   valid JSON or carries an `error` object
+    ///
+    /// # Details
+    /// Items are parsed one by one, so an item with an unexpected shape is
+    /// skipped (and counted in `skipped`) instead of failing the whole response.
+    pub fn parse(body: &str) -> Result<Self> {
+        let response: ApiResponse<serde_json::Value> =
+            serde_json::from_str(body).context("Invalid JSON")?;
+        if let Some(error) = response.error {
+            return Err(anyhow::anyhow!(
+                "YouTube API error ({}): {}",
+                error.code,
+                if error.message.is_empty() {
+                    "unknown error"
+                } else {
+                    &error.message
+                }
+            ));
+        }
+        let total = response.items.len();
+        let items: Vec<T> = response
+            .items
+            .into_iter()
+            .filter_map(|item| serde_json::from_value(item).ok())
+            .collect();
+        Ok(Self {
+            skipped: total - items.len(),
+            items,
+            next_page_token: response.next_page_token,
+            error: None,
+        })
+    }
+}
+
+/// YouTube API error envelope (`{"error": {...}}`).
+#[derive(Debug, Deserialize)]
+pub struct ApiErrorResponse {
+    /// Error details
+    pub error: ApiError,
+}
+
+/// YouTube API error object.
+#[derive(Debug, Deserialize)]
+pub struct ApiError {
+    /// HTTP status code reported by the API
+    #[serde(default)]
+    pub code: u16,
+    /// Human-readable error message
+    #[serde(default)]
+    pub message: String,
+    /// Individual error entries
+    #[serde(default)]
+    pub errors: Vec<ApiErrorDetail>,
+}
+
+/// Single error entry within an API error.
+#[derive(Debug, Deserialize)]
+pub struct ApiErrorDetail {
+    /// Machine-readable reason (e.g. "quotaExceeded", "keyInvalid")
+    #[serde(default)]
+    pub reason: String,
+}
+
+impl ApiErrorResponse {
+    /// Check if any error entry has the given reason.
+    ///
+    /// # Arguments
+    /// * `reason` - Reason string to look for (e.g. "quotaExceeded")
+    ///
+    /// # Returns
+    /// * `bool` - True if the reason is present
+    pub fn has_reason(&self, reason: &str) -> bool {
+        self.error.errors.iter().any(|e| e.reason == reason)
+    }
 }
 
 /// YouTube API video item (from activities.list or search.list).
@@ -173,6 +413,48 @@ pub struct ApiThumbnails {
     /// High thumbnail
     #[serde(default)]
     pub high: Option<ApiThumbnail>,
+    /// Standard thumbnail (640x480, not always present)
+    #[serde(default)]
+    pub standard: Option<ApiThumbnail>,
+    /// Maximum resolution thumbnail (1280x720, not always present)
+    #[serde(default)]
+    pub maxres: Option<ApiThumbnail>,
+}
+
+/// Default thumbnail tier preference, used when none is configured.
+pub const DEFAULT_THUMBNAIL_ORDER: [&str; 5] = ["high", "medium", "default", "standard", "maxres"];
+
+impl ApiThumbnails {
+    /// Pick a thumbnail URL by tier preference.
+    ///
+    /// # Arguments
+    /// * `order` - Tier names in order of preference ("maxres", "standard", "high", "medium", "default")
+    ///
+    /// # Returns
+    /// * `Option<String>` - URL of the first present tier, or None if none of them is present
+    ///
+    /// # Details
+    /// Unknown tier names are ignored.
+    pub fn pick<S: AsRef<str>>(self, order: &[S]) -> Option<String> {
+        let ApiThumbnails {
+            mut default,
+            mut medium,
+            mut high,
+            mut standard,
+            mut maxres,
+        } = self;
+        order.iter().find_map(|tier| {
+            match tier.as_ref() {
+                "default" => default.take(),
+                "medium" => medium.take(),
+                "high" => high.take(),
+                "standard" => standard.take(),
+                "maxres" => maxres.take(),
+                _ => None,
+            }
+            .map(|t| t.url)
+        })
+    }
 }
 
 /// Single thumbnail.
@@ -236,12 +518,29 @@ pub struct ApiRecommendationDetails {
     /// Resource ID of recommended video
     #[serde(rename = "resourceId")]
     pub resource_id: ApiResourceId,
-    /// Reason for recommendation
-    #[allow(dead_code)]
-    // Part of API response structure, may be used for displaying recommendation reasons
+    /// Reason for recommendation (e.g. "videoWatched")
     pub reason: Option<String>,
 }
 
+impl ApiRecommendationDetails {
+    /// Describe why the video was recommended.
+    ///
+    /// # Returns
+    /// * `Option<String>` - Readable reason, or None if the API gave none
+    ///
+    /// # Details
+    /// Known reasons are phrased for display; unknown ones are passed through.
+    pub fn reason_text(&self) -> Option<String> {
+        let text = match self.reason.as_deref()? {
+            "videoWatched" => "Because you watched a similar video",
+            "videoLiked" => "Because you liked a similar video",
+            "videoFavorited" => "Because you favorited a similar video",
+            other => other,
+        };
+        Some(text.to_string())
+    }
+}
+
 /// Resource ID (video ID).
 #[derive(Debug, Deserialize)]
 pub struct ApiResourceId {
@@ -262,11 +561,34 @@ impl TryFrom<ApiVideoItem> for Video {
     /// * `Result<Video>` - Converted video or error
     ///
     /// # Details
-    /// Parses duration from ISO 8601 format (PT4M13S) to seconds.
+    /// Uses the default thumbnail preference and doesn't keep the raw strings
+    /// (see `Video::from_api_item`).
     fn try_from(item: ApiVideoItem) -> Result<Self, Self::Error> {
-        let duration = item
-            .content_details
-            .and_then(|cd| cd.duration)
+        Video::from_api_item(item, &DEFAULT_THUMBNAIL_ORDER, false)
+    }
+}
+
+impl Video {
+    /// Convert an API video item to a Video.
+    ///
+    /// # Arguments
+    /// * `item` - API video item
+    /// * `thumbnail_order` - Thumbnail tiers in order of preference
+    /// * `keep_raw` - Keep the raw duration and published strings (debug mode)
+    ///
+    /// # Returns
+    /// * `Result<Video>` - Converted video or error
+    ///
+    /// # Details
+    /// Parses duration from ISO 8601 format (PT4M13S) to seconds.
+    pub fn from_api_item<S: AsRef<str>>(
+        item: ApiVideoItem,
+        thumbnail_order: &[S],
+        keep_raw: bool,
+    ) -> Result<Self> {
+        let raw_duration = item.content_details.and_then(|cd| cd.duration);
+        let duration = raw_duration
+            .clone()
             .map(parse_duration)
             .transpose()?
             .unwrap_or(0);
@@ -284,13 +606,10 @@ impl TryFrom<ApiVideoItem> for Video {
         let thumbnail_url = item
             .snippet
             .thumbnails
-            .high
-            .or(item.snippet.thumbnails.medium)
-            .or(item.snippet.thumbnails.default)
-            .map(|t| t.url)
+            .pick(thumbnail_order)
             .unwrap_or_default();
 
-        Ok(Video::new(
+        let mut video = Video::new(
             item.id,
             item.snippet.title,
             item.snippet.channel_title,
@@ -300,7 +619,12 @@ impl TryFrom<ApiVideoItem> for Video {
             published_at,
             thumbnail_url,
             view_count,
-        ))
+        );
+        if keep_raw {
+            video.raw_duration = raw_duration;
+            video.raw_published_at = Some(item.snippet.published_at);
+        }
+        Ok(video)
     }
 }
 
@@ -311,38 +635,92 @@ impl TryFrom<ApiVideoItem> for Video {
 ///
 /// # Returns
 /// * `Result<u64>` - Duration in seconds or error
+///
+/// # Details
+/// Supports the `W`, `D`, `H`, `M` and `S` designators, so multi-day
+/// livestreams (`P1DT2H`) and `P0D` parse too. Absurdly large values
+/// saturate at `u64::MAX` instead of overflowing.
 fn parse_duration(duration: String) -> anyhow::Result<u64> {
     // Format: PT4M13S (Period Time, 4 Minutes, 13 Seconds)
     let mut seconds = 0u64;
     let mut current_num = String::new();
 
     for ch in duration.chars() {
-        match ch {
+        let unit = match ch {
             'P' | 'T' => continue,
-            'H' => {
-                seconds += current_num.parse::<u64>()? * 3600;
-                current_num.clear();
+            'W' => 604_800,
+            'D' => 86_400,
+            'H' => 3600,
+            'M' => 60,
+            'S' => 1,
+            c if c.is_ascii_digit() => {
+                current_num.push(c);
+                continue;
             }
-            'M' => {
-                seconds += current_num.parse::<u64>()? * 60;
-                current_num.clear();
-            }
-            'S' => {
-                seconds += current_num.parse::<u64>()?;
-                current_num.clear();
-            }
-            c if c.is_ascii_digit() => current_num.push(c),
             _ => return Err(anyhow::anyhow!("Invalid duration format: {}", duration)),
-        }
+        };
+        seconds = seconds.saturating_add(current_num.parse::<u64>()?.saturating_mul(unit));
+        current_num.clear();
     }
 
     Ok(seconds)
 }
 
+/// Build a minimal video for tests.
+///
+/// # Arguments
+/// * `id` - Video ID (the title is derived from it)
+///
+/// # Returns
+/// * `Video` - One-minute video of "Channel" uploaded now
+#[cfg(test)]
+pub fn test_video(id: &str) -> Video {
+    Video::new(
+        id.to_string(),
+        format!("Video {}", id),
+        "Channel".to_string(),
+        "channel_id".to_string(),
+        String::new(),
+        60,
+        chrono::Utc::now(),
+        String::new(),
+        10,
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_short_url() {
+        let mut video = Video::id_only("dQw4w9WgXcQ".to_string(), Utc::now());
+        assert_eq!(video.short_url(), "https://youtu.be/dQw4w9WgXcQ");
+
+        // Derived from the ID only, not from the full URL and its query
+        video.url = "https://www.youtube.com/watch?v=dQw4w9WgXcQ&t=42s&list=PL1".to_string();
+        assert_eq!(video.short_url(), "https://youtu.be/dQw4w9WgXcQ");
+    }
+
+    #[test]
+    fn test_recommendation_reason_text() {
+        let details = |reason: Option<&str>| ApiRecommendationDetails {
+            resource_id: ApiResourceId {
+                video_id: "abc".to_string(),
+            },
+            reason: reason.map(str::to_string),
+        };
+        assert_eq!(
+            details(Some("videoWatched")).reason_text().as_deref(),
+            Some("Because you watched a similar video")
+        );
+        assert_eq!(
+            details(Some("somethingNew")).reason_text().as_deref(),
+            Some("somethingNew")
+        );
+        assert_eq!(details(None).reason_text(), None);
+    }
+
     #[test]
     fn test_parse_duration() {
         assert_eq!(parse_duration("PT4M13S".to_string()).unwrap(), 253);
@@ -351,6 +729,23 @@ mod tests {
         assert_eq!(parse_duration("PT2H15M30S".to_string()).unwrap(), 8130);
     }
 
+    #[test]
+    fn test_parse_duration_days_and_weeks() {
+        assert_eq!(parse_duration("P1DT2H3M4S".to_string()).unwrap(), 93_784);
+        assert_eq!(parse_duration("P2D".to_string()).unwrap(), 172_800);
+        assert_eq!(parse_duration("P1W".to_string()).unwrap(), 604_800);
+        assert_eq!(parse_duration("P0D".to_string()).unwrap(), 0);
+        assert!(parse_duration("P1Y".to_string()).is_err());
+        assert!(parse_duration("PTXS".to_string()).is_err());
+    }
+
+    #[test]
+    fn test_parse_duration_saturates() {
+        let max = u64::MAX.to_string();
+        assert_eq!(parse_duration(format!("P{}W", max)).unwrap(), u64::MAX);
+        assert_eq!(parse_duration(format!("P{}DT1S", max)).unwrap(), u64::MAX);
+    }
+
     #[test]
     fn test_video_format_duration() {
         let video = Video::new(
@@ -393,7 +788,8 @@ mod tests {
             "thumb".to_string(),
             1500,
         );
-        assert_eq!(video.format_views(), "1.5K");
+        assert_eq!(video.format_views(NumberStyle::Short, ""), "1.5K");
+        assert_eq!(video.format_views(NumberStyle::Full, "."), "1.500");
 
         let video_m = Video::new(
             "test".to_string(),
@@ -406,6 +802,165 @@ mod tests {
             "thumb".to_string(),
             2_500_000,
         );
-        assert_eq!(video_m.format_views(), "2.5M");
+        assert_eq!(video_m.format_views(NumberStyle::Short, ""), "2.5M");
+    }
+
+    #[test]
+    fn test_format_relative_date() {
+        let now = Utc::now();
+        let ago = |seconds: i64| {
+            Video::id_only("id".to_string(), now - chrono::Duration::seconds(seconds))
+                .format_relative_date_at(now)
+        };
+        assert_eq!(ago(30), "just now");
+        assert_eq!(ago(-3600), "just now");
+        assert_eq!(ago(60), "1 minute ago");
+        assert_eq!(ago(5 * 3600), "5 hours ago");
+        assert_eq!(ago(86_400), "1 day ago");
+        assert_eq!(ago(3 * 86_400), "3 days ago");
+        assert_eq!(ago(14 * 86_400), "2 weeks ago");
+        assert_eq!(ago(65 * 86_400), "2 months ago");
+        assert_eq!(ago(400 * 86_400), "1 year ago");
+    }
+
+    #[test]
+    fn test_format_count_full() {
+        assert_eq!(format_count(1_234_567, NumberStyle::Full, ","), "1,234,567");
+        assert_eq!(format_count(123_456, NumberStyle::Full, "."), "123.456");
+        assert_eq!(format_count(999, NumberStyle::Full, ","), "999");
+        assert_eq!(format_count(1000, NumberStyle::Full, ""), "1000");
+        assert_eq!("full".parse::<NumberStyle>().unwrap(), NumberStyle::Full);
+        assert!("long".parse::<NumberStyle>().is_err());
+    }
+
+    #[test]
+    fn test_api_response_without_items() {
+        let response =
+            ApiResponse::<ApiVideoItem>::parse(r#"{"kind": "youtube#videoListResponse"}"#).unwrap();
+        assert!(response.items.is_empty());
+        assert_eq!(response.next_page_token, None);
+    }
+
+    #[test]
+    fn test_api_response_skips_malformed_items() {
+        let item = |id: &str| {
+            serde_json::json!({
+                "id": id,
+                "snippet": {
+                    "title": "Title",
+                    "channelTitle": "Channel",
+                    "channelId": "UC1",
+                    "description": "",
+                    "publishedAt": "2024-03-01T10:00:00Z",
+                    "thumbnails": {}
+                }
+            })
+        };
+        let body = serde_json::json!({
+            "items": [item("a"), { "id": "broken", "snippet": 5 }, item("b")],
+            "nextPageToken": "next"
+        })
+        .to_string();
+        let response = ApiResponse::<ApiVideoItem>::parse(&body).unwrap();
+        let ids: Vec<&str> = response.items.iter().map(|i| i.id.as_str()).collect();
+        assert_eq!(ids, ["a", "b"]);
+        assert_eq!(response.skipped, 1);
+        assert_eq!(response.next_page_token.as_deref(), Some("next"));
+    }
+
+    #[test]
+    fn test_api_response_error_object() {
+        let body = r#"{"error": {"code": 403, "message": "The request cannot be completed because you have exceeded your quota.", "errors": [{"reason": "quotaExceeded"}]}}"#;
+        let err = ApiResponse::<ApiVideoItem>::parse(body)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("403"));
+        assert!(err.contains("exceeded your quota"));
+        assert!(ApiResponse::<ApiVideoItem>::parse("not json").is_err());
+    }
+
+    fn thumbnails(tiers: &[&str]) -> ApiThumbnails {
+        let thumb = |name: &str| {
+            tiers.contains(&name).then(|| ApiThumbnail {
+                url: format!("{}.jpg", name),
+            })
+        };
+        ApiThumbnails {
+            default: thumb("default"),
+            medium: thumb("medium"),
+            high: thumb("high"),
+            standard: thumb("standard"),
+            maxres: thumb("maxres"),
+        }
+    }
+
+    #[test]
+    fn test_from_api_item_keeps_raw_strings_in_debug_mode() {
+        let item = || -> ApiVideoItem {
+            serde_json::from_value(serde_json::json!({
+                "id": "abc",
+                "snippet": {
+                    "title": "Title",
+                    "channelTitle": "Channel",
+                    "channelId": "UC1",
+                    "description": "",
+                    "publishedAt": "2024-03-01T10:00:00Z",
+                    "thumbnails": {}
+                },
+                "contentDetails": { "duration": "PT1M5S" }
+            }))
+            .unwrap()
+        };
+
+        let video = Video::from_api_item(item(), &DEFAULT_THUMBNAIL_ORDER, true).unwrap();
+        assert_eq!(video.duration, 65);
+        assert_eq!(video.raw_duration.as_deref(), Some("PT1M5S"));
+        assert_eq!(
+            video.raw_published_at.as_deref(),
+            Some("2024-03-01T10:00:00Z")
+        );
+
+        let video = Video::from_api_item(item(), &DEFAULT_THUMBNAIL_ORDER, false).unwrap();
+        assert!(video.raw_duration.is_none() && video.raw_published_at.is_none());
+    }
+
+    #[test]
+    fn test_thumbnail_pick_default_order() {
+        let all = ["default", "medium", "high", "standard", "maxres"];
+        assert_eq!(
+            thumbnails(&all).pick(&DEFAULT_THUMBNAIL_ORDER).as_deref(),
+            Some("high.jpg")
+        );
+        assert_eq!(
+            thumbnails(&["default", "medium"])
+                .pick(&DEFAULT_THUMBNAIL_ORDER)
+                .as_deref(),
+            Some("medium.jpg")
+        );
+        // Falls back to maxres when it is the only size available
+        assert_eq!(
+            thumbnails(&["maxres"])
+                .pick(&DEFAULT_THUMBNAIL_ORDER)
+                .as_deref(),
+            Some("maxres.jpg")
+        );
+        assert_eq!(thumbnails(&[]).pick(&DEFAULT_THUMBNAIL_ORDER), None);
+    }
+
+    #[test]
+    fn test_thumbnail_pick_custom_order() {
+        let order = ["maxres", "standard", "bogus", "high"];
+        assert_eq!(
+            thumbnails(&["high", "standard", "maxres"])
+                .pick(&order)
+                .as_deref(),
+            Some("maxres.jpg")
+        );
+        assert_eq!(
+            thumbnails(&["high", "standard"]).pick(&order).as_deref(),
+            Some("standard.jpg")
+        );
+        // Tiers missing from the order are never picked
+        assert_eq!(thumbnails(&["medium", "default"]).pick(&order), None);
     }
 }
@@ -0,0 +1,178 @@
+//! Estimated API quota usage of the current quota day.
+//!
+//! YouTube counts quota per project and day, not per process, so the estimate
+//! is kept in a small JSON file shared by every run (and every clone of the
+//! client) on the same day.
+
+use anyhow::{Context, Result};
+use chrono::{FixedOffset, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+
+/// Offset of the zone whose midnight resets the quota (Pacific Standard Time).
+///
+/// Google resets at midnight Pacific Time; the fixed offset is an hour off
+/// during daylight saving time, which only shifts the estimate's reset.
+const QUOTA_DAY_OFFSET_SECS: i32 = -8 * 3600;
+
+/// Quota units used on one day.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+struct QuotaUsage {
+    /// Quota day the units were used on
+    day: Option<NaiveDate>,
+    /// Estimated units used
+    units: u64,
+}
+
+impl QuotaUsage {
+    /// Start over when the quota day changed.
+    ///
+    /// # Arguments
+    /// * `today` - Current quota day
+    fn roll_over(&mut self, today: NaiveDate) {
+        if self.day != Some(today) {
+            *self = QuotaUsage {
+                day: Some(today),
+                units: 0,
+            };
+        }
+    }
+}
+
+/// Quota usage tracker, optionally backed by a JSON file.
+#[derive(Debug, Clone)]
+pub struct QuotaTracker {
+    /// Usage file path (None keeps the usage in memory only)
+    path: Option<PathBuf>,
+    /// Usage as last read or written (shared between clones)
+    usage: Arc<Mutex<QuotaUsage>>,
+}
+
+impl QuotaTracker {
+    /// Create a tracker for a usage file.
+    ///
+    /// # Arguments
+    /// * `path` - Usage file path (created on the first request), or None
+    ///
+    /// # Returns
+    /// * `QuotaTracker` - Tracker (the file is read on every lookup)
+    pub fn new(path: Option<PathBuf>) -> Self {
+        Self {
+            path,
+            usage: Arc::default(),
+        }
+    }
+
+    /// Add the cost of a request.
+    ///
+    /// # Arguments
+    /// * `units` - Quota cost of the request
+    ///
+    /// # Returns
+    /// * `Result<()>` - Success, or error if the usage file can't be written
+    ///   (the in-memory estimate is updated either way)
+    ///
+    /// # Details
+    /// The file is re-read first so requests of other running instances count too.
+    pub fn record(&self, units: u64) -> Result<()> {
+        let usage = {
+            let mut usage = self.sync();
+            usage.units = usage.units.saturating_add(units);
+            usage.clone()
+        };
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).with_context(|| {
+                format!("Failed to create quota directory: {}", parent.display())
+            })?;
+        }
+        let json = serde_json::to_string(&usage).context("Failed to serialize quota usage")?;
+        fs::write(path, json)
+            .with_context(|| format!("Failed to write quota usage: {}", path.display()))
+    }
+
+    /// Get the units used today.
+    ///
+    /// # Returns
+    /// * `u64` - Estimated units used since the last quota reset
+    pub fn used(&self) -> u64 {
+        self.sync().units
+    }
+
+    /// Load the usage file and roll over to the current quota day.
+    ///
+    /// # Returns
+    /// * `MutexGuard<QuotaUsage>` - Current usage (a missing or invalid file
+    ///   keeps the in-memory usage)
+    fn sync(&self) -> std::sync::MutexGuard<'_, QuotaUsage> {
+        let mut usage = self
+            .usage
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        if let Some(stored) = self
+            .path
+            .as_ref()
+            .and_then(|path| fs::read_to_string(path).ok())
+            .and_then(|content| serde_json::from_str::<QuotaUsage>(&content).ok())
+        {
+            *usage = stored;
+        }
+        usage.roll_over(quota_day());
+        usage
+    }
+}
+
+/// Get the current quota day.
+///
+/// # Returns
+/// * `NaiveDate` - Date in the zone whose midnight resets the quota
+fn quota_day() -> NaiveDate {
+    let offset = FixedOffset::east_opt(QUOTA_DAY_OFFSET_SECS).expect("valid offset");
+    Utc::now().with_timezone(&offset).date_naive()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_usage_shared_through_file() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("quota.json");
+        let first = QuotaTracker::new(Some(path.clone()));
+        first.record(100).unwrap();
+        first.clone().record(1).unwrap();
+        assert_eq!(first.used(), 101);
+
+        // A later run (or another instance) continues from the file
+        let second = QuotaTracker::new(Some(path));
+        assert_eq!(second.used(), 101);
+        second.record(100).unwrap();
+        assert_eq!(first.used(), 201);
+    }
+
+    #[test]
+    fn test_usage_resets_on_new_day() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("quota.json");
+        let yesterday = QuotaUsage {
+            day: quota_day().pred_opt(),
+            units: 9_000,
+        };
+        fs::write(&path, serde_json::to_string(&yesterday).unwrap()).unwrap();
+        let tracker = QuotaTracker::new(Some(path));
+        assert_eq!(tracker.used(), 0);
+    }
+
+    #[test]
+    fn test_usage_in_memory() {
+        let tracker = QuotaTracker::new(None);
+        tracker.record(5).unwrap();
+        assert_eq!(tracker.used(), 5);
+    }
+}